@@ -0,0 +1,234 @@
+use crate::activity_metadata::Activity;
+use crate::external_id::ExternalId;
+use crate::utils::external_ids_from_json;
+use serde_json;
+
+/// A group of summaries ORCID considers to be the same item, e.g. versions of one
+/// work contributed by different sources
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActivityGroup<T> {
+    external_ids: Vec<ExternalId>,
+    summaries: Vec<T>,
+}
+
+impl<T> ActivityGroup<T> {
+    pub(crate) fn new(external_ids: Vec<ExternalId>, summaries: Vec<T>) -> Self {
+        Self {
+            external_ids,
+            summaries,
+        }
+    }
+
+    /// Parses a group, e.g. an entry of `works.group` or `employments.affiliation-group`,
+    /// collecting every `summary_key` object in it
+    pub fn new_from_json(
+        j: &serde_json::Value,
+        summary_key: &str,
+        parse: impl Fn(&serde_json::Value) -> T,
+    ) -> Self {
+        Self {
+            external_ids: external_ids_from_json(&j["external-ids"]),
+            summaries: Self::summaries_json(j, summary_key)
+                .into_iter()
+                .map(parse)
+                .collect(),
+        }
+    }
+
+    /// Summaries are either an array in the group (works, fundings), objects wrapped in
+    /// `summaries` (affiliations), or nested in `peer-review-group` sub-groups
+    fn summaries_json<'a>(
+        j: &'a serde_json::Value,
+        summary_key: &str,
+    ) -> Vec<&'a serde_json::Value> {
+        let mut ret = Vec::new();
+        if let Some(summaries) = j[summary_key].as_array() {
+            ret.extend(summaries.iter().filter(|s| s.is_object()));
+        }
+        if let Some(summaries) = j["summaries"].as_array() {
+            ret.extend(
+                summaries
+                    .iter()
+                    .map(|s| &s[summary_key])
+                    .filter(|s| s.is_object()),
+            );
+        }
+        if let Some(sub_groups) = j["peer-review-group"].as_array() {
+            for sub_group in sub_groups {
+                ret.append(&mut Self::summaries_json(sub_group, summary_key));
+            }
+        }
+        ret
+    }
+
+    // Getter methods
+    /// The external IDs of the group, the union of those of its summaries
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
+        &self.external_ids
+    }
+
+    /// Every summary in the group, in API order
+    pub fn summaries(&self) -> &Vec<T> {
+        &self.summaries
+    }
+
+    pub fn into_summaries(self) -> Vec<T> {
+        self.summaries
+    }
+
+    pub fn len(&self) -> usize {
+        self.summaries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.summaries.is_empty()
+    }
+}
+
+impl<T: Activity> ActivityGroup<T> {
+    /// The summary ORCID displays for the group: the one with the highest display
+    /// index, or the first one if there is a tie
+    pub fn preferred(&self) -> Option<&T> {
+        self.summaries.iter().reduce(|best, summary| {
+            if summary.metadata().display_index().unwrap_or(0)
+                > best.metadata().display_index().unwrap_or(0)
+            {
+                summary
+            } else {
+                best
+            }
+        })
+    }
+}
+
+/// An activity that gets the external IDs of its group when it has none
+pub(crate) trait GroupedActivity {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId>;
+}
+
+/// The summaries of `group`, where those without external IDs get the group's
+pub(crate) fn summaries_with_group_ids<T: GroupedActivity>(group: ActivityGroup<T>) -> Vec<T> {
+    let external_ids = group.external_ids;
+    group
+        .summaries
+        .into_iter()
+        .map(|mut summary| {
+            let ids = summary.external_ids_mut();
+            if ids.is_empty() {
+                *ids = external_ids.clone();
+            }
+            summary
+        })
+        .collect()
+}
+
+/// The preferred summary of `group`, with the group's external IDs if it has none
+pub(crate) fn preferred_with_group_ids<T: Activity + GroupedActivity + Clone>(
+    group: ActivityGroup<T>,
+) -> Option<T> {
+    let mut summary = group.preferred()?.clone();
+    let ids = summary.external_ids_mut();
+    if ids.is_empty() {
+        *ids = group.external_ids;
+    }
+    Some(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::funding::Funding;
+    use crate::role::Role;
+    use crate::work::Work;
+    use serde_json::json;
+
+    #[test]
+    fn test_work_group() {
+        let j = json!({
+            "external-ids": {
+                "external-id": [{
+                    "external-id-type": "doi",
+                    "external-id-value": "10.1234/test"
+                }]
+            },
+            "work-summary": [{
+                "put-code": 1,
+                "display-index": "0",
+                "title": { "title": { "value": "From Crossref" } }
+            }, {
+                "put-code": 2,
+                "display-index": "1",
+                "title": { "title": { "value": "Self-asserted" } }
+            }, {
+                "put-code": 3,
+                "display-index": "1",
+                "title": { "title": { "value": "Also index 1" } }
+            }]
+        });
+        let group = ActivityGroup::new_from_json(&j, "work-summary", Work::new_from_summary_json);
+        assert_eq!(group.len(), 3);
+        assert_eq!(
+            group
+                .external_ids()
+                .iter()
+                .map(|id| (id.id_type().as_str(), id.value()))
+                .collect::<Vec<_>>(),
+            vec![("doi", "10.1234/test")]
+        );
+        assert_eq!(group.preferred().unwrap().metadata.put_code(), Some(2));
+        assert_eq!(
+            group.summaries()[0].title,
+            Some("From Crossref".to_string())
+        );
+    }
+
+    #[test]
+    fn test_affiliation_group() {
+        let j = json!({
+            "summaries": [
+                { "employment-summary": { "put-code": 1, "role-title": "Lecturer" } },
+                { "employment-summary": { "put-code": 2, "role-title": "Professor" } }
+            ]
+        });
+        let group =
+            ActivityGroup::new_from_json(&j, "employment-summary", Role::new_from_summary_json);
+        assert_eq!(group.len(), 2);
+        assert_eq!(group.summaries()[1].title(), Some(&"Professor".to_string()));
+        // Without display indexes, the first summary is preferred
+        assert_eq!(group.preferred().unwrap().metadata().put_code(), Some(1));
+    }
+
+    #[test]
+    fn test_peer_review_sub_groups() {
+        let j = json!({
+            "external-ids": { "external-id": [{
+                "external-id-type": "peer-review",
+                "external-id-value": "issn:0028-0836"
+            }] },
+            "peer-review-group": [
+                { "peer-review-summary": [{ "put-code": 1 }] },
+                { "peer-review-summary": [{ "put-code": 2 }, { "put-code": 3 }] }
+            ]
+        });
+        let group = ActivityGroup::new_from_json(
+            &j,
+            "peer-review-summary",
+            crate::PeerReview::new_from_summary_json,
+        );
+        assert_eq!(group.len(), 3);
+        assert_eq!(group.summaries()[2].metadata().put_code(), Some(3));
+    }
+
+    #[test]
+    fn test_empty_group() {
+        let group: ActivityGroup<Funding> = ActivityGroup::new_from_json(
+            &json!({}),
+            "funding-summary",
+            Funding::new_from_summary_json,
+        );
+        assert!(group.is_empty());
+        assert!(group.preferred().is_none());
+        assert!(group.external_ids().is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+use crate::record;
+use serde_json;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Who asserted an activity: a user via the ORCID website, or a member API client
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Source {
+    name: Option<String>,
+    orcid: Option<String>,
+    client_id: Option<String>,
+    assertion_origin_name: Option<String>,
+    assertion_origin_orcid: Option<String>,
+    assertion_origin_client_id: Option<String>,
+}
+
+impl Source {
+    pub fn new_from_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::Source>(j))
+    }
+
+    // Getter methods
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    /// The ORCID ID of the user who added the activity, if added by a user
+    pub fn orcid(&self) -> Option<&String> {
+        self.orcid.as_ref()
+    }
+
+    /// The ID of the member API client that added the activity, e.g. `APP-...`
+    pub fn client_id(&self) -> Option<&String> {
+        self.client_id.as_ref()
+    }
+
+    /// The name of the organization a client asserted the activity on behalf of
+    pub fn assertion_origin_name(&self) -> Option<&String> {
+        self.assertion_origin_name.as_ref()
+    }
+
+    pub fn assertion_origin_orcid(&self) -> Option<&String> {
+        self.assertion_origin_orcid.as_ref()
+    }
+
+    pub fn assertion_origin_client_id(&self) -> Option<&String> {
+        self.assertion_origin_client_id.as_ref()
+    }
+
+    /// True if the activity was added by a user rather than a member API client
+    pub fn is_user(&self) -> bool {
+        self.orcid.is_some() && self.client_id.is_none()
+    }
+
+    /// True if the activity was added by the record holder themselves
+    pub fn is_self_asserted(&self, record_orcid: &str) -> bool {
+        self.is_user() && self.orcid.as_deref() == Some(record_orcid)
+    }
+}
+
+/// Bookkeeping data ORCID keeps for every activity summary
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActivityMetadata {
+    put_code: Option<u64>,
+    source: Option<Source>,
+    visibility: Option<String>,
+    created_date: Option<SystemTime>,
+    last_modified_date: Option<SystemTime>,
+    path: Option<String>,
+    display_index: Option<u64>,
+}
+
+impl ActivityMetadata {
+    /// Parses the metadata of an activity summary, e.g. a `work-summary` entry
+    pub fn new_from_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::Metadata>(j))
+    }
+
+    /// ORCID timestamps are milliseconds since the epoch, e.g. `{"value": 1487783400000}`
+    fn timestamp(t: &Option<record::Timestamp>) -> Option<SystemTime> {
+        t.as_ref()
+            .and_then(|t| t.value)
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    // Getter methods
+    /// The put-code, needed to fetch details of, update or delete the activity
+    pub fn put_code(&self) -> Option<u64> {
+        self.put_code
+    }
+
+    pub fn source(&self) -> Option<&Source> {
+        self.source.as_ref()
+    }
+
+    /// The visibility, `public`, `limited`, `registered-only` or `private`
+    pub fn visibility(&self) -> Option<&String> {
+        self.visibility.as_ref()
+    }
+
+    pub fn created_date(&self) -> Option<SystemTime> {
+        self.created_date
+    }
+
+    pub fn last_modified_date(&self) -> Option<SystemTime> {
+        self.last_modified_date
+    }
+
+    /// The API path of the activity, e.g. `/0000-0001-2345-6789/work/12345`
+    pub fn path(&self) -> Option<&String> {
+        self.path.as_ref()
+    }
+
+    /// The display index; within a group, the summary with the highest one is preferred
+    pub fn display_index(&self) -> Option<u64> {
+        self.display_index
+    }
+}
+
+/// An activity summary, e.g. a `Work` or a `Funding`
+pub trait Activity {
+    fn metadata(&self) -> &ActivityMetadata;
+}
+
+impl From<&record::Source> for Source {
+    fn from(source: &record::Source) -> Self {
+        let path =
+            |id: &Option<record::OrcidIdentifier>| id.as_ref().and_then(|id| id.path.clone());
+        Self {
+            name: record::string_value(&source.source_name),
+            orcid: path(&source.source_orcid),
+            client_id: path(&source.source_client_id),
+            assertion_origin_name: record::string_value(&source.assertion_origin_name),
+            assertion_origin_orcid: path(&source.assertion_origin_orcid),
+            assertion_origin_client_id: path(&source.assertion_origin_client_id),
+        }
+    }
+}
+
+impl From<&record::Metadata> for ActivityMetadata {
+    fn from(metadata: &record::Metadata) -> Self {
+        Self {
+            put_code: metadata.put_code,
+            source: metadata.source.as_ref().map(Source::from),
+            visibility: metadata.visibility.clone(),
+            created_date: Self::timestamp(&metadata.created_date),
+            last_modified_date: Self::timestamp(&metadata.last_modified_date),
+            path: metadata.path.clone(),
+            display_index: metadata
+                .display_index
+                .as_ref()
+                .and_then(|index| index.as_str().parse().ok()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn summary() -> serde_json::Value {
+        json!({
+            "created-date": { "value": 1487783400000u64 },
+            "last-modified-date": { "value": 1700000000123u64 },
+            "source": {
+                "source-orcid": null,
+                "source-client-id": {
+                    "uri": "https://orcid.org/client/APP-1234",
+                    "path": "APP-1234",
+                    "host": "orcid.org"
+                },
+                "source-name": { "value": "Example University" },
+                "assertion-origin-orcid": null,
+                "assertion-origin-client-id": null,
+                "assertion-origin-name": null
+            },
+            "put-code": 12345,
+            "path": "/0000-0001-2345-6789/work/12345",
+            "visibility": "public",
+            "display-index": "1"
+        })
+    }
+
+    #[test]
+    fn test_new_from_json() {
+        let metadata = ActivityMetadata::new_from_json(&summary());
+        assert_eq!(metadata.put_code(), Some(12345));
+        assert_eq!(metadata.visibility(), Some(&"public".to_string()));
+        assert_eq!(metadata.display_index(), Some(1));
+        assert_eq!(
+            metadata.path(),
+            Some(&"/0000-0001-2345-6789/work/12345".to_string())
+        );
+        assert_eq!(
+            metadata.created_date(),
+            Some(UNIX_EPOCH + Duration::from_millis(1487783400000))
+        );
+        assert_eq!(
+            metadata.last_modified_date(),
+            Some(UNIX_EPOCH + Duration::from_millis(1700000000123))
+        );
+        let source = metadata.source().unwrap();
+        assert_eq!(source.name(), Some(&"Example University".to_string()));
+        assert_eq!(source.client_id(), Some(&"APP-1234".to_string()));
+        assert_eq!(source.orcid(), None);
+        assert!(!source.is_user());
+    }
+
+    #[test]
+    fn test_new_from_json_empty() {
+        assert_eq!(
+            ActivityMetadata::new_from_json(&json!({})),
+            ActivityMetadata::default()
+        );
+    }
+
+    #[test]
+    fn test_self_asserted() {
+        let source = Source::new_from_json(&json!({
+            "source-orcid": { "path": "0000-0001-2345-6789" },
+            "source-client-id": null,
+            "source-name": { "value": "Jane Doe" }
+        }));
+        assert!(source.is_user());
+        assert!(source.is_self_asserted("0000-0001-2345-6789"));
+        assert!(!source.is_self_asserted("0000-0002-1825-0097"));
+    }
+}
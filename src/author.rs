@@ -1,39 +1,74 @@
-use crate::date::Date;
+use crate::activity_group::{self, ActivityGroup, GroupedActivity};
+use crate::activity_metadata::Activity;
+use crate::diagnostic::{diagnose, Diagnostic};
+use crate::external_id::{self, ExternalId};
 use crate::funding::Funding;
 use crate::membership::Membership;
-use crate::organization::Organization;
 use crate::peer_review::PeerReview;
 use crate::qualification::Qualification;
+use crate::record::{self, ActivitiesSummary, AffiliationSummary, Affiliations, StringValue};
+use crate::research_resource::ResearchResource;
 use crate::role::Role;
-use crate::utils::collect_parts;
+use crate::timeline::{Timeline, TimelineEntry, TimelineItem};
 use crate::work::Work;
 use serde_json;
 
 #[derive(Debug, Clone)]
 pub struct Author {
     j: serde_json::Value,
+    record: record::Record,
 }
 
 impl Author {
+    /// Parses an ORCID v3.0 record; the raw JSON stays available via `json()`
     pub fn new_from_json(j: serde_json::Value) -> Self {
-        Author { j }
+        let record = record::from_json(&j);
+        Author { j, record }
+    }
+
+    /// Parses an ORCID v3.0 record, failing with every part that does not match the
+    /// schema, and every date whose year, month or day is out of range.
+    /// `new_from_json` would silently drop those parts. Other values, e.g. ORCID iDs
+    /// and external IDs, are not checked beyond their types.
+    pub fn parse_strict(j: serde_json::Value) -> Result<Self, Vec<Diagnostic>> {
+        let diagnostics = diagnose::<record::Record>(&j);
+        if diagnostics.is_empty() {
+            Ok(Self::new_from_json(j))
+        } else {
+            Err(diagnostics)
+        }
     }
 
     pub fn json(&self) -> &serde_json::Value {
         &self.j
     }
 
+    /// The typed record, for fields without an accessor of their own
+    pub fn record(&self) -> &record::Record {
+        &self.record
+    }
+
     pub fn orcid_id(&self) -> Option<&str> {
-        self.j["orcid-identifier"]["path"].as_str()
+        self.record.orcid_identifier.as_ref()?.path.as_deref()
+    }
+
+    fn person(&self) -> Option<&record::Person> {
+        self.record.person.as_ref()
+    }
+
+    fn name_part(&self, part: impl Fn(&record::Name) -> &Option<StringValue>) -> Option<&str> {
+        part(self.person()?.name.as_ref()?)
+            .as_ref()
+            .and_then(StringValue::as_str)
     }
 
     pub fn credit_name(&self) -> Option<&str> {
-        self.j["person"]["name"]["credit-name"]["value"].as_str()
+        self.name_part(|name| &name.credit_name)
     }
 
     pub fn full_name(&self) -> Option<String> {
-        let last_name = self.j["person"]["name"]["family-name"]["value"].as_str();
-        let given_names = self.j["person"]["name"]["given-names"]["value"].as_str();
+        let last_name = self.family_name();
+        let given_names = self.given_names();
         match (given_names, last_name) {
             (Some(f), Some(l)) => Some(format!("{} {}", f, l)),
             (None, Some(l)) => Some(l.to_string()),
@@ -42,158 +77,299 @@ impl Author {
     }
 
     pub fn given_names(&self) -> Option<&str> {
-        self.j["person"]["name"]["given-names"]["value"].as_str()
+        self.name_part(|name| &name.given_names)
     }
 
     pub fn family_name(&self) -> Option<&str> {
-        self.j["person"]["name"]["family-name"]["value"].as_str()
+        self.name_part(|name| &name.family_name)
     }
 
     pub fn other_names(&self) -> Vec<&str> {
-        self.j["person"]["other-names"]["other-name"]
-            .as_array()
-            .map(|x| x.iter().filter_map(|x| x["content"].as_str()).collect())
+        self.person()
+            .and_then(|p| p.other_names.as_ref())
+            .map(|x| {
+                x.other_name
+                    .iter()
+                    .filter_map(|x| x.content.as_deref())
+                    .collect()
+            })
             .unwrap_or_default()
     }
 
     pub fn biography(&self) -> Option<&str> {
-        self.j["person"]["biography"]["content"].as_str()
+        self.person()?.biography.as_ref()?.content.as_deref()
     }
 
-    pub fn external_ids(&self) -> Vec<(String, String)> {
-        collect_parts(
-            &self.j["person"]["external-identifiers"]["external-identifier"],
-            vec!["external-id-type", "external-id-value"],
-        )
-        .into_iter()
-        .map(|v| (v[0].clone(), v[1].clone()))
-        .collect()
+    /// Person identifiers, e.g. Scopus Author IDs; types ORCID does not list as work
+    /// identifiers are `ExternalIdType::Other`
+    pub fn external_ids(&self) -> Vec<ExternalId> {
+        self.person()
+            .and_then(|p| p.external_identifiers.as_ref())
+            .map(|ids| {
+                ids.external_identifier
+                    .iter()
+                    .filter_map(ExternalId::from_person_record)
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
     pub fn keywords(&self) -> Vec<String> {
-        collect_parts(&self.j["person"]["keywords"]["keyword"], vec!["content"])
-            .into_iter()
-            .map(|v| v.into_iter().next().unwrap_or_default())
-            .collect()
+        self.person()
+            .and_then(|p| p.keywords.as_ref())
+            .map(|k| {
+                k.keyword
+                    .iter()
+                    .map(|k| k.content.clone().unwrap_or_default())
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
+    /// Every work summary; see `work_groups()` to tell versions of the same work apart
     pub fn works(&self) -> Vec<Work> {
-        self.j["activities-summary"]["works"]["group"]
-            .as_array()
-            .map(|arr| arr.iter().map(Work::new_from_json).collect())
+        Self::flatten(self.work_groups())
+    }
+
+    pub fn work_groups(&self) -> Vec<ActivityGroup<Work>> {
+        self.activities()
+            .and_then(|a| a.works.as_ref())
+            .map(|works| {
+                works
+                    .group
+                    .iter()
+                    .map(|g| Self::group(&g.external_ids, &g.work_summary))
+                    .collect()
+            })
             .unwrap_or_default()
     }
 
     pub fn researcher_urls(&self) -> Vec<(&str, &str)> {
-        self.j["person"]["researcher-urls"]["researcher-url"]
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(
-                        |x| match (x["url-name"].as_str(), x["url"]["value"].as_str()) {
+        self.person()
+            .and_then(|p| p.researcher_urls.as_ref())
+            .map(|urls| {
+                urls.researcher_url
+                    .iter()
+                    .filter_map(|x| {
+                        match (
+                            x.url_name.as_deref(),
+                            x.url.as_ref().and_then(StringValue::as_str),
+                        ) {
                             (Some(name), Some(value)) => Some((name, value)),
                             _ => None,
-                        },
-                    )
+                        }
+                    })
                     .collect()
             })
             .unwrap_or_default()
     }
 
-    fn roles(&self, key1: &str, key2: &str) -> Vec<Role> {
-        self.j["activities-summary"][key1]["affiliation-group"]
-            .as_array()
-            .map(|groups| {
-                let mut ret = Vec::new();
-                for group in groups {
-                    // Parse external IDs at group level
-                    let group_external_ids =
-                        if let Some(ext_ids) = group["external-ids"]["external-id"].as_array() {
-                            ext_ids
-                                .iter()
-                                .filter_map(|id| {
-                                    match (
-                                        id["external-id-type"].as_str(),
-                                        id["external-id-value"].as_str(),
-                                    ) {
-                                        (Some(id_type), Some(id_value)) => {
-                                            Some((id_type.to_string(), id_value.to_string()))
-                                        }
-                                        _ => None,
-                                    }
-                                })
-                                .collect::<Vec<(String, String)>>()
-                        } else {
-                            vec![]
-                        };
-
-                    if let Some(summaries) = group["summaries"].as_array() {
-                        for summary in summaries {
-                            if !summary[key2].is_object() {
-                                continue;
-                            }
-                            let x2 = &summary[key2];
-                            let mut role = Role::new();
-                            role.set_department(
-                                x2["department-name"].as_str().map(|s| s.to_string()),
-                            );
-                            role.set_title(x2["role-title"].as_str().map(|s| s.to_string()));
-                            if x2["start-date"].is_object() {
-                                role.set_start_date(Some(Date::new_from_json(&x2["start-date"])))
-                            }
-                            if x2["end-date"].is_object() {
-                                role.set_end_date(Some(Date::new_from_json(&x2["end-date"])))
-                            }
-                            if x2["organization"].is_object() {
-                                role.set_organization(Some(Organization::new_from_json(
-                                    &x2["organization"],
-                                )))
-                            }
-                            // Set external IDs from the group level
-                            role.set_external_ids(group_external_ids.clone());
-                            ret.push(role);
-                        }
-                    }
-                }
-                ret
+    fn activities(&self) -> Option<&ActivitiesSummary> {
+        self.record.activities_summary.as_ref()
+    }
+
+    /// Converts the summaries of a group, e.g. an entry of `works.group`
+    fn group<'a, S: 'a, T: From<&'a S>>(
+        external_ids: &Option<record::ExternalIds>,
+        summaries: impl IntoIterator<Item = &'a S>,
+    ) -> ActivityGroup<T> {
+        ActivityGroup::new(
+            external_id::from_record_ids(external_ids),
+            summaries.into_iter().map(T::from).collect(),
+        )
+    }
+
+    /// The groups of an affiliation section, e.g. `employments`
+    fn affiliation_groups<T: for<'a> From<&'a AffiliationSummary>>(
+        &self,
+        section: impl Fn(&ActivitiesSummary) -> &Option<Affiliations>,
+    ) -> Vec<ActivityGroup<T>> {
+        self.activities()
+            .and_then(|a| section(a).as_ref())
+            .map(|affiliations| {
+                affiliations
+                    .affiliation_group
+                    .iter()
+                    .map(|g| Self::group(&g.external_ids, g.all_summaries()))
+                    .collect()
             })
             .unwrap_or_default()
     }
 
     pub fn education(&self) -> Vec<Role> {
-        self.roles("educations", "education-summary")
+        Self::flatten(self.education_groups())
+    }
+
+    pub fn education_groups(&self) -> Vec<ActivityGroup<Role>> {
+        self.affiliation_groups(|a| &a.educations)
     }
 
     pub fn employment(&self) -> Vec<Role> {
-        self.roles("employments", "employment-summary")
+        Self::flatten(self.employment_groups())
+    }
+
+    pub fn employment_groups(&self) -> Vec<ActivityGroup<Role>> {
+        self.affiliation_groups(|a| &a.employments)
+    }
+
+    pub fn invited_positions(&self) -> Vec<Role> {
+        Self::flatten(self.invited_position_groups())
+    }
+
+    pub fn invited_position_groups(&self) -> Vec<ActivityGroup<Role>> {
+        self.affiliation_groups(|a| &a.invited_positions)
+    }
+
+    pub fn distinctions(&self) -> Vec<Role> {
+        Self::flatten(self.distinction_groups())
+    }
+
+    pub fn distinction_groups(&self) -> Vec<ActivityGroup<Role>> {
+        self.affiliation_groups(|a| &a.distinctions)
+    }
+
+    pub fn services(&self) -> Vec<Role> {
+        Self::flatten(self.service_groups())
+    }
+
+    pub fn service_groups(&self) -> Vec<ActivityGroup<Role>> {
+        self.affiliation_groups(|a| &a.services)
+    }
+
+    /// Research resource summaries; fetch one with `Client::research_resource` for its items
+    pub fn research_resources(&self) -> Vec<ResearchResource> {
+        Self::flatten(self.research_resource_groups())
+    }
+
+    pub fn research_resource_groups(&self) -> Vec<ActivityGroup<ResearchResource>> {
+        self.activities()
+            .and_then(|a| a.research_resources.as_ref())
+            .map(|resources| {
+                resources
+                    .group
+                    .iter()
+                    .map(|g| Self::group(&g.external_ids, &g.research_resource_summary))
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
     pub fn fundings(&self) -> Vec<Funding> {
-        self.j["activities-summary"]["fundings"]["group"]
-            .as_array()
-            .map(|arr| arr.iter().map(Funding::new_from_json).collect())
+        Self::flatten(self.funding_groups())
+    }
+
+    pub fn funding_groups(&self) -> Vec<ActivityGroup<Funding>> {
+        self.activities()
+            .and_then(|a| a.fundings.as_ref())
+            .map(|fundings| {
+                fundings
+                    .group
+                    .iter()
+                    .map(|g| Self::group(&g.external_ids, &g.funding_summary))
+                    .collect()
+            })
             .unwrap_or_default()
     }
 
     pub fn memberships(&self) -> Vec<Membership> {
-        self.j["activities-summary"]["memberships"]["group"]
-            .as_array()
-            .map(|arr| arr.iter().map(Membership::new_from_json).collect())
-            .unwrap_or_default()
+        Self::flatten(self.membership_groups())
+    }
+
+    pub fn membership_groups(&self) -> Vec<ActivityGroup<Membership>> {
+        self.affiliation_groups(|a| &a.memberships)
     }
 
     pub fn peer_reviews(&self) -> Vec<PeerReview> {
-        self.j["activities-summary"]["peer-reviews"]["group"]
-            .as_array()
-            .map(|arr| arr.iter().map(PeerReview::new_from_json).collect())
+        Self::flatten(self.peer_review_groups())
+    }
+
+    pub fn peer_review_groups(&self) -> Vec<ActivityGroup<PeerReview>> {
+        self.activities()
+            .and_then(|a| a.peer_reviews.as_ref())
+            .map(|peer_reviews| {
+                peer_reviews
+                    .group
+                    .iter()
+                    .map(|g| Self::group(&g.external_ids, g.all_summaries()))
+                    .collect()
+            })
             .unwrap_or_default()
     }
 
     pub fn qualifications(&self) -> Vec<Qualification> {
-        self.j["activities-summary"]["qualifications"]["group"]
-            .as_array()
-            .map(|arr| arr.iter().map(Qualification::new_from_json).collect())
-            .unwrap_or_default()
+        Self::flatten(self.qualification_groups())
+    }
+
+    pub fn qualification_groups(&self) -> Vec<ActivityGroup<Qualification>> {
+        self.affiliation_groups(|a| &a.qualifications)
+    }
+
+    /// Employments, educations, invited positions, memberships, qualifications and
+    /// fundings as one chronological timeline, with the preferred summary of each group
+    pub fn timeline(&self) -> Timeline {
+        let roles = |groups: Vec<ActivityGroup<Role>>, item: fn(Role) -> TimelineItem| {
+            Self::preferred(groups).into_iter().map(move |role| {
+                TimelineEntry::new(
+                    role.start_date().copied(),
+                    role.end_date().copied(),
+                    item(role),
+                )
+            })
+        };
+        let mut entries: Vec<TimelineEntry> =
+            roles(self.employment_groups(), TimelineItem::Employment)
+                .chain(roles(self.education_groups(), TimelineItem::Education))
+                .chain(roles(
+                    self.invited_position_groups(),
+                    TimelineItem::InvitedPosition,
+                ))
+                .collect();
+        entries.extend(
+            Self::preferred(self.membership_groups())
+                .into_iter()
+                .map(|m| {
+                    TimelineEntry::new(
+                        m.start_date().copied(),
+                        m.end_date().copied(),
+                        TimelineItem::Membership(m),
+                    )
+                }),
+        );
+        entries.extend(
+            Self::preferred(self.qualification_groups())
+                .into_iter()
+                .map(|q| {
+                    TimelineEntry::new(
+                        q.start_date().copied(),
+                        q.end_date().copied(),
+                        TimelineItem::Qualification(q),
+                    )
+                }),
+        );
+        entries.extend(Self::preferred(self.funding_groups()).into_iter().map(|f| {
+            TimelineEntry::new(
+                f.start_date().copied(),
+                f.end_date().copied(),
+                TimelineItem::Funding(f),
+            )
+        }));
+        Timeline::new(entries)
+    }
+
+    /// The preferred summary of each group, with the external IDs of its group if it has none
+    fn preferred<T: Activity + GroupedActivity + Clone>(groups: Vec<ActivityGroup<T>>) -> Vec<T> {
+        groups
+            .into_iter()
+            .filter_map(activity_group::preferred_with_group_ids)
+            .collect()
+    }
+
+    /// Every summary of the groups; summaries without external IDs get those of their group
+    fn flatten<T: GroupedActivity>(groups: Vec<ActivityGroup<T>>) -> Vec<T> {
+        groups
+            .into_iter()
+            .flat_map(activity_group::summaries_with_group_ids)
+            .collect()
     }
 
     pub fn name_variants(&self) -> Vec<String> {
@@ -217,10 +393,81 @@ impl Author {
         // Convert to Vec and return
         variants.into_iter().collect()
     }
+}
+
+/// Authors are equal if their raw JSON is; the typed record is parsed from it
+impl PartialEq for Author {
+    fn eq(&self, other: &Self) -> bool {
+        self.j == other.j
+    }
+}
+
+/// Serializes the domain types under their snake_case getter names, plus the raw ORCID
+/// record under `json`. Deserializing reads only `json`, so a round trip is lossless.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Author {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(serde::Serialize)]
+        struct Shape<'a> {
+            orcid_id: Option<&'a str>,
+            given_names: Option<&'a str>,
+            family_name: Option<&'a str>,
+            credit_name: Option<&'a str>,
+            other_names: Vec<&'a str>,
+            biography: Option<&'a str>,
+            keywords: Vec<String>,
+            external_ids: Vec<ExternalId>,
+            researcher_urls: Vec<(&'a str, &'a str)>,
+            work_groups: Vec<ActivityGroup<Work>>,
+            education_groups: Vec<ActivityGroup<Role>>,
+            employment_groups: Vec<ActivityGroup<Role>>,
+            invited_position_groups: Vec<ActivityGroup<Role>>,
+            distinction_groups: Vec<ActivityGroup<Role>>,
+            service_groups: Vec<ActivityGroup<Role>>,
+            funding_groups: Vec<ActivityGroup<Funding>>,
+            membership_groups: Vec<ActivityGroup<Membership>>,
+            qualification_groups: Vec<ActivityGroup<Qualification>>,
+            peer_review_groups: Vec<ActivityGroup<PeerReview>>,
+            research_resource_groups: Vec<ActivityGroup<ResearchResource>>,
+            json: &'a serde_json::Value,
+        }
+        Shape {
+            orcid_id: self.orcid_id(),
+            given_names: self.given_names(),
+            family_name: self.family_name(),
+            credit_name: self.credit_name(),
+            other_names: self.other_names(),
+            biography: self.biography(),
+            keywords: self.keywords(),
+            external_ids: self.external_ids(),
+            researcher_urls: self.researcher_urls(),
+            work_groups: self.work_groups(),
+            education_groups: self.education_groups(),
+            employment_groups: self.employment_groups(),
+            invited_position_groups: self.invited_position_groups(),
+            distinction_groups: self.distinction_groups(),
+            service_groups: self.service_groups(),
+            funding_groups: self.funding_groups(),
+            membership_groups: self.membership_groups(),
+            qualification_groups: self.qualification_groups(),
+            peer_review_groups: self.peer_review_groups(),
+            research_resource_groups: self.research_resource_groups(),
+            json: &self.j,
+        }
+        .serialize(serializer)
+    }
+}
 
-    // TODO invited-positions
-    // research-resources?
-    // services?
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Author {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Shape {
+            json: serde_json::Value,
+        }
+        let shape = Shape::deserialize(deserializer)?;
+        Ok(Author::new_from_json(shape.json))
+    }
 }
 
 #[cfg(test)]
@@ -353,8 +600,8 @@ mod tests {
         let ext_ids = author.external_ids();
         assert_eq!(ext_ids.len(), 1);
         assert_eq!(
-            ext_ids[0],
-            ("ISNI".to_string(), "0000000012345678".to_string())
+            (ext_ids[0].id_type().as_str(), ext_ids[0].value()),
+            ("ISNI", "0000000012345678")
         );
 
         let keywords = author.keywords();
@@ -522,10 +769,13 @@ mod tests {
         let ids = author.external_ids();
         assert_eq!(ids.len(), 2);
         assert_eq!(
-            ids[0],
-            ("ResearcherID".to_string(), "A-1234-5678".to_string())
+            (ids[0].id_type().as_str(), ids[0].value()),
+            ("ResearcherID", "A-1234-5678")
+        );
+        assert_eq!(
+            (ids[1].id_type().as_str(), ids[1].value()),
+            ("Scopus", "1234567890")
         );
-        assert_eq!(ids[1], ("Scopus".to_string(), "1234567890".to_string()));
     }
 
     #[test]
@@ -759,12 +1009,18 @@ mod tests {
         );
         assert_eq!(role.external_ids().len(), 2);
         assert_eq!(
-            role.external_ids()[0],
-            ("grant-number".to_string(), "GR-2023-12345".to_string())
+            (
+                role.external_ids()[0].id_type().as_str(),
+                role.external_ids()[0].value()
+            ),
+            ("grant-number", "GR-2023-12345")
         );
         assert_eq!(
-            role.external_ids()[1],
-            ("project-id".to_string(), "PROJ-456".to_string())
+            (
+                role.external_ids()[1].id_type().as_str(),
+                role.external_ids()[1].value()
+            ),
+            ("project-id", "PROJ-456")
         );
     }
 
@@ -812,4 +1068,487 @@ mod tests {
             .len();
         assert_eq!(unique_count, name_variants.len());
     }
+
+    #[test]
+    fn test_role_metadata() {
+        let j = json!({
+            "activities-summary": {
+                "employments": {
+                    "affiliation-group": [{
+                        "summaries": [{
+                            "employment-summary": {
+                                "put-code": 99,
+                                "visibility": "public",
+                                "role-title": "Professor"
+                            }
+                        }]
+                    }]
+                }
+            }
+        });
+        let author = Author::new_from_json(j);
+        let employment = author.employment();
+        assert_eq!(employment[0].metadata().put_code(), Some(99));
+        assert_eq!(
+            employment[0].metadata().visibility(),
+            Some(&"public".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mistyped_fields_are_dropped_alone() {
+        let author = Author::new_from_json(json!({
+            "path": 5,
+            "person": { "name": { "family-name": { "value": "Carberry" } } },
+            "activities-summary": {
+                "works": { "group": [{ "work-summary": [{
+                    "put-code": "5",
+                    "title": { "title": { "value": "Toward a Unified Theory" } }
+                }] }] },
+                "employments": { "affiliation-group": [{ "summaries": [{
+                    "employment-summary": { "put-code": 7, "visibility": 3, "role-title": "Professor" }
+                }] }] }
+            }
+        }));
+        assert_eq!(author.family_name(), Some("Carberry"));
+
+        let works = author.works();
+        assert_eq!(works.len(), 1);
+        assert_eq!(works[0].title, Some("Toward a Unified Theory".to_string()));
+        assert_eq!(works[0].metadata.put_code(), None);
+
+        let employment = author.employment();
+        assert_eq!(employment.len(), 1);
+        assert_eq!(employment[0].title(), Some(&"Professor".to_string()));
+        assert_eq!(employment[0].metadata().put_code(), Some(7));
+        assert_eq!(employment[0].metadata().visibility(), None);
+    }
+
+    fn group_external_ids() -> serde_json::Value {
+        json!({ "external-id": [{
+            "external-id-type": "grant_number",
+            "external-id-value": "G-1",
+            "external-id-relationship": "self"
+        }] })
+    }
+
+    fn assert_group_external_ids(ids: &[ExternalId]) {
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids[0].value(), "G-1");
+    }
+
+    #[test]
+    fn test_fundings_inherit_group_external_ids() {
+        let author = Author::new_from_json(json!({
+            "activities-summary": { "fundings": { "group": [{
+                "external-ids": group_external_ids(),
+                "funding-summary": [{ "title": { "title": { "value": "Grant" } } }]
+            }] } }
+        }));
+        assert_group_external_ids(author.fundings()[0].external_ids());
+    }
+
+    #[test]
+    fn test_memberships_inherit_group_external_ids() {
+        let author = Author::new_from_json(json!({
+            "activities-summary": { "memberships": { "affiliation-group": [{
+                "external-ids": group_external_ids(),
+                "summaries": [{ "membership-summary": { "role-title": "Fellow" } }]
+            }] } }
+        }));
+        assert_group_external_ids(author.memberships()[0].external_ids());
+    }
+
+    #[test]
+    fn test_peer_reviews_inherit_group_external_ids() {
+        let author = Author::new_from_json(json!({
+            "activities-summary": { "peer-reviews": { "group": [{
+                "external-ids": group_external_ids(),
+                "peer-review-group": [{
+                    "peer-review-summary": [{ "review-group-id": "issn:0953-1513" }]
+                }]
+            }] } }
+        }));
+        assert_group_external_ids(author.peer_reviews()[0].external_ids());
+    }
+
+    #[test]
+    fn test_qualifications_inherit_group_external_ids() {
+        let author = Author::new_from_json(json!({
+            "activities-summary": { "qualifications": { "affiliation-group": [{
+                "external-ids": group_external_ids(),
+                "summaries": [{ "qualification-summary": { "role-title": "Engineer" } }]
+            }] } }
+        }));
+        assert_group_external_ids(author.qualifications()[0].external_ids());
+    }
+
+    #[test]
+    fn test_research_resources_inherit_group_external_ids() {
+        let author = Author::new_from_json(json!({
+            "activities-summary": { "research-resources": { "group": [{
+                "external-ids": group_external_ids(),
+                "research-resource-summary": [{
+                    "proposal": { "title": { "title": { "value": "Beam time" } } }
+                }]
+            }] } }
+        }));
+        assert_group_external_ids(author.research_resources()[0].external_ids());
+    }
+
+    #[test]
+    fn test_own_external_ids_are_kept() {
+        let author = Author::new_from_json(json!({
+            "activities-summary": { "fundings": { "group": [{
+                "external-ids": group_external_ids(),
+                "funding-summary": [{ "external-ids": { "external-id": [{
+                    "external-id-type": "grant_number",
+                    "external-id-value": "OWN-1"
+                }] } }]
+            }] } }
+        }));
+        assert_eq!(author.fundings()[0].external_ids()[0].value(), "OWN-1");
+    }
+
+    #[test]
+    fn test_work_groups() {
+        let j = json!({
+            "activities-summary": {
+                "works": {
+                    "group": [{
+                        "external-ids": { "external-id": [{
+                            "external-id-type": "doi",
+                            "external-id-value": "10.1234/test"
+                        }] },
+                        "work-summary": [
+                            { "put-code": 1, "display-index": "0", "title": { "title": { "value": "Crossref" } } },
+                            { "put-code": 2, "display-index": "1", "title": { "title": { "value": "Mine" } } }
+                        ]
+                    }, {
+                        "work-summary": [
+                            { "put-code": 3, "title": { "title": { "value": "Other" } } }
+                        ]
+                    }]
+                }
+            }
+        });
+        let author = Author::new_from_json(j);
+        let groups = author.work_groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].len(), 2);
+        assert_eq!(
+            groups[0].preferred().unwrap().title,
+            Some("Mine".to_string())
+        );
+
+        // The flattened view keeps every version and inherits group external IDs
+        let works = author.works();
+        assert_eq!(works.len(), 3);
+        assert_eq!(
+            works[0]
+                .external_ids
+                .iter()
+                .map(|id| (id.id_type().as_str(), id.value()))
+                .collect::<Vec<_>>(),
+            vec![("doi", "10.1234/test")]
+        );
+        assert!(works[2].external_ids.is_empty());
+    }
+
+    #[test]
+    fn test_affiliation_groups() {
+        let j = json!({
+            "activities-summary": {
+                "memberships": {
+                    "affiliation-group": [{
+                        "summaries": [
+                            { "membership-summary": { "put-code": 1, "role-title": "Member" } },
+                            { "membership-summary": { "put-code": 2, "role-title": "Fellow" } }
+                        ]
+                    }]
+                },
+                "peer-reviews": {
+                    "group": [{
+                        "peer-review-group": [
+                            { "peer-review-summary": [{ "put-code": 5 }] },
+                            { "peer-review-summary": [{ "put-code": 6 }] }
+                        ]
+                    }]
+                }
+            }
+        });
+        let author = Author::new_from_json(j);
+        assert_eq!(author.membership_groups().len(), 1);
+        let memberships = author.memberships();
+        assert_eq!(memberships.len(), 2);
+        assert_eq!(memberships[1].role_title(), Some(&"Fellow".to_string()));
+        assert_eq!(author.peer_review_groups()[0].len(), 2);
+        assert_eq!(author.peer_reviews().len(), 2);
+    }
+
+    #[test]
+    fn test_other_affiliation_sections() {
+        let j = json!({
+            "activities-summary": {
+                "invited-positions": {
+                    "affiliation-group": [{
+                        "summaries": [{
+                            "invited-position-summary": { "role-title": "Visiting Professor" }
+                        }]
+                    }]
+                },
+                "distinctions": {
+                    "affiliation-group": [{
+                        "summaries": [{
+                            "distinction-summary": { "role-title": "Best Paper Award" }
+                        }, {
+                            "distinction-summary": { "role-title": "Fellowship" }
+                        }]
+                    }]
+                },
+                "services": {
+                    "affiliation-group": [{
+                        "summaries": [{
+                            "service-summary": {
+                                "role-title": "Program Committee Member",
+                                "organization": { "name": "ACM" }
+                            }
+                        }]
+                    }]
+                }
+            }
+        });
+        let author = Author::new_from_json(j);
+        assert_eq!(
+            author.invited_positions()[0].title(),
+            Some(&"Visiting Professor".to_string())
+        );
+        assert_eq!(author.distinctions().len(), 2);
+        assert_eq!(author.distinction_groups().len(), 1);
+        let services = author.services();
+        assert_eq!(services.len(), 1);
+        assert_eq!(
+            services[0].organization().and_then(|o| o.name()),
+            Some(&"ACM".to_string())
+        );
+    }
+
+    #[test]
+    fn test_research_resources() {
+        let j = json!({
+            "activities-summary": {
+                "research-resources": {
+                    "group": [{
+                        "research-resource-summary": [{
+                            "put-code": 7,
+                            "proposal": {
+                                "title": { "title": { "value": "Beam time" } },
+                                "hosts": { "organization": [{ "name": "Synchrotron" }] }
+                            }
+                        }]
+                    }]
+                }
+            }
+        });
+        let author = Author::new_from_json(j);
+        let resources = author.research_resources();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(
+            resources[0].proposal_title(),
+            Some(&"Beam time".to_string())
+        );
+        assert_eq!(resources[0].hosts().len(), 1);
+    }
+
+    #[test]
+    fn test_record() {
+        let j = json!({
+            "history": { "claimed": true, "creation-method": "DIRECT" },
+            "person": {
+                "emails": { "email": [{ "email": "jdoe@example.com", "primary": true }] }
+            },
+            "activities-summary": {
+                "employments": {
+                    "affiliation-group": [{
+                        "summaries": [{
+                            "employment-summary": {
+                                "start-date": { "year": { "value": "2019" }, "month": { "value": "09" } }
+                            }
+                        }]
+                    }]
+                }
+            }
+        });
+        let author = Author::new_from_json(j.clone());
+        assert_eq!(author.json(), &j);
+        let history = author.record().history.as_ref().unwrap();
+        assert_eq!(history.claimed, Some(true));
+        assert_eq!(history.creation_method.as_deref(), Some("DIRECT"));
+        let emails = author.record().person.as_ref().unwrap().emails.as_ref();
+        assert_eq!(
+            emails.unwrap().email[0].email.as_deref(),
+            Some("jdoe@example.com")
+        );
+        // ORCID sends date parts as strings
+        let start_date = author.employment()[0].start_date().cloned().unwrap();
+        assert_eq!(start_date.year(), 2019);
+        assert_eq!(start_date.month(), Some(9));
+    }
+
+    #[test]
+    fn test_timeline() {
+        let summary = |key: &str, name: &str, start: &str, end: Option<&str>| {
+            let mut summary = json!({
+                "organization": { "name": name },
+                "start-date": { "year": { "value": start } }
+            });
+            if let Some(end) = end {
+                summary["end-date"] = json!({ "year": { "value": end } });
+            }
+            json!({ "summaries": [{ key: summary }] })
+        };
+        let j = json!({
+            "activities-summary": {
+                "employments": { "affiliation-group": [
+                    summary("employment-summary", "Current Corp", "2020", None),
+                    summary("employment-summary", "Old Corp", "2015", Some("2019")),
+                ] },
+                "educations": { "affiliation-group": [
+                    summary("education-summary", "Test U", "2010", Some("2014")),
+                ] },
+                "fundings": { "group": [{ "funding-summary": [{
+                    "title": { "title": { "value": "Grant" } },
+                    "organization": { "name": "Funder" },
+                    "start-date": { "year": { "value": "2019" } },
+                    "end-date": { "year": { "value": "2021" } }
+                }] }] }
+            }
+        });
+        let timeline = Author::new_from_json(j).timeline();
+        assert_eq!(timeline.len(), 4);
+        let names: Vec<&str> = timeline
+            .entries()
+            .iter()
+            .filter_map(|e| e.item().organization().and_then(|o| o.name()))
+            .map(|s| s.as_str())
+            .collect();
+        assert_eq!(names, vec!["Test U", "Old Corp", "Funder", "Current Corp"]);
+
+        let active: Vec<&TimelineEntry> = timeline.active_at("2021-03".parse().unwrap());
+        assert_eq!(active.len(), 2);
+        assert!(active
+            .iter()
+            .any(|e| matches!(e.item(), TimelineItem::Funding(_))));
+
+        let current = timeline.current();
+        assert_eq!(current.len(), 1);
+        assert!(matches!(current[0].item(), TimelineItem::Employment(_)));
+    }
+
+    #[test]
+    fn test_timeline_uses_preferred_summary() {
+        let summary = |display_index: &str, title: &str| {
+            json!({ "employment-summary": {
+                "display-index": display_index,
+                "role-title": title,
+                "organization": { "name": "Test U" },
+                "start-date": { "year": { "value": "2020" } }
+            } })
+        };
+        let j = json!({
+            "activities-summary": {
+                "employments": { "affiliation-group": [{ "summaries": [
+                    summary("0", "Researcher"),
+                    summary("1", "Professor"),
+                ] }] }
+            }
+        });
+        let author = Author::new_from_json(j);
+        assert_eq!(author.employment().len(), 2);
+        let timeline = author.timeline();
+        assert_eq!(timeline.len(), 1);
+        let current = timeline.current();
+        assert_eq!(current.len(), 1);
+        match current[0].item() {
+            TimelineItem::Employment(role) => {
+                assert_eq!(role.title(), Some(&"Professor".to_string()))
+            }
+            _ => panic!("Expected Employment"),
+        }
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let j = json!({
+            "orcid-identifier": { "path": "0000-0001-5916-0947" },
+            "person": { "biography": { "content": "A biography" } }
+        });
+        let author = Author::parse_strict(j).unwrap();
+        assert_eq!(author.biography(), Some("A biography"));
+
+        let j = json!({
+            "orcid-identifier": { "path": "0000-0001-5916-0947" },
+            "person": { "biography": { "content": ["A biography"] } }
+        });
+        assert_eq!(Author::new_from_json(j.clone()).biography(), None);
+        let diagnostics = Author::parse_strict(j).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path(), "person.biography.content");
+        assert_eq!(diagnostics[0].expected(), "a string");
+        assert_eq!(diagnostics[0].actual(), &json!(["A biography"]));
+
+        let j = json!({
+            "activities-summary": { "employments": { "affiliation-group": [{ "summaries": [{
+                "employment-summary": { "start-date": { "year": { "value": "2020" }, "month": { "value": "13" } } }
+            }] }] } }
+        });
+        let diagnostics = Author::parse_strict(j).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].path(),
+            "activities-summary.employments.affiliation-group[0].summaries[0]\
+             .employment-summary.start-date.month.value"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let author = Author::new_from_json(json!({
+            "orcid-identifier": { "path": "0000-0001-5916-0947" },
+            "person": {
+                "name": { "family-name": { "value": "Carberry" } },
+                "not-in-the-schema": true
+            },
+            "activities-summary": {
+                "works": { "group": [{ "work-summary": [{
+                    "put-code": 1,
+                    "display-index": 0,
+                    "title": { "title": { "value": "Toward a Unified Theory" } },
+                    "publication-date": { "year": { "value": "2008" } }
+                }] }] },
+                "employments": { "affiliation-group": [{ "summaries": [{
+                    "employment-summary": { "role-title": "Professor" }
+                }] }] }
+            }
+        }));
+        let serialized = serde_json::to_value(&author).unwrap();
+        assert_eq!(serialized["orcid_id"], "0000-0001-5916-0947");
+        assert_eq!(serialized["family_name"], "Carberry");
+        assert_eq!(
+            serialized["work_groups"][0]["summaries"][0]["title"],
+            "Toward a Unified Theory"
+        );
+        assert_eq!(
+            serialized["employment_groups"][0]["summaries"][0]["title"],
+            "Professor"
+        );
+        let back: Author = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, author);
+        // Parts the typed record does not model survive the round trip
+        assert_eq!(back.json(), author.json());
+        assert_eq!(back.json()["person"]["not-in-the-schema"], true);
+        assert_eq!(back.family_name(), Some("Carberry"));
+        assert_eq!(back.works()[0].metadata.put_code(), Some(1));
+    }
 }
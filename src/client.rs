@@ -1,64 +1,131 @@
 use crate::author::Author;
+use crate::client_builder::ClientBuilder;
+#[cfg(feature = "csv")]
+use crate::csv_search::{self, csv_query_string, CsvColumn, CsvSearchRow};
 use crate::error::{OrcidError, Result};
+use crate::expanded_search_result::ExpandedSearchResult;
+use crate::member_api::{
+    bulk_items, bulk_results, check_response, error_code, json_request, put_code_from_location,
+    with_put_code, BULK_LIMIT,
+};
+use crate::membership::Membership;
+use crate::new_funding::NewFunding;
+use crate::new_peer_review::NewPeerReview;
+use crate::new_work::NewWork;
+use crate::oauth::TokenProvider;
+use crate::orcid_id::OrcidId;
+use crate::qualification::Qualification;
+use crate::research_resource::ResearchResource;
+use crate::role::Role;
 use crate::search_builder::SearchBuilder;
+use crate::search_query::SearchQuery;
+use crate::search_results::{SearchHit, SearchResults};
+use crate::transport::{with_bearer, Method, Request, ReqwestTransport, Response, Transport};
+use crate::work_detail::WorkDetail;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use reqwest::header::ACCEPT;
 use serde_json;
+#[cfg(feature = "csv")]
+use std::io::Write;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct Client {
     api_url: String,
-    client: reqwest::Client,
+    transport: Arc<dyn Transport>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
 }
 
 impl Client {
     pub fn new() -> Self {
+        Self::new_with_parts(
+            ClientBuilder::new().api_url(),
+            Arc::new(ReqwestTransport::default()),
+            None,
+        )
+    }
+
+    pub(crate) fn new_with_parts(
+        api_url: String,
+        transport: Arc<dyn Transport>,
+        token_provider: Option<Arc<dyn TokenProvider>>,
+    ) -> Self {
         Self {
-            api_url: "https://pub.orcid.org/v3.0/".to_string(),
-            client: reqwest::Client::new(),
+            api_url,
+            transport,
+            token_provider,
         }
     }
 
-    async fn get_json_from_api(&self, query: String) -> Result<serde_json::Value> {
-        let url = self.api_url.clone() + &query;
+    /// Returns a `ClientBuilder` to configure environment, timeouts etc.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// The API URL requests are sent to, ending in `/`
+    pub fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    /// The transport requests are sent through, e.g. for OAuth helpers
+    pub fn transport(&self) -> &dyn Transport {
+        self.transport.as_ref()
+    }
+
+    /// Sends a request, attaching a bearer token if a token provider is set.
+    /// A rejected token is dropped and the request retried once with a new one.
+    async fn send(&self, request: Request) -> Result<Response> {
+        let token_provider = match self.token_provider {
+            Some(ref token_provider) => token_provider,
+            None => return self.transport.execute(request).await,
+        };
+        let token = token_provider.access_token(self.transport.as_ref()).await?;
         let response = self
-            .client
-            .get(&url)
-            .header(ACCEPT, "application/json")
-            .send()
+            .transport
+            .execute(with_bearer(request.clone(), &token))
             .await?;
+        if response.status != 401 || !token_provider.invalidate() {
+            return Ok(response);
+        }
+        let token = token_provider.access_token(self.transport.as_ref()).await?;
+        self.transport.execute(with_bearer(request, &token)).await
+    }
+
+    async fn get_json_from_api(&self, query: String) -> Result<serde_json::Value> {
+        let url = self.api_url.clone() + &query;
+        let request = Request::get(&url).with_header(ACCEPT.as_str(), "application/json");
+        self.send(request).await?.json()
+    }
 
-        let json = response.json().await?;
-        Ok(json)
+    #[cfg(feature = "csv")]
+    async fn get_csv_from_api(&self, query: String) -> Result<String> {
+        let url = self.api_url.clone() + &query;
+        let request = Request::get(&url).with_header(ACCEPT.as_str(), "text/csv");
+        let response = self.send(request).await?;
+        if !response.is_success() {
+            return Err(OrcidError::HttpError {
+                status: response.status,
+                body: response.body,
+            });
+        }
+        Ok(response.body)
     }
 
+    /// True if `id` parses as an `OrcidId`
     pub fn is_valid_orcid_id(id: &str) -> bool {
-        let mut digits: Vec<u32> = id
-            .chars()
-            .filter(|c| *c != '-')
-            .filter_map(|c| if c == 'X' { Some(10) } else { c.to_digit(10) })
-            .collect();
-        if digits.len() != 16 {
-            return false;
-        }
-        let last_digit = digits.pop().unwrap(); // unwrap OK
-        let total = digits.iter().fold(0, |total, digit| (total + digit) * 2);
-        let remainder = total % 11;
-        let result = (12 - remainder) % 11;
-        last_digit == result
+        id.parse::<OrcidId>().is_ok()
     }
 
     /// Returns an `Author` for a given ORCID ID
-    pub async fn author(&self, orcid_id: &str) -> Result<Author> {
-        if !Self::is_valid_orcid_id(orcid_id) {
-            return Err(OrcidError::InvalidOrcidId(orcid_id.to_string()));
-        }
+    pub async fn author(&self, orcid_id: impl AsRef<str>) -> Result<Author> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
 
         let json: serde_json::Value = self.get_json_from_api(orcid_id.to_string()).await?;
 
-        match json["error-code"].as_str() {
+        match error_code(&json) {
             Some(error_code) => Err(OrcidError::ApiError {
                 orcid_id: orcid_id.to_string(),
-                error_code: error_code.to_string(),
+                error_code,
                 developer_message: json["developer-message"]
                     .as_str()
                     .unwrap_or("no developer-message")
@@ -68,26 +135,434 @@ impl Client {
         }
     }
 
+    /// POSTs a new activity to `section`, returns the put-code assigned by ORCID
+    async fn add_activity(
+        &self,
+        orcid_id: impl AsRef<str>,
+        section: &str,
+        body: serde_json::Value,
+    ) -> Result<u64> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
+        let url = format!("{}{}/{}", self.api_url, orcid_id, section);
+        let response = self
+            .send(json_request(Method::Post, &url, Some(body)))
+            .await?;
+        put_code_from_location(&check_response(orcid_id.as_str(), response)?)
+    }
+
+    /// PUTs an updated activity to `section/put_code`
+    async fn update_activity(
+        &self,
+        orcid_id: impl AsRef<str>,
+        section: &str,
+        put_code: u64,
+        body: serde_json::Value,
+    ) -> Result<()> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
+        let url = format!("{}{}/{}/{}", self.api_url, orcid_id, section, put_code);
+        let body = with_put_code(body, put_code);
+        let response = self
+            .send(json_request(Method::Put, &url, Some(body)))
+            .await?;
+        check_response(orcid_id.as_str(), response)?;
+        Ok(())
+    }
+
+    /// DELETEs the activity at `section/put_code`
+    async fn delete_activity(
+        &self,
+        orcid_id: impl AsRef<str>,
+        section: &str,
+        put_code: u64,
+    ) -> Result<()> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
+        let url = format!("{}{}/{}/{}", self.api_url, orcid_id, section, put_code);
+        let response = self.send(json_request(Method::Delete, &url, None)).await?;
+        check_response(orcid_id.as_str(), response)?;
+        Ok(())
+    }
+
+    /// Adds a work to a record via the member API, returns the put-code assigned by ORCID.
+    /// Fails with `OrcidError::Conflict` if the record already has a work from the same
+    /// source with the same external IDs.
+    pub async fn add_work(&self, orcid_id: impl AsRef<str>, work: &NewWork) -> Result<u64> {
+        self.add_activity(orcid_id, "work", work.to_json()).await
+    }
+
+    /// Replaces the work with the given put-code
+    pub async fn update_work(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        work: &NewWork,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "work", put_code, work.to_json())
+            .await
+    }
+
+    /// Deletes the work with the given put-code
+    pub async fn delete_work(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<()> {
+        self.delete_activity(orcid_id, "work", put_code).await
+    }
+
+    /// Adds several works, in batches of up to 100. Returns one result per work,
+    /// in order, so that e.g. duplicates do not fail the whole batch.
+    pub async fn add_works(
+        &self,
+        orcid_id: impl AsRef<str>,
+        works: &[NewWork],
+    ) -> Result<Vec<Result<u64>>> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
+        let url = format!("{}{}/works", self.api_url, orcid_id);
+        let mut ret = Vec::new();
+        for chunk in works.chunks(BULK_LIMIT) {
+            let body = serde_json::json!({
+                "bulk": chunk
+                    .iter()
+                    .map(|work| serde_json::json!({ "work": work.to_json() }))
+                    .collect::<Vec<serde_json::Value>>()
+            });
+            let response = self
+                .send(json_request(Method::Post, &url, Some(body)))
+                .await?;
+            let json = check_response(orcid_id.as_str(), response)?.json()?;
+            ret.append(&mut bulk_results(
+                orcid_id.as_str(),
+                "work",
+                &json,
+                chunk.len(),
+            )?);
+        }
+        Ok(ret)
+    }
+
+    /// Returns the full details of the work with the given put-code
+    pub async fn work(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<WorkDetail> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
+        let url = format!("{}{}/work/{}", self.api_url, orcid_id, put_code);
+        let response = self.send(json_request(Method::Get, &url, None)).await?;
+        let json = check_response(orcid_id.as_str(), response)?.json()?;
+        Ok(WorkDetail::new_from_json(&json))
+    }
+
+    /// Returns the full details of several works, fetched in batches of up to 100.
+    /// Returns one result per put-code, in order, so that e.g. a deleted work does
+    /// not fail the whole batch.
+    pub async fn works_detail(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_codes: &[u64],
+    ) -> Result<Vec<Result<WorkDetail>>> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
+        let mut ret = Vec::new();
+        for chunk in put_codes.chunks(BULK_LIMIT) {
+            let put_codes: Vec<String> =
+                chunk.iter().map(|put_code| put_code.to_string()).collect();
+            let url = format!("{}{}/works/{}", self.api_url, orcid_id, put_codes.join(","));
+            let response = self.send(json_request(Method::Get, &url, None)).await?;
+            let json = check_response(orcid_id.as_str(), response)?.json()?;
+            ret.extend(
+                bulk_items(orcid_id.as_str(), "work", &json, chunk.len())?
+                    .into_iter()
+                    .map(|item| item.map(WorkDetail::new_from_json)),
+            );
+        }
+        Ok(ret)
+    }
+
+    /// Returns the research resource with the given put-code, including its resource items
+    pub async fn research_resource(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+    ) -> Result<ResearchResource> {
+        let orcid_id = OrcidId::parse_lenient(orcid_id.as_ref())?;
+        let url = format!(
+            "{}{}/research-resource/{}",
+            self.api_url, orcid_id, put_code
+        );
+        let response = self.send(json_request(Method::Get, &url, None)).await?;
+        let json = check_response(orcid_id.as_str(), response)?.json()?;
+        Ok(ResearchResource::new_from_summary_json(&json))
+    }
+
+    /// Adds an employment to a record via the member API, returns the put-code assigned by ORCID
+    pub async fn add_employment(&self, orcid_id: impl AsRef<str>, role: &Role) -> Result<u64> {
+        self.add_activity(orcid_id, "employment", role.to_json())
+            .await
+    }
+
+    /// Replaces the employment with the given put-code
+    pub async fn update_employment(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        role: &Role,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "employment", put_code, role.to_json())
+            .await
+    }
+
+    /// Deletes the employment with the given put-code
+    pub async fn delete_employment(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<()> {
+        self.delete_activity(orcid_id, "employment", put_code).await
+    }
+
+    /// Adds an education to a record via the member API, returns the put-code assigned by ORCID
+    pub async fn add_education(&self, orcid_id: impl AsRef<str>, role: &Role) -> Result<u64> {
+        self.add_activity(orcid_id, "education", role.to_json())
+            .await
+    }
+
+    /// Replaces the education with the given put-code
+    pub async fn update_education(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        role: &Role,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "education", put_code, role.to_json())
+            .await
+    }
+
+    /// Deletes the education with the given put-code
+    pub async fn delete_education(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<()> {
+        self.delete_activity(orcid_id, "education", put_code).await
+    }
+
+    /// Adds a qualification to a record via the member API, returns the put-code assigned by ORCID
+    pub async fn add_qualification(
+        &self,
+        orcid_id: impl AsRef<str>,
+        qualification: &Qualification,
+    ) -> Result<u64> {
+        self.add_activity(orcid_id, "qualification", qualification.to_json())
+            .await
+    }
+
+    /// Replaces the qualification with the given put-code
+    pub async fn update_qualification(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        qualification: &Qualification,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "qualification", put_code, qualification.to_json())
+            .await
+    }
+
+    /// Deletes the qualification with the given put-code
+    pub async fn delete_qualification(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+    ) -> Result<()> {
+        self.delete_activity(orcid_id, "qualification", put_code)
+            .await
+    }
+
+    /// Adds a membership to a record via the member API, returns the put-code assigned by ORCID
+    pub async fn add_membership(
+        &self,
+        orcid_id: impl AsRef<str>,
+        membership: &Membership,
+    ) -> Result<u64> {
+        self.add_activity(orcid_id, "membership", membership.to_json())
+            .await
+    }
+
+    /// Replaces the membership with the given put-code
+    pub async fn update_membership(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        membership: &Membership,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "membership", put_code, membership.to_json())
+            .await
+    }
+
+    /// Deletes the membership with the given put-code
+    pub async fn delete_membership(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<()> {
+        self.delete_activity(orcid_id, "membership", put_code).await
+    }
+
+    /// Adds a service to a record via the member API, returns the put-code assigned by ORCID
+    pub async fn add_service(&self, orcid_id: impl AsRef<str>, role: &Role) -> Result<u64> {
+        self.add_activity(orcid_id, "service", role.to_json()).await
+    }
+
+    /// Replaces the service with the given put-code
+    pub async fn update_service(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        role: &Role,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "service", put_code, role.to_json())
+            .await
+    }
+
+    /// Deletes the service with the given put-code
+    pub async fn delete_service(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<()> {
+        self.delete_activity(orcid_id, "service", put_code).await
+    }
+
+    /// Adds a funding to a record via the member API, returns the put-code assigned by ORCID
+    pub async fn add_funding(
+        &self,
+        orcid_id: impl AsRef<str>,
+        funding: &NewFunding,
+    ) -> Result<u64> {
+        self.add_activity(orcid_id, "funding", funding.to_json())
+            .await
+    }
+
+    /// Replaces the funding with the given put-code
+    pub async fn update_funding(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        funding: &NewFunding,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "funding", put_code, funding.to_json())
+            .await
+    }
+
+    /// Deletes the funding with the given put-code
+    pub async fn delete_funding(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<()> {
+        self.delete_activity(orcid_id, "funding", put_code).await
+    }
+
+    /// Adds a peer review to a record via the member API, returns the put-code assigned by ORCID
+    pub async fn add_peer_review(
+        &self,
+        orcid_id: impl AsRef<str>,
+        peer_review: &NewPeerReview,
+    ) -> Result<u64> {
+        self.add_activity(orcid_id, "peer-review", peer_review.to_json())
+            .await
+    }
+
+    /// Replaces the peer review with the given put-code
+    pub async fn update_peer_review(
+        &self,
+        orcid_id: impl AsRef<str>,
+        put_code: u64,
+        peer_review: &NewPeerReview,
+    ) -> Result<()> {
+        self.update_activity(orcid_id, "peer-review", put_code, peer_review.to_json())
+            .await
+    }
+
+    /// Deletes the peer review with the given put-code
+    pub async fn delete_peer_review(&self, orcid_id: impl AsRef<str>, put_code: u64) -> Result<()> {
+        self.delete_activity(orcid_id, "peer-review", put_code)
+            .await
+    }
+
     /// Takes a DOI, quotes and searches it, returns a Vec<String> of ORCID IDs
     pub async fn search_doi(&self, doi: &str) -> Result<Vec<String>> {
         self.search(&("\"".to_string() + doi + "\"")).await
     }
 
-    /// Takes a search query, returns a Vec<String> of ORCID IDs
+    /// Takes a search query, returns a Vec<String> of the ORCID IDs on the first page
     pub async fn search(&self, query: &str) -> Result<Vec<String>> {
-        let encoded_query = urlencoding::encode(query);
-        let json: serde_json::Value = self
-            .get_json_from_api(format!("search?q={}", encoded_query))
+        let results = self.search_query(&SearchQuery::new(query)).await?;
+        Ok(results
+            .into_orcid_ids()
+            .iter()
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    /// Returns one page of results for a query, with the total number of matches
+    pub async fn search_query(&self, query: &SearchQuery) -> Result<SearchResults> {
+        self.search_page(query).await
+    }
+
+    /// A stream of the ORCID IDs of all pages of a query, fetching pages as needed.
+    /// The page size is the query's `rows`, and the stream ends after ORCID's 10,000 results.
+    pub fn search_stream(&self, query: impl Into<SearchQuery>) -> BoxStream<'_, Result<OrcidId>> {
+        self.search_pages(query.into())
+    }
+
+    /// Returns one page of `expanded-search` results, with names, emails and institutions
+    pub async fn expanded_search(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<SearchResults<ExpandedSearchResult>> {
+        self.search_page(query).await
+    }
+
+    /// A stream of the `expanded-search` results of all pages of a query, like `search_stream`
+    pub fn expanded_search_stream(
+        &self,
+        query: impl Into<SearchQuery>,
+    ) -> BoxStream<'_, Result<ExpandedSearchResult>> {
+        self.search_pages(query.into())
+    }
+
+    /// Returns one page of `csv-search` results with the given columns
+    #[cfg(feature = "csv")]
+    pub async fn csv_search(
+        &self,
+        query: &SearchQuery,
+        columns: &[CsvColumn],
+    ) -> Result<Vec<CsvSearchRow>> {
+        let body = self
+            .get_csv_from_api(format!("csv-search?{}", csv_query_string(query, columns)?))
             .await?;
+        csv_search::parse_rows(&body, columns)
+    }
 
-        match json["result"].as_array() {
-            Some(res) => Ok(res
-                .iter()
-                .filter_map(|x| x["orcid-identifier"]["path"].as_str())
-                .map(|s| s.to_string())
-                .collect()),
-            None => Err(OrcidError::BadApiResponse(json)),
+    /// Writes the raw CSV of all pages of a query to `writer`, one header line first,
+    /// fetching the next page only after the previous one is written. Returns the number of rows.
+    #[cfg(feature = "csv")]
+    pub async fn csv_search_to_writer(
+        &self,
+        query: impl Into<SearchQuery>,
+        columns: &[CsvColumn],
+        mut writer: impl Write,
+    ) -> Result<usize> {
+        let mut next = Some(query.into());
+        let mut total = 0;
+        while let Some(query) = next {
+            let body = self
+                .get_csv_from_api(format!("csv-search?{}", csv_query_string(&query, columns)?))
+                .await?;
+            let (rows, after) = csv_search::write_page(&query, &body, total == 0, &mut writer)?;
+            total += rows;
+            next = after;
         }
+        writer.flush()?;
+        Ok(total)
+    }
+
+    /// Fetches one page from the endpoint of `T`
+    async fn search_page<T: SearchHit>(&self, query: &SearchQuery) -> Result<SearchResults<T>> {
+        query.validate()?;
+        let json = self
+            .get_json_from_api(format!("{}?{}", T::ENDPOINT, query.to_query_string()))
+            .await?;
+        SearchResults::new_from_json(&json)
+    }
+
+    fn search_pages<T: SearchHit + Send + 'static>(
+        &self,
+        query: SearchQuery,
+    ) -> BoxStream<'_, Result<T>> {
+        stream::try_unfold(Some(query), move |query| async move {
+            let Some(query) = query else {
+                return Ok(None);
+            };
+            let results = self.search_page::<T>(&query).await?;
+            let next = query.next_page(&results);
+            Result::Ok(Some((results.into_items(), next)))
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
     }
 
     /// Create a search builder for constructing complex searches
@@ -105,6 +580,10 @@ impl Default for Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client_builder::Environment;
+    use crate::oauth::UserToken;
+    use crate::transport::{FixtureTransport, Method, Response};
+    use serde_json::json;
 
     #[test]
     fn test_new() {
@@ -133,6 +612,8 @@ mod tests {
         ));
         assert!(!Client::is_valid_orcid_id("12345"));
         assert!(!Client::is_valid_orcid_id("xyz"));
+        assert!(!Client::is_valid_orcid_id("00000000000000é"));
+        assert!(!Client::is_valid_orcid_id("0000-0000-0000-00é"));
     }
 
     #[test]
@@ -187,23 +668,721 @@ mod tests {
         }
     }
 
+    fn fixture_client(fixture: &FixtureTransport) -> Client {
+        ClientBuilder::new()
+            .transport(Arc::new(fixture.clone()))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_author_from_fixture() {
+        let fixture = FixtureTransport::new().with_json(
+            "0000-0001-5916-0947",
+            json!({
+                "orcid-identifier": { "path": "0000-0001-5916-0947" },
+                "person": { "name": { "family-name": { "value": "Manske" } } }
+            }),
+        );
+        let client = fixture_client(&fixture);
+        let author = client.author("0000-0001-5916-0947").await.unwrap();
+        assert_eq!(author.orcid_id(), Some("0000-0001-5916-0947"));
+        assert_eq!(author.family_name(), Some("Manske"));
+
+        let requests = fixture.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].url,
+            "https://pub.orcid.org/v3.0/0000-0001-5916-0947"
+        );
+        assert_eq!(requests[0].header("accept"), Some("application/json"));
+    }
+
+    #[tokio::test]
+    async fn test_author_normalizes_orcid_id() {
+        let fixture = FixtureTransport::new().with_json(
+            "0000-0001-5916-0947",
+            json!({ "orcid-identifier": { "path": "0000-0001-5916-0947" } }),
+        );
+        let client = fixture_client(&fixture);
+        client
+            .author("https://orcid.org/0000000159160947")
+            .await
+            .unwrap();
+        let orcid_id: OrcidId = "0000-0001-5916-0947".parse().unwrap();
+        client.author(&orcid_id).await.unwrap();
+        client.author(orcid_id).await.unwrap();
+        let requests = fixture.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests
+            .iter()
+            .all(|r| r.url == "https://pub.orcid.org/v3.0/0000-0001-5916-0947"));
+    }
+
+    #[tokio::test]
+    async fn test_author_accepts_lowercase_x() {
+        let fixture = FixtureTransport::new().with_json(
+            "0000-0002-1694-233X",
+            json!({ "orcid-identifier": { "path": "0000-0002-1694-233X" } }),
+        );
+        let client = fixture_client(&fixture);
+        client.author("0000-0002-1694-233x").await.unwrap();
+        assert_eq!(
+            fixture.requests()[0].url,
+            "https://pub.orcid.org/v3.0/0000-0002-1694-233X"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_author_api_error() {
+        let fixture = FixtureTransport::new().with_response(
+            Method::Get,
+            "0000-0002-1825-0097",
+            Response::new(
+                404,
+                r#"{"response-code":404,"developer-message":"Not found","error-code":9016}"#,
+            ),
+        );
+        let client = fixture_client(&fixture);
+        match client.author("0000-0002-1825-0097").await {
+            Err(OrcidError::ApiError {
+                orcid_id,
+                error_code,
+                developer_message,
+            }) => {
+                assert_eq!(orcid_id, "0000-0002-1825-0097");
+                assert_eq!(error_code, "9016");
+                assert_eq!(developer_message, "Not found");
+            }
+            _ => panic!("Expected ApiError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_author_http_error() {
+        let client = fixture_client(&FixtureTransport::new());
+        match client.author("0000-0002-1825-0097").await {
+            Err(OrcidError::HttpError { status, .. }) => assert_eq!(status, 404),
+            _ => panic!("Expected HttpError"),
+        }
+    }
+
     #[tokio::test]
     async fn test_search_with_empty_query() {
-        let client = Client::new();
+        let fixture = FixtureTransport::new().with_json(
+            "search?q=",
+            json!({
+                "result": [
+                    { "orcid-identifier": { "path": "0000-0001-5916-0947" } },
+                    { "orcid-identifier": { "path": "0000-0002-1825-0097" } }
+                ],
+                "num-found": 2
+            }),
+        );
+        let client = fixture_client(&fixture);
+        let result = client.search("").await.unwrap();
+        assert_eq!(result, vec!["0000-0001-5916-0947", "0000-0002-1825-0097"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_bad_response() {
+        let fixture =
+            FixtureTransport::new().with_json("search?q=x", json!({ "unexpected": true }));
+        let client = fixture_client(&fixture);
+        match client.search("x").await {
+            Err(OrcidError::BadApiResponse(_)) => (),
+            _ => panic!("Expected BadApiResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_query_pagination() {
+        let fixture = FixtureTransport::new().with_json(
+            "search?q=family-name%3ASmith&rows=2&start=4",
+            json!({
+                "result": [{ "orcid-identifier": { "path": "0000-0001-5916-0947" } }],
+                "num-found": 5
+            }),
+        );
+        let client = fixture_client(&fixture);
+        let query = SearchQuery::new("family-name:Smith")
+            .with_rows(2)
+            .with_start(4);
+        let results = client.search_query(&query).await.unwrap();
+        assert_eq!(results.num_found(), 5);
+        assert_eq!(results.len(), 1);
 
-        // Test that empty search doesn't panic
-        // (In a real test, we'd mock the API response)
-        let _result = client.search("").await;
-        // We don't assert on the result as it would make a real API call
+        // Out of range pages fail without a request
+        let query = SearchQuery::new("family-name:Smith").with_rows(5000);
+        match client.search_query(&query).await {
+            Err(OrcidError::InvalidSearch(_)) => (),
+            _ => panic!("Expected InvalidSearch"),
+        }
+        assert_eq!(fixture.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_stream() {
+        let page = |ids: &[&str]| {
+            json!({
+                "result": ids
+                    .iter()
+                    .map(|id| json!({ "orcid-identifier": { "path": id } }))
+                    .collect::<Vec<_>>(),
+                "num-found": 3
+            })
+        };
+        let fixture = FixtureTransport::new()
+            .with_json(
+                "search?q=x&rows=2",
+                page(&["0000-0001-5916-0947", "0000-0002-1825-0097"]),
+            )
+            .with_json("search?q=x&rows=2&start=2", page(&["0000-0002-1694-233X"]));
+        let client = fixture_client(&fixture);
+        let stream = client.search_stream(SearchQuery::new("x").with_rows(2));
+        assert!(fixture.requests().is_empty()); // Nothing is fetched before polling
+        let ids: Vec<String> = stream.map(|id| id.unwrap().to_string()).collect().await;
+        assert_eq!(
+            ids,
+            vec![
+                "0000-0001-5916-0947",
+                "0000-0002-1825-0097",
+                "0000-0002-1694-233X"
+            ]
+        );
+        assert_eq!(fixture.requests().len(), 2);
+
+        // Taking the first result fetches only the first page
+        let first = client
+            .search_stream(SearchQuery::new("x").with_rows(2))
+            .next()
+            .await;
+        assert_eq!(first.unwrap().unwrap().as_str(), "0000-0001-5916-0947");
+        assert_eq!(fixture.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_search_stream_error() {
+        let fixture =
+            FixtureTransport::new().with_json("search?q=x", json!({ "unexpected": true }));
+        let client = fixture_client(&fixture);
+        let results: Vec<Result<OrcidId>> = client.search_stream("x").collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(OrcidError::BadApiResponse(_))));
+    }
+
+    #[cfg(feature = "csv")]
+    #[tokio::test]
+    async fn test_csv_search() {
+        let fixture = FixtureTransport::new().with_response(
+            Method::Get,
+            "csv-search?q=x&fl=orcid,family-name",
+            Response::new(200, "orcid,family-name\n0000-0002-1825-0097,Carberry\n"),
+        );
+        let client = fixture_client(&fixture);
+        let rows = client
+            .csv_search(
+                &SearchQuery::new("x"),
+                &[CsvColumn::Orcid, CsvColumn::FamilyName],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].family_name(), Some(&"Carberry".to_string()));
+        assert_eq!(fixture.requests()[0].header("Accept"), Some("text/csv"));
+    }
+
+    #[cfg(feature = "csv")]
+    #[tokio::test]
+    async fn test_csv_search_to_writer() {
+        let fixture = FixtureTransport::new()
+            .with_response(
+                Method::Get,
+                "csv-search?q=x&rows=2&fl=orcid",
+                Response::new(200, "orcid\n0000-0001-5916-0947\n0000-0002-1825-0097\n"),
+            )
+            .with_response(
+                Method::Get,
+                "csv-search?q=x&rows=2&start=2&fl=orcid",
+                Response::new(200, "orcid\n0000-0002-1694-233X\n"),
+            );
+        let client = fixture_client(&fixture);
+        let mut out = Vec::new();
+        let rows = client
+            .csv_search_to_writer(
+                SearchQuery::new("x").with_rows(2),
+                &[CsvColumn::Orcid],
+                &mut out,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows, 3);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "orcid\n0000-0001-5916-0947\n0000-0002-1825-0097\n0000-0002-1694-233X\n"
+        );
+        assert_eq!(fixture.requests().len(), 2);
     }
 
     #[tokio::test]
     async fn test_search_doi_adds_quotes() {
-        let client = Client::new();
+        let fixture = FixtureTransport::new().with_json(
+            "search?q=%2210.1234%2Ftest%22",
+            json!({ "result": [], "num-found": 0 }),
+        );
+        let client = fixture_client(&fixture);
+        let result = client.search_doi("10.1234/test").await.unwrap();
+        assert!(result.is_empty());
+        assert_eq!(
+            fixture.requests()[0].url,
+            "https://pub.orcid.org/v3.0/search?q=%2210.1234%2Ftest%22"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials_attach_bearer_token() {
+        let fixture = FixtureTransport::new()
+            .with_response(
+                Method::Post,
+                "https://orcid.org/oauth/token",
+                Response::new(200, r#"{"access_token":"tok","expires_in":3600}"#),
+            )
+            .with_json("search?q=x", json!({ "result": [] }));
+        let client = ClientBuilder::new()
+            .transport(Arc::new(fixture.clone()))
+            .client_credentials("APP-1", "secret")
+            .build()
+            .unwrap();
+        client.search("x").await.unwrap();
+        client.search("x").await.unwrap();
+
+        let requests = fixture.requests();
+        assert_eq!(requests.len(), 3); // One token request, two searches
+        assert_eq!(requests[0].url, "https://orcid.org/oauth/token");
+        assert_eq!(requests[1].header("authorization"), Some("Bearer tok"));
+        assert_eq!(requests[2].header("authorization"), Some("Bearer tok"));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_token_is_refreshed_once() {
+        let fixture = FixtureTransport::new()
+            .with_response(
+                Method::Post,
+                "https://orcid.org/oauth/token",
+                Response::new(200, r#"{"access_token":"tok"}"#),
+            )
+            .with_response(
+                Method::Get,
+                "search?q=x",
+                Response::new(401, "Unauthorized"),
+            );
+        let client = ClientBuilder::new()
+            .transport(Arc::new(fixture.clone()))
+            .client_credentials("APP-1", "secret")
+            .build()
+            .unwrap();
+        match client.search("x").await {
+            Err(OrcidError::HttpError { status, .. }) => assert_eq!(status, 401),
+            _ => panic!("Expected HttpError"),
+        }
+        assert_eq!(fixture.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_user_token_attach_bearer_token() {
+        let fixture = FixtureTransport::new()
+            .with_base_url("https://api.orcid.org/v3.0/")
+            .with_json("0000-0001-2345-6789", json!({}));
+        let client = ClientBuilder::new()
+            .environment(Environment::Member)
+            .transport(Arc::new(fixture.clone()))
+            .user_token(UserToken::new("user-tok", "0000-0001-2345-6789"))
+            .build()
+            .unwrap();
+        client.author("0000-0001-2345-6789").await.unwrap();
+        let requests = fixture.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].header("authorization"), Some("Bearer user-tok"));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_user_token_is_not_retried() {
+        let fixture = FixtureTransport::new()
+            .with_base_url("https://api.orcid.org/v3.0/")
+            .with_response(
+                Method::Get,
+                "0000-0001-2345-6789",
+                Response::new(401, "Unauthorized"),
+            );
+        let client = ClientBuilder::new()
+            .environment(Environment::Member)
+            .transport(Arc::new(fixture.clone()))
+            .user_token(UserToken::new("user-tok", "0000-0001-2345-6789"))
+            .build()
+            .unwrap();
+        assert!(client.author("0000-0001-2345-6789").await.is_err());
+        assert_eq!(fixture.requests().len(), 1);
+    }
 
-        // Test that DOI search adds quotes
-        // (In a real test, we'd mock the API to verify the query)
-        let _result = client.search_doi("10.1234/test").await;
-        // We don't assert on the result as it would make a real API call
+    const MEMBER_API: &str = "https://api.orcid.org/v3.0/";
+    const RECORD: &str = "0000-0001-2345-6789";
+
+    fn member_client(fixture: &FixtureTransport) -> Client {
+        ClientBuilder::new()
+            .environment(Environment::Member)
+            .transport(Arc::new(fixture.clone()))
+            .user_token(UserToken::new("user-tok", RECORD))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_add_work() {
+        let fixture = FixtureTransport::new()
+            .with_base_url(MEMBER_API)
+            .with_response(
+                Method::Post,
+                &format!("{}/work", RECORD),
+                Response::new(201, "")
+                    .with_header("Location", &format!("{}{}/work/733536", MEMBER_API, RECORD)),
+            );
+        let client = member_client(&fixture);
+        let work = NewWork::new("Test", "journal-article").with_external_id("doi", "10.1/x");
+        assert_eq!(client.add_work(RECORD, &work).await.unwrap(), 733536);
+
+        let request = &fixture.requests()[0];
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        assert_eq!(request.header("authorization"), Some("Bearer user-tok"));
+        let body: serde_json::Value = serde_json::from_str(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(body, work.to_json());
+    }
+
+    #[tokio::test]
+    async fn test_add_work_conflict() {
+        let fixture = FixtureTransport::new()
+            .with_base_url(MEMBER_API)
+            .with_response(
+                Method::Post,
+                &format!("{}/work", RECORD),
+                Response::new(
+                    409,
+                    r#"{"response-code":409,"developer-message":"409 Conflict: duplicate","error-code":9021}"#,
+                ),
+            );
+        let client = member_client(&fixture);
+        match client.add_work(RECORD, &NewWork::new("Test", "book")).await {
+            Err(OrcidError::Conflict(message)) => assert!(message.contains("duplicate")),
+            _ => panic!("Expected Conflict"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_work() {
+        let fixture = FixtureTransport::new()
+            .with_base_url(MEMBER_API)
+            .with_response(
+                Method::Put,
+                &format!("{}/work/733536", RECORD),
+                Response::new(200, "{}"),
+            )
+            .with_response(
+                Method::Delete,
+                &format!("{}/work/733536", RECORD),
+                Response::new(204, ""),
+            );
+        let client = member_client(&fixture);
+        client
+            .update_work(RECORD, 733536, &NewWork::new("Test", "book"))
+            .await
+            .unwrap();
+        client.delete_work(RECORD, 733536).await.unwrap();
+
+        let requests = fixture.requests();
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["put-code"], 733536);
+        assert_eq!(requests[1].method, Method::Delete);
+        assert!(requests[1].body.is_none());
+
+        match client.delete_work("invalid", 1).await {
+            Err(OrcidError::InvalidOrcidId(_)) => (),
+            _ => panic!("Expected InvalidOrcidId"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_works_in_batches() {
+        let bulk_response = |put_codes: std::ops::Range<u64>| {
+            Response::new(
+                200,
+                &json!({
+                    "bulk": put_codes.map(|i| json!({ "work": { "put-code": i } })).collect::<Vec<_>>()
+                })
+                .to_string(),
+            )
+        };
+        let path = format!("{}/works", RECORD);
+        let fixture = FixtureTransport::new()
+            .with_base_url(MEMBER_API)
+            .with_response(Method::Post, &path, bulk_response(0..100))
+            .with_response(Method::Post, &path, bulk_response(100..150));
+        let client = member_client(&fixture);
+        let works: Vec<NewWork> = (0..150)
+            .map(|i| NewWork::new(&format!("Work {}", i), "book"))
+            .collect();
+        let results = client.add_works(RECORD, &works).await.unwrap();
+        assert_eq!(results.len(), 150);
+        assert_eq!(*results[149].as_ref().unwrap(), 149);
+        assert_eq!(fixture.requests().len(), 2);
+        let body: serde_json::Value =
+            serde_json::from_str(fixture.requests()[1].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["bulk"].as_array().unwrap().len(), 50);
+        assert_eq!(
+            body["bulk"][0]["work"]["title"]["title"]["value"],
+            "Work 100"
+        );
+
+        // A response with fewer results than works is rejected
+        let fixture = FixtureTransport::new()
+            .with_base_url(MEMBER_API)
+            .with_response(Method::Post, &path, bulk_response(0..100));
+        let client = member_client(&fixture);
+        match client.add_works(RECORD, &works[..50]).await {
+            Err(OrcidError::BadApiResponse(_)) => (),
+            _ => panic!("Expected BadApiResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_affiliation_sections() {
+        let mut fixture = FixtureTransport::new().with_base_url(MEMBER_API);
+        for section in [
+            "employment",
+            "education",
+            "qualification",
+            "membership",
+            "service",
+        ] {
+            fixture = fixture
+                .with_response(
+                    Method::Post,
+                    &format!("{}/{}", RECORD, section),
+                    Response::new(201, "").with_header(
+                        "Location",
+                        &format!("{}{}/{}/7", MEMBER_API, RECORD, section),
+                    ),
+                )
+                .with_response(
+                    Method::Put,
+                    &format!("{}/{}/7", RECORD, section),
+                    Response::new(200, "{}"),
+                )
+                .with_response(
+                    Method::Delete,
+                    &format!("{}/{}/7", RECORD, section),
+                    Response::new(204, ""),
+                );
+        }
+        let client = member_client(&fixture);
+
+        let mut role = Role::new();
+        role.set_title(Some("Professor".to_string()));
+        let mut organization = crate::Organization::new("Test University");
+        organization.set_disambiguated_organization(Some(
+            crate::OrgIdentifier::new("ROR", "https://ror.org/03yrm5c26").unwrap(),
+        ));
+        role.set_organization(Some(organization));
+        assert_eq!(client.add_employment(RECORD, &role).await.unwrap(), 7);
+        client.update_employment(RECORD, 7, &role).await.unwrap();
+        client.delete_employment(RECORD, 7).await.unwrap();
+        assert_eq!(client.add_education(RECORD, &role).await.unwrap(), 7);
+        client.update_education(RECORD, 7, &role).await.unwrap();
+        client.delete_education(RECORD, 7).await.unwrap();
+        assert_eq!(client.add_service(RECORD, &role).await.unwrap(), 7);
+        client.update_service(RECORD, 7, &role).await.unwrap();
+        client.delete_service(RECORD, 7).await.unwrap();
+
+        let qualification = Qualification::new();
+        assert_eq!(
+            client
+                .add_qualification(RECORD, &qualification)
+                .await
+                .unwrap(),
+            7
+        );
+        client
+            .update_qualification(RECORD, 7, &qualification)
+            .await
+            .unwrap();
+        client.delete_qualification(RECORD, 7).await.unwrap();
+
+        let membership = Membership::new();
+        assert_eq!(client.add_membership(RECORD, &membership).await.unwrap(), 7);
+        client
+            .update_membership(RECORD, 7, &membership)
+            .await
+            .unwrap();
+        client.delete_membership(RECORD, 7).await.unwrap();
+
+        let requests = fixture.requests();
+        assert_eq!(requests.len(), 15);
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["role-title"], "Professor");
+        assert_eq!(
+            body["organization"]["disambiguated-organization"]["disambiguation-source"],
+            "ROR"
+        );
+        let body: serde_json::Value =
+            serde_json::from_str(requests[1].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["put-code"], 7);
+    }
+
+    #[tokio::test]
+    async fn test_funding_and_peer_review() {
+        let mut fixture = FixtureTransport::new().with_base_url(MEMBER_API);
+        for section in ["funding", "peer-review"] {
+            fixture = fixture
+                .with_response(
+                    Method::Post,
+                    &format!("{}/{}", RECORD, section),
+                    Response::new(201, "").with_header(
+                        "Location",
+                        &format!("{}{}/{}/11", MEMBER_API, RECORD, section),
+                    ),
+                )
+                .with_response(
+                    Method::Put,
+                    &format!("{}/{}/11", RECORD, section),
+                    Response::new(200, "{}"),
+                )
+                .with_response(
+                    Method::Delete,
+                    &format!("{}/{}/11", RECORD, section),
+                    Response::new(204, ""),
+                );
+        }
+        let client = member_client(&fixture);
+
+        let funding = NewFunding::new("Grant", "grant", crate::Organization::new("NSF"))
+            .with_grant_number("NSF-1");
+        assert_eq!(client.add_funding(RECORD, &funding).await.unwrap(), 11);
+        client.update_funding(RECORD, 11, &funding).await.unwrap();
+        client.delete_funding(RECORD, 11).await.unwrap();
+
+        let review = NewPeerReview::new(
+            "reviewer",
+            "review",
+            "issn:0028-0836",
+            crate::Organization::new("Nature"),
+        );
+        assert_eq!(client.add_peer_review(RECORD, &review).await.unwrap(), 11);
+        client
+            .update_peer_review(RECORD, 11, &review)
+            .await
+            .unwrap();
+        client.delete_peer_review(RECORD, 11).await.unwrap();
+
+        let requests = fixture.requests();
+        assert_eq!(requests.len(), 6);
+        assert_eq!(
+            requests[3].url,
+            format!("{}{}/peer-review", MEMBER_API, RECORD)
+        );
+        let body: serde_json::Value =
+            serde_json::from_str(requests[4].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["put-code"], 11);
+        assert_eq!(body["review-group-id"], "issn:0028-0836");
+    }
+
+    #[tokio::test]
+    async fn test_work() {
+        let fixture = FixtureTransport::new().with_json(
+            &format!("{}/work/42", RECORD),
+            json!({
+                "put-code": 42,
+                "title": { "title": { "value": "A study of things" } },
+                "citation": { "citation-type": "bibtex", "citation-value": "@article{x}" },
+                "contributors": { "contributor": [{
+                    "credit-name": { "value": "Josiah Carberry" },
+                    "contributor-attributes": { "contributor-role": "author" }
+                }] }
+            }),
+        );
+        let client = fixture_client(&fixture);
+        let work = client.work(RECORD, 42).await.unwrap();
+        assert_eq!(work.put_code(), Some(42));
+        assert_eq!(work.title(), Some(&"A study of things".to_string()));
+        assert_eq!(
+            work.contributors()[0].name(),
+            Some(&"Josiah Carberry".to_string())
+        );
+        assert!(matches!(
+            client.work(RECORD, 43).await,
+            Err(OrcidError::HttpError { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_works_detail_in_batches() {
+        let first: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        let fixture = FixtureTransport::new()
+            .with_json(
+                &format!("{}/works/{}", RECORD, first.join(",")),
+                json!({
+                    "bulk": (0..100).map(|i| json!({ "work": { "put-code": i } })).collect::<Vec<_>>()
+                }),
+            )
+            .with_json(
+                &format!("{}/works/100,101", RECORD),
+                json!({
+                    "bulk": [
+                        { "work": { "put-code": 100 } },
+                        { "error": { "response-code": 404, "developer-message": "Not found", "error-code": 9016 } }
+                    ]
+                }),
+            );
+        let client = fixture_client(&fixture);
+        let put_codes: Vec<u64> = (0..102).collect();
+        let works = client.works_detail(RECORD, &put_codes).await.unwrap();
+        assert_eq!(works.len(), 102);
+        assert_eq!(fixture.requests().len(), 2);
+        assert_eq!(works[100].as_ref().unwrap().put_code(), Some(100));
+        match &works[101] {
+            Err(OrcidError::ApiError { error_code, .. }) => assert_eq!(error_code, "9016"),
+            _ => panic!("Expected ApiError"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_works_detail_short_response() {
+        let fixture = FixtureTransport::new().with_json(
+            &format!("{}/works/1,2", RECORD),
+            json!({ "bulk": [{ "work": { "put-code": 2 } }] }),
+        );
+        let client = fixture_client(&fixture);
+        match client.works_detail(RECORD, &[1, 2]).await {
+            Err(OrcidError::BadApiResponse(_)) => (),
+            _ => panic!("Expected BadApiResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_research_resource() {
+        let fixture = FixtureTransport::new().with_json(
+            &format!("{}/research-resource/7", RECORD),
+            json!({
+                "put-code": 7,
+                "proposal": { "title": { "title": { "value": "Beam time" } } },
+                "resource-item": [{ "resource-name": "Beamline ID23" }]
+            }),
+        );
+        let client = fixture_client(&fixture);
+        let resource = client.research_resource(RECORD, 7).await.unwrap();
+        assert_eq!(resource.proposal_title(), Some(&"Beam time".to_string()));
+        assert_eq!(
+            resource.resource_items()[0].name(),
+            Some(&"Beamline ID23".to_string())
+        );
     }
 }
@@ -0,0 +1,341 @@
+use crate::client::Client;
+use crate::client_blocking::ClientBlocking;
+use crate::error::Result;
+use crate::oauth::{ClientCredentials, TokenProvider, UserToken};
+use crate::transport::{BlockingTransport, ReqwestBlockingTransport, ReqwestTransport, Transport};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The API version used when none is set explicitly
+pub const DEFAULT_API_VERSION: &str = "v3.0";
+
+/// The ORCID installation a client talks to
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Environment {
+    /// The production public API, `https://pub.orcid.org/`
+    #[default]
+    Production,
+    /// The sandbox public API, `https://pub.sandbox.orcid.org/`
+    Sandbox,
+    /// The production member API, `https://api.orcid.org/`
+    Member,
+    /// The sandbox member API, `https://api.sandbox.orcid.org/`
+    MemberSandbox,
+    /// Any other base URL, e.g. a caching proxy or a local mock server
+    Custom(String),
+}
+
+impl Environment {
+    /// Returns the base URL of the environment, always ending in `/`
+    pub fn base_url(&self) -> String {
+        match self {
+            Environment::Production => "https://pub.orcid.org/".to_string(),
+            Environment::Sandbox => "https://pub.sandbox.orcid.org/".to_string(),
+            Environment::Member => "https://api.orcid.org/".to_string(),
+            Environment::MemberSandbox => "https://api.sandbox.orcid.org/".to_string(),
+            Environment::Custom(url) => {
+                if url.ends_with('/') {
+                    url.to_string()
+                } else {
+                    format!("{}/", url)
+                }
+            }
+        }
+    }
+
+    /// Returns the base URL of the OAuth endpoints, always ending in `/`.
+    /// For custom environments, this is the custom base URL.
+    pub fn oauth_base_url(&self) -> String {
+        match self {
+            Environment::Production | Environment::Member => "https://orcid.org/".to_string(),
+            Environment::Sandbox | Environment::MemberSandbox => {
+                "https://sandbox.orcid.org/".to_string()
+            }
+            Environment::Custom(_) => self.base_url(),
+        }
+    }
+
+    /// Returns the URL of the OAuth token endpoint
+    pub fn token_url(&self) -> String {
+        self.oauth_base_url() + "oauth/token"
+    }
+}
+
+/// Configures and builds a `Client` or a `ClientBlocking`
+///
+/// # Example
+///
+/// ```
+/// use orcid::{ClientBuilder, Environment};
+/// use std::time::Duration;
+///
+/// let client = ClientBuilder::new()
+///     .environment(Environment::Sandbox)
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my-tool/1.0")
+///     .build()
+///     .unwrap();
+/// assert_eq!(client.api_url(), "https://pub.sandbox.orcid.org/v3.0/");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    environment: Environment,
+    api_version: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    transport: Option<Arc<dyn Transport>>,
+    blocking_transport: Option<Arc<dyn BlockingTransport>>,
+    client_credentials: Option<(String, String)>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the ORCID environment (default: production public API)
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Use a custom base URL; shorthand for `environment(Environment::Custom(..))`
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.environment = Environment::Custom(base_url.to_string());
+        self
+    }
+
+    /// Set the API version (default: `v3.0`)
+    pub fn api_version(mut self, api_version: &str) -> Self {
+        self.api_version = Some(api_version.trim_matches('/').to_string());
+        self
+    }
+
+    /// Set the total timeout for each request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing a connection
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with each request
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Send requests of the async `Client` through a custom transport.
+    /// Timeouts and user agent are then up to the transport.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Send requests of `ClientBlocking` through a custom transport.
+    /// Timeouts and user agent are then up to the transport.
+    pub fn blocking_transport(mut self, transport: Arc<dyn BlockingTransport>) -> Self {
+        self.blocking_transport = Some(transport);
+        self
+    }
+
+    /// Authenticate with a `/read-public` token obtained from the environment's
+    /// token endpoint via the client-credentials grant
+    pub fn client_credentials(mut self, client_id: &str, client_secret: &str) -> Self {
+        self.client_credentials = Some((client_id.to_string(), client_secret.to_string()));
+        self
+    }
+
+    /// Authenticate with tokens from a custom provider.
+    /// Takes precedence over `client_credentials`.
+    pub fn token_provider(mut self, token_provider: Arc<dyn TokenProvider>) -> Self {
+        self.token_provider = Some(token_provider);
+        self
+    }
+
+    /// Act on behalf of the record a `UserToken` was issued for,
+    /// typically together with `Environment::Member`
+    pub fn user_token(self, user_token: UserToken) -> Self {
+        self.token_provider(Arc::new(user_token))
+    }
+
+    pub fn get_environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// The API URL the built client will use, ending in `/`
+    pub fn api_url(&self) -> String {
+        format!(
+            "{}{}/",
+            self.environment.base_url(),
+            self.api_version.as_deref().unwrap_or(DEFAULT_API_VERSION)
+        )
+    }
+
+    fn build_token_provider(&self) -> Option<Arc<dyn TokenProvider>> {
+        if let Some(ref token_provider) = self.token_provider {
+            return Some(token_provider.clone());
+        }
+        self.client_credentials
+            .as_ref()
+            .map(|(client_id, client_secret)| {
+                Arc::new(
+                    ClientCredentials::new(client_id, client_secret)
+                        .with_token_url(&self.environment.token_url()),
+                ) as Arc<dyn TokenProvider>
+            })
+    }
+
+    /// Build an async `Client`
+    pub fn build(&self) -> Result<Client> {
+        if let Some(ref transport) = self.transport {
+            return Ok(Client::new_with_parts(
+                self.api_url(),
+                transport.clone(),
+                self.build_token_provider(),
+            ));
+        }
+        let mut builder = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(ref user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        Ok(Client::new_with_parts(
+            self.api_url(),
+            Arc::new(ReqwestTransport::new(builder.build()?)),
+            self.build_token_provider(),
+        ))
+    }
+
+    /// Build a `ClientBlocking`
+    pub fn build_blocking(&self) -> Result<ClientBlocking> {
+        if let Some(ref transport) = self.blocking_transport {
+            return Ok(ClientBlocking::new_with_parts(
+                self.api_url(),
+                transport.clone(),
+                self.build_token_provider(),
+            ));
+        }
+        let mut builder = reqwest::blocking::Client::builder();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(ref user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        Ok(ClientBlocking::new_with_parts(
+            self.api_url(),
+            Arc::new(ReqwestBlockingTransport::new(builder.build()?)),
+            self.build_token_provider(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_api_url() {
+        let builder = ClientBuilder::new();
+        assert_eq!(builder.get_environment(), &Environment::Production);
+        assert_eq!(builder.api_url(), "https://pub.orcid.org/v3.0/");
+    }
+
+    #[test]
+    fn test_environments() {
+        assert_eq!(
+            ClientBuilder::new()
+                .environment(Environment::Sandbox)
+                .api_url(),
+            "https://pub.sandbox.orcid.org/v3.0/"
+        );
+        assert_eq!(
+            ClientBuilder::new()
+                .environment(Environment::Member)
+                .api_url(),
+            "https://api.orcid.org/v3.0/"
+        );
+        assert_eq!(
+            ClientBuilder::new()
+                .environment(Environment::MemberSandbox)
+                .api_url(),
+            "https://api.sandbox.orcid.org/v3.0/"
+        );
+    }
+
+    #[test]
+    fn test_custom_base_url() {
+        let builder = ClientBuilder::new().base_url("http://127.0.0.1:8080");
+        assert_eq!(builder.api_url(), "http://127.0.0.1:8080/v3.0/");
+
+        let builder = ClientBuilder::new().base_url("http://proxy.local/orcid/");
+        assert_eq!(builder.api_url(), "http://proxy.local/orcid/v3.0/");
+    }
+
+    #[test]
+    fn test_token_urls() {
+        assert_eq!(
+            Environment::Production.token_url(),
+            "https://orcid.org/oauth/token"
+        );
+        assert_eq!(
+            Environment::Member.token_url(),
+            "https://orcid.org/oauth/token"
+        );
+        assert_eq!(
+            Environment::Sandbox.token_url(),
+            "https://sandbox.orcid.org/oauth/token"
+        );
+        assert_eq!(
+            Environment::MemberSandbox.token_url(),
+            "https://sandbox.orcid.org/oauth/token"
+        );
+        assert_eq!(
+            Environment::Custom("http://localhost:8080".to_string()).token_url(),
+            "http://localhost:8080/oauth/token"
+        );
+    }
+
+    #[test]
+    fn test_api_version() {
+        let builder = ClientBuilder::new().api_version("/v2.1/");
+        assert_eq!(builder.api_url(), "https://pub.orcid.org/v2.1/");
+    }
+
+    #[test]
+    fn test_build() {
+        let client = ClientBuilder::new()
+            .environment(Environment::Sandbox)
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(5))
+            .user_agent("orcid-test/1.0")
+            .build()
+            .unwrap();
+        assert_eq!(client.api_url(), "https://pub.sandbox.orcid.org/v3.0/");
+    }
+
+    #[test]
+    fn test_build_blocking() {
+        let client = ClientBuilder::new()
+            .environment(Environment::Member)
+            .user_agent("orcid-test/1.0")
+            .build_blocking()
+            .unwrap();
+        assert_eq!(client.api_url(), "https://api.orcid.org/v3.0/");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::record;
+use serde_json::json;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contributor {
+    name: Option<String>,
+    orcid: Option<String>,
+    role: Option<String>,
+    sequence: Option<String>,
+}
+
+impl Contributor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses an ORCID v3.0 `contributor` object
+    pub fn new_from_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::Contributor>(j))
+    }
+
+    // Getter methods
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    pub fn orcid(&self) -> Option<&String> {
+        self.orcid.as_ref()
+    }
+
+    /// The contributor role, e.g. `author` or `editor`
+    pub fn role(&self) -> Option<&String> {
+        self.role.as_ref()
+    }
+
+    /// The contributor sequence, `first` or `additional`
+    pub fn sequence(&self) -> Option<&String> {
+        self.sequence.as_ref()
+    }
+
+    // Setter methods
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
+
+    pub fn set_orcid(&mut self, orcid: Option<String>) {
+        self.orcid = orcid;
+    }
+
+    pub fn set_role(&mut self, role: Option<String>) {
+        self.role = role;
+    }
+
+    pub fn set_sequence(&mut self, sequence: Option<String>) {
+        self.sequence = sequence;
+    }
+
+    /// Serializes to an ORCID v3.0 `contributor` object
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = json!({});
+        if let Some(ref orcid) = self.orcid {
+            j["contributor-orcid"] = json!({
+                "uri": format!("https://orcid.org/{}", orcid),
+                "path": orcid,
+                "host": "orcid.org",
+            });
+        }
+        if let Some(ref name) = self.name {
+            j["credit-name"] = json!({ "value": name });
+        }
+        let mut attributes = json!({});
+        if let Some(ref sequence) = self.sequence {
+            attributes["contributor-sequence"] = json!(sequence);
+        }
+        if let Some(ref role) = self.role {
+            attributes["contributor-role"] = json!(role);
+        }
+        if attributes != json!({}) {
+            j["contributor-attributes"] = attributes;
+        }
+        j
+    }
+}
+
+impl From<&record::Contributor> for Contributor {
+    fn from(contributor: &record::Contributor) -> Self {
+        let attributes = contributor.contributor_attributes.as_ref();
+        Self {
+            name: record::string_value(&contributor.credit_name),
+            orcid: contributor
+                .contributor_orcid
+                .as_ref()
+                .and_then(|id| id.path.clone()),
+            role: attributes.and_then(|a| a.contributor_role.clone()),
+            sequence: attributes.and_then(|a| a.contributor_sequence.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let contributor = Contributor::new();
+        assert!(contributor.name().is_none());
+        assert!(contributor.orcid().is_none());
+        assert!(contributor.role().is_none());
+        assert!(contributor.sequence().is_none());
+        assert_eq!(contributor.to_json(), json!({}));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut contributor = Contributor::new();
+        contributor.set_name(Some("Jane Doe".to_string()));
+        contributor.set_orcid(Some("0000-0002-1825-0097".to_string()));
+        contributor.set_role(Some("author".to_string()));
+        contributor.set_sequence(Some("first".to_string()));
+
+        let j = contributor.to_json();
+        assert_eq!(j["credit-name"]["value"], "Jane Doe");
+        assert_eq!(j["contributor-orcid"]["path"], "0000-0002-1825-0097");
+        assert_eq!(
+            j["contributor-orcid"]["uri"],
+            "https://orcid.org/0000-0002-1825-0097"
+        );
+        assert_eq!(j["contributor-attributes"]["contributor-role"], "author");
+        assert_eq!(j["contributor-attributes"]["contributor-sequence"], "first");
+    }
+
+    #[test]
+    fn test_to_json_role_only() {
+        // Funding contributors have a role but no sequence
+        let mut contributor = Contributor::new();
+        contributor.set_role(Some("lead".to_string()));
+        assert_eq!(
+            contributor.to_json(),
+            json!({ "contributor-attributes": { "contributor-role": "lead" } })
+        );
+    }
+
+    #[test]
+    fn test_new_from_json() {
+        let j = json!({
+            "contributor-orcid": {
+                "uri": "https://orcid.org/0000-0002-1825-0097",
+                "path": "0000-0002-1825-0097",
+                "host": "orcid.org"
+            },
+            "credit-name": { "value": "Josiah Carberry" },
+            "contributor-email": null,
+            "contributor-attributes": {
+                "contributor-sequence": "first",
+                "contributor-role": "author"
+            }
+        });
+        let contributor = Contributor::new_from_json(&j);
+        assert_eq!(contributor.name(), Some(&"Josiah Carberry".to_string()));
+        assert_eq!(
+            contributor.orcid(),
+            Some(&"0000-0002-1825-0097".to_string())
+        );
+        assert_eq!(contributor.role(), Some(&"author".to_string()));
+        assert_eq!(contributor.sequence(), Some(&"first".to_string()));
+        assert_eq!(
+            Contributor::new_from_json(&contributor.to_json()),
+            contributor
+        );
+        assert_eq!(Contributor::new_from_json(&json!({})), Contributor::new());
+    }
+}
@@ -0,0 +1,318 @@
+use crate::error::{OrcidError, Result};
+use crate::orcid_id::OrcidId;
+use crate::search_query::{SearchQuery, DEFAULT_ROWS};
+use std::fmt;
+use std::io::Write;
+
+/// A column of the `csv-search` endpoint, selected via its `fl` parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CsvColumn {
+    Orcid,
+    Email,
+    GivenNames,
+    FamilyName,
+    GivenAndFamilyNames,
+    CreditName,
+    OtherName,
+    CurrentInstitutionAffiliationName,
+    PastInstitutionAffiliationName,
+}
+
+impl CsvColumn {
+    /// Every column, in ORCID's documented order
+    pub const ALL: [CsvColumn; 9] = [
+        CsvColumn::Orcid,
+        CsvColumn::Email,
+        CsvColumn::GivenNames,
+        CsvColumn::FamilyName,
+        CsvColumn::GivenAndFamilyNames,
+        CsvColumn::CreditName,
+        CsvColumn::OtherName,
+        CsvColumn::CurrentInstitutionAffiliationName,
+        CsvColumn::PastInstitutionAffiliationName,
+    ];
+
+    /// The ORCID field name, e.g. `given-names`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CsvColumn::Orcid => "orcid",
+            CsvColumn::Email => "email",
+            CsvColumn::GivenNames => "given-names",
+            CsvColumn::FamilyName => "family-name",
+            CsvColumn::GivenAndFamilyNames => "given-and-family-names",
+            CsvColumn::CreditName => "credit-name",
+            CsvColumn::OtherName => "other-name",
+            CsvColumn::CurrentInstitutionAffiliationName => "current-institution-affiliation-name",
+            CsvColumn::PastInstitutionAffiliationName => "past-institution-affiliation-name",
+        }
+    }
+
+    /// The column with the ORCID field name `name`
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.as_str() == name)
+    }
+}
+
+impl fmt::Display for CsvColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A row of a `csv-search` response. Columns that were not requested, or are empty, are `None`.
+/// Multiple values, e.g. several other names, are in one comma-separated string, as ORCID sends them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CsvSearchRow {
+    orcid: Option<String>,
+    email: Option<String>,
+    given_names: Option<String>,
+    family_name: Option<String>,
+    given_and_family_names: Option<String>,
+    credit_name: Option<String>,
+    other_name: Option<String>,
+    current_institution_affiliation_name: Option<String>,
+    past_institution_affiliation_name: Option<String>,
+}
+
+impl CsvSearchRow {
+    fn field(&mut self, column: CsvColumn) -> &mut Option<String> {
+        match column {
+            CsvColumn::Orcid => &mut self.orcid,
+            CsvColumn::Email => &mut self.email,
+            CsvColumn::GivenNames => &mut self.given_names,
+            CsvColumn::FamilyName => &mut self.family_name,
+            CsvColumn::GivenAndFamilyNames => &mut self.given_and_family_names,
+            CsvColumn::CreditName => &mut self.credit_name,
+            CsvColumn::OtherName => &mut self.other_name,
+            CsvColumn::CurrentInstitutionAffiliationName => {
+                &mut self.current_institution_affiliation_name
+            }
+            CsvColumn::PastInstitutionAffiliationName => {
+                &mut self.past_institution_affiliation_name
+            }
+        }
+    }
+
+    /// The value of a column
+    pub fn get(&self, column: CsvColumn) -> Option<&str> {
+        match column {
+            CsvColumn::Orcid => self.orcid.as_deref(),
+            CsvColumn::Email => self.email.as_deref(),
+            CsvColumn::GivenNames => self.given_names.as_deref(),
+            CsvColumn::FamilyName => self.family_name.as_deref(),
+            CsvColumn::GivenAndFamilyNames => self.given_and_family_names.as_deref(),
+            CsvColumn::CreditName => self.credit_name.as_deref(),
+            CsvColumn::OtherName => self.other_name.as_deref(),
+            CsvColumn::CurrentInstitutionAffiliationName => {
+                self.current_institution_affiliation_name.as_deref()
+            }
+            CsvColumn::PastInstitutionAffiliationName => {
+                self.past_institution_affiliation_name.as_deref()
+            }
+        }
+    }
+
+    // Getter methods
+    /// The ORCID iD, or `None` if it is missing or invalid; `get(CsvColumn::Orcid)` has the raw value
+    pub fn orcid_id(&self) -> Option<OrcidId> {
+        self.orcid.as_deref()?.parse().ok()
+    }
+
+    pub fn email(&self) -> Option<&String> {
+        self.email.as_ref()
+    }
+
+    pub fn given_names(&self) -> Option<&String> {
+        self.given_names.as_ref()
+    }
+
+    pub fn family_name(&self) -> Option<&String> {
+        self.family_name.as_ref()
+    }
+
+    pub fn given_and_family_names(&self) -> Option<&String> {
+        self.given_and_family_names.as_ref()
+    }
+
+    pub fn credit_name(&self) -> Option<&String> {
+        self.credit_name.as_ref()
+    }
+
+    pub fn other_name(&self) -> Option<&String> {
+        self.other_name.as_ref()
+    }
+
+    pub fn current_institution_affiliation_name(&self) -> Option<&String> {
+        self.current_institution_affiliation_name.as_ref()
+    }
+
+    pub fn past_institution_affiliation_name(&self) -> Option<&String> {
+        self.past_institution_affiliation_name.as_ref()
+    }
+}
+
+/// The URL query of a `csv-search` request, e.g. `q=x&fl=orcid,email`
+pub(crate) fn csv_query_string(query: &SearchQuery, columns: &[CsvColumn]) -> Result<String> {
+    if columns.is_empty() {
+        return Err(OrcidError::InvalidSearch("no CSV columns".to_string()));
+    }
+    query.validate()?;
+    let fl: Vec<&str> = columns.iter().map(|column| column.as_str()).collect();
+    Ok(format!("{}&fl={}", query.to_query_string(), fl.join(",")))
+}
+
+/// Parses a `csv-search` response of the requested `columns`, matching values to columns
+/// by the header line. Fails on unknown header names and on requested columns without one.
+pub(crate) fn parse_rows(body: &str, columns: &[CsvColumn]) -> Result<Vec<CsvSearchRow>> {
+    let mut reader = csv::Reader::from_reader(body.as_bytes());
+    let headers = reader.headers()?;
+    if headers.is_empty() {
+        return Ok(vec![]);
+    }
+    let header_columns = headers
+        .iter()
+        .map(|name| {
+            CsvColumn::from_name(name.trim())
+                .ok_or_else(|| OrcidError::CsvError(format!("unknown column {}", name).into()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(missing) = columns.iter().find(|c| !header_columns.contains(c)) {
+        return Err(OrcidError::CsvError(
+            format!("missing column {}", missing).into(),
+        ));
+    }
+    let mut ret = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let mut row = CsvSearchRow::default();
+        for (column, value) in header_columns.iter().zip(record.iter()) {
+            if !value.is_empty() {
+                *row.field(*column) = Some(value.to_string());
+            }
+        }
+        ret.push(row);
+    }
+    Ok(ret)
+}
+
+/// Writes a page of a `csv-search` response, with its header line only if `with_header`.
+/// Returns the number of rows, and the query for the next page if the page was full.
+pub(crate) fn write_page(
+    query: &SearchQuery,
+    body: &str,
+    with_header: bool,
+    writer: &mut impl Write,
+) -> Result<(usize, Option<SearchQuery>)> {
+    let rows = csv::Reader::from_reader(body.as_bytes())
+        .records()
+        .collect::<std::result::Result<Vec<_>, _>>()?
+        .len();
+    let body = match (with_header, body.split_once('\n')) {
+        (false, Some((_, rest))) => rest,
+        (false, None) => "",
+        (true, _) => body,
+    };
+    writer.write_all(body.as_bytes())?;
+    if !body.is_empty() && !body.ends_with('\n') {
+        writer.write_all(b"\n")?;
+    }
+    // CSV responses have no total, so a short page is the last one
+    let next = if rows < query.rows().unwrap_or(DEFAULT_ROWS) {
+        None
+    } else {
+        query.page_after(rows, usize::MAX)
+    };
+    Ok((rows, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_query_string() {
+        let query = SearchQuery::new("family-name:Carberry").with_rows(10);
+        assert_eq!(
+            csv_query_string(&query, &[CsvColumn::Orcid, CsvColumn::GivenNames]).unwrap(),
+            "q=family-name%3ACarberry&rows=10&fl=orcid,given-names"
+        );
+        assert!(csv_query_string(&query, &[]).is_err());
+        assert!(csv_query_string(&query.with_rows(1001), &CsvColumn::ALL).is_err());
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        let body = "orcid,given-names,current-institution-affiliation-name\n\
+            0000-0002-1825-0097,Josiah,\"Brown University, Providence\"\n\
+            0000-0001-5916-0947,,\n";
+        let columns = [
+            CsvColumn::Orcid,
+            CsvColumn::GivenNames,
+            CsvColumn::CurrentInstitutionAffiliationName,
+        ];
+        let rows = parse_rows(body, &columns).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].orcid_id().unwrap().as_str(), "0000-0002-1825-0097");
+        assert_eq!(rows[0].given_names(), Some(&"Josiah".to_string()));
+        assert_eq!(
+            rows[0].get(CsvColumn::CurrentInstitutionAffiliationName),
+            Some("Brown University, Providence")
+        );
+        assert_eq!(rows[0].email(), None);
+        assert_eq!(rows[1].given_names(), None);
+
+        let rows = parse_rows("orcid\n0000-0001-5916-0948\n", &[CsvColumn::Orcid]).unwrap();
+        assert_eq!(rows[0].orcid_id(), None);
+        assert_eq!(rows[0].get(CsvColumn::Orcid), Some("0000-0001-5916-0948"));
+        assert!(parse_rows("", &[CsvColumn::Orcid]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_rows_by_header() {
+        let columns = [CsvColumn::Orcid, CsvColumn::FamilyName];
+        let rows = parse_rows(
+            "family-name,orcid
+Carberry,0000-0002-1825-0097
+",
+            &columns,
+        )
+        .unwrap();
+        assert_eq!(rows[0].family_name(), Some(&"Carberry".to_string()));
+        assert_eq!(rows[0].get(CsvColumn::Orcid), Some("0000-0002-1825-0097"));
+
+        match parse_rows(
+            "orcid,surname
+0000-0002-1825-0097,Carberry
+",
+            &columns,
+        ) {
+            Err(OrcidError::CsvError(e)) => assert_eq!(e.to_string(), "unknown column surname"),
+            _ => panic!("Expected CsvError"),
+        }
+        match parse_rows(
+            "orcid
+0000-0002-1825-0097
+",
+            &columns,
+        ) {
+            Err(OrcidError::CsvError(e)) => assert_eq!(e.to_string(), "missing column family-name"),
+            _ => panic!("Expected CsvError"),
+        }
+    }
+
+    #[test]
+    fn test_write_page() {
+        let query = SearchQuery::new("x").with_rows(2);
+        let mut out = Vec::new();
+        let (rows, next) = write_page(&query, "orcid\na\nb\n", true, &mut out).unwrap();
+        assert_eq!(rows, 2);
+        let next = next.unwrap();
+        assert_eq!(next.start(), Some(2));
+        let (rows, next) = write_page(&next, "orcid\nc", false, &mut out).unwrap();
+        assert_eq!(rows, 1);
+        assert!(next.is_none());
+        assert_eq!(String::from_utf8(out).unwrap(), "orcid\na\nb\nc\n");
+    }
+}
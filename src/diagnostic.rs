@@ -0,0 +1,342 @@
+use crate::fuzzy_date::FuzzyDate;
+use crate::record::{self, Metadata};
+use serde::de::DeserializeOwned;
+use serde_path_to_error::Segment;
+use std::collections::HashSet;
+use std::fmt;
+
+/// A part of an ORCID response that does not match the v3.0 schema and is
+/// dropped by the lenient parsers
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostic {
+    path: String,
+    expected: String,
+    actual: serde_json::Value,
+}
+
+impl Diagnostic {
+    // Getter methods
+    /// The JSON path of the value, e.g. `person.name.given-names.value`
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// What the schema expects, e.g. `a string`
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The value ORCID sent
+    pub fn actual(&self) -> &serde_json::Value {
+        &self.actual
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.path, self.expected, self.actual
+        )
+    }
+}
+
+/// Date fields, whose values are checked after the schema
+const DATE_KEYS: [&str; 5] = [
+    "completion-date",
+    "end-date",
+    "publication-date",
+    "review-completion-date",
+    "start-date",
+];
+
+/// Collects every schema deviation in `j` when parsed as `T`, then every date that
+/// has the right types but is dropped for being out of range, e.g. a month of `13`.
+///
+/// Parsing stops at the first error, so each malformed value is reported, then
+/// blanked out in a copy of the JSON, and parsing starts over.
+pub(crate) fn diagnose<T: DeserializeOwned>(j: &serde_json::Value) -> Vec<Diagnostic> {
+    let mut patched = j.clone();
+    let mut seen = HashSet::new();
+    let mut ret = Vec::new();
+    record::strict(|| {
+        while let Err(e) = serde_path_to_error::deserialize::<_, T>(&patched) {
+            let mut segments: Vec<Segment> = e.path().iter().cloned().collect();
+            let mut message = e.inner().to_string();
+            // `#[serde(flatten)]` loses the path of metadata fields; look them up again
+            if let Some(value @ serde_json::Value::Object(_)) = lookup(&patched, &segments) {
+                if let Err(e) = serde_path_to_error::deserialize::<_, Metadata>(value) {
+                    segments.extend(e.path().iter().cloned());
+                    message = e.inner().to_string();
+                }
+            }
+            let path = path_string(&segments);
+            if !seen.insert(path.clone()) {
+                break;
+            }
+            ret.push(Diagnostic {
+                path,
+                expected: expected(&message),
+                actual: lookup(j, &segments)
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Null),
+            });
+            if !blank(&mut patched, &segments) {
+                break;
+            }
+        }
+    });
+    diagnose_dates(j, &mut String::new(), &seen, &mut ret);
+    ret
+}
+
+/// Reports the year, month or day of every date in `j` that `FuzzyDate` would drop
+fn diagnose_dates(
+    j: &serde_json::Value,
+    path: &mut String,
+    seen: &HashSet<String>,
+    ret: &mut Vec<Diagnostic>,
+) {
+    let len = path.len();
+    match j {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+                if DATE_KEYS.contains(&key.as_str()) && value.is_object() {
+                    diagnose_date(value, path, seen, ret);
+                } else {
+                    diagnose_dates(value, path, seen, ret);
+                }
+                path.truncate(len);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                path.push_str(&format!("[{}]", index));
+                diagnose_dates(item, path, seen, ret);
+                path.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn diagnose_date(
+    j: &serde_json::Value,
+    path: &str,
+    seen: &HashSet<String>,
+    ret: &mut Vec<Diagnostic>,
+) {
+    let date: record::FuzzyDate = record::from_json(j);
+    let year = record::parse_value::<u16>(&date.year);
+    let month = record::parse_value::<u8>(&date.month);
+    let day = record::parse_value::<u8>(&date.day);
+    let mut report = |part: &str, expected: &str| {
+        let path = format!("{}.{}.value", path, part);
+        if !seen.contains(&path) {
+            ret.push(Diagnostic {
+                path,
+                expected: expected.to_string(),
+                actual: j[part]["value"].clone(),
+            });
+        }
+    };
+    let Some(year) = year else {
+        if date.year.as_ref().and_then(|y| y.as_str()).is_some() {
+            report("year", "a year");
+        }
+        return;
+    };
+    if date.month.as_ref().and_then(|m| m.as_str()).is_none() {
+        return;
+    }
+    if FuzzyDate::new(year, month, None).is_err() {
+        report("month", "a month from 1 to 12");
+    } else if date.day.as_ref().and_then(|d| d.as_str()).is_some()
+        && FuzzyDate::new(year, month, day).is_err()
+    {
+        report("day", "a day of the month");
+    }
+}
+
+/// The expected type from a serde error, e.g. `a string` from
+/// "invalid type: integer `5`, expected a string"
+fn expected(message: &str) -> String {
+    match message.rfind(", expected ") {
+        Some(pos) => message[pos + ", expected ".len()..].to_string(),
+        None => message.to_string(),
+    }
+}
+
+fn path_string(segments: &[Segment]) -> String {
+    let mut ret = String::new();
+    for segment in segments {
+        match segment {
+            Segment::Seq { index } => ret += &format!("[{}]", index),
+            Segment::Map { key } | Segment::Enum { variant: key } => {
+                if !ret.is_empty() {
+                    ret.push('.');
+                }
+                ret += key;
+            }
+            Segment::Unknown => ret += ".?",
+        }
+    }
+    ret
+}
+
+fn lookup<'a>(j: &'a serde_json::Value, segments: &[Segment]) -> Option<&'a serde_json::Value> {
+    segments.iter().try_fold(j, |j, segment| match segment {
+        Segment::Seq { index } => j.get(*index),
+        Segment::Map { key } => j.get(key),
+        _ => None,
+    })
+}
+
+/// Replaces the value at `segments` with something every schema type accepts:
+/// `null` for object fields, `{}` for list items. Returns false if there is no such value.
+fn blank(j: &mut serde_json::Value, segments: &[Segment]) -> bool {
+    let Some((last, parent)) = segments.split_last() else {
+        return false;
+    };
+    let parent = parent.iter().try_fold(j, |j, segment| match segment {
+        Segment::Seq { index } => j.get_mut(*index),
+        Segment::Map { key } => j.get_mut(key),
+        _ => None,
+    });
+    match (parent, last) {
+        (Some(parent), Segment::Seq { index }) => match parent.get_mut(*index) {
+            Some(value) => {
+                *value = serde_json::json!({});
+                true
+            }
+            None => false,
+        },
+        (Some(parent), Segment::Map { key }) => match parent.get_mut(key) {
+            Some(value) => {
+                *value = serde_json::Value::Null;
+                true
+            }
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Record;
+    use serde_json::json;
+
+    #[test]
+    fn test_valid_record() {
+        let j = json!({
+            "orcid-identifier": { "path": "0000-0001-5916-0947" },
+            "person": { "name": { "given-names": { "value": "John" } } },
+            "activities-summary": {
+                "works": { "group": [{ "work-summary": [{
+                    "put-code": 1,
+                    "display-index": "0",
+                    "publication-date": { "year": { "value": "2020" } }
+                }] }] }
+            }
+        });
+        assert!(diagnose::<Record>(&j).is_empty());
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let j = json!({
+            "orcid-identifier": "0000-0001-5916-0947",
+            "person": {
+                "name": { "given-names": { "value": true } },
+                "keywords": { "keyword": [{ "content": "ok" }, { "content": 42 }] }
+            },
+            "activities-summary": {
+                "works": { "group": [{ "work-summary": [{ "put-code": "12345" }] }] }
+            }
+        });
+        // Object keys are visited in alphabetical order
+        let diagnostics = diagnose::<Record>(&j);
+        let paths: Vec<&str> = diagnostics.iter().map(Diagnostic::path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "activities-summary.works.group[0].work-summary[0].put-code",
+                "orcid-identifier",
+                "person.keywords.keyword[1].content",
+                "person.name.given-names.value",
+            ]
+        );
+        assert_eq!(diagnostics[0].expected(), "u64");
+        assert_eq!(diagnostics[0].actual(), &json!("12345"));
+        assert_eq!(diagnostics[2].expected(), "a string");
+        assert_eq!(diagnostics[3].expected(), "a string or number");
+        assert_eq!(diagnostics[3].actual(), &json!(true));
+        assert_eq!(
+            diagnostics[2].to_string(),
+            "person.keywords.keyword[1].content: expected a string, got 42"
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_dates() {
+        let j = json!({
+            "activities-summary": {
+                "works": { "group": [{ "work-summary": [
+                    { "publication-date": { "year": { "value": "2020" }, "month": { "value": "13" } } },
+                    { "publication-date": { "year": { "value": "MMXX" } } },
+                    { "publication-date": { "year": { "value": "2023" }, "month": { "value": "02" }, "day": { "value": "30" } } },
+                    { "publication-date": { "year": { "value": "2024" }, "month": { "value": "02" }, "day": { "value": "29" } } }
+                ] }] }
+            }
+        });
+        let diagnostics = diagnose::<Record>(&j);
+        let found: Vec<(&str, &str)> = diagnostics
+            .iter()
+            .map(|d| (d.path(), d.expected()))
+            .collect();
+        let prefix = "activities-summary.works.group[0].work-summary";
+        assert_eq!(
+            found,
+            vec![
+                (
+                    &*format!("{}[0].publication-date.month.value", prefix),
+                    "a month from 1 to 12"
+                ),
+                (
+                    &*format!("{}[1].publication-date.year.value", prefix),
+                    "a year"
+                ),
+                (
+                    &*format!("{}[2].publication-date.day.value", prefix),
+                    "a day of the month"
+                ),
+            ]
+        );
+        assert_eq!(diagnostics[0].actual(), &json!("13"));
+    }
+
+    #[test]
+    fn test_malformed_list_item() {
+        let j = json!({ "person": { "emails": { "email": ["jdoe@example.com"] } } });
+        let diagnostics = diagnose::<Record>(&j);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path(), "person.emails.email[0]");
+        assert_eq!(diagnostics[0].actual(), &json!("jdoe@example.com"));
+    }
+
+    #[test]
+    fn test_not_an_object() {
+        let diagnostics = diagnose::<Record>(&json!("0000-0001-5916-0947"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path(), "");
+        assert_eq!(diagnostics[0].expected(), "struct Record");
+    }
+}
@@ -7,12 +7,28 @@ pub enum OrcidError {
     /// Invalid ORCID ID format or checksum
     InvalidOrcidId(String),
 
+    /// Invalid date, e.g. a month outside 1-12
+    InvalidDate(String),
+
+    /// Invalid organization identifier, e.g. a ROR ID with a wrong checksum
+    InvalidOrgIdentifier(String),
+
+    /// Invalid search parameters, e.g. more rows than ORCID returns per page
+    InvalidSearch(String),
+
     /// Network request failed
     NetworkError(reqwest::Error),
 
     /// Failed to parse JSON response
     JsonError(serde_json::Error),
 
+    /// Failed to parse a CSV response or ROR data dump. The variant exists without
+    /// the `csv` and `ror` features too, so enabling them does not change this enum.
+    CsvError(Box<dyn Error + Send + Sync>),
+
+    /// Failed to read a file, e.g. a ROR data dump
+    IoError(std::io::Error),
+
     /// API returned an error
     ApiError {
         orcid_id: String,
@@ -22,6 +38,16 @@ pub enum OrcidError {
 
     /// API returned a response that could not be parsed
     BadApiResponse(serde_json::Value),
+
+    /// API returned an unsuccessful HTTP status without a JSON error body
+    HttpError { status: u16, body: String },
+
+    /// OAuth token request failed
+    AuthError(String),
+
+    /// The activity already exists on the record (HTTP 409), usually because
+    /// an activity from the same source has the same external IDs
+    Conflict(String),
 }
 
 impl fmt::Display for OrcidError {
@@ -30,12 +56,27 @@ impl fmt::Display for OrcidError {
             OrcidError::InvalidOrcidId(id) => {
                 write!(f, "{} is not a valid ORCID ID", id)
             }
+            OrcidError::InvalidDate(date) => {
+                write!(f, "{} is not a valid date", date)
+            }
+            OrcidError::InvalidOrgIdentifier(id) => {
+                write!(f, "{} is not a valid organization identifier", id)
+            }
+            OrcidError::InvalidSearch(message) => {
+                write!(f, "Invalid search: {}", message)
+            }
             OrcidError::NetworkError(e) => {
                 write!(f, "Network request failed: {}", e)
             }
             OrcidError::JsonError(e) => {
                 write!(f, "Failed to parse JSON: {}", e)
             }
+            OrcidError::CsvError(e) => {
+                write!(f, "Failed to parse CSV: {}", e)
+            }
+            OrcidError::IoError(e) => {
+                write!(f, "I/O error: {}", e)
+            }
             OrcidError::ApiError {
                 orcid_id,
                 error_code,
@@ -50,6 +91,15 @@ impl fmt::Display for OrcidError {
             OrcidError::BadApiResponse(json) => {
                 write!(f, "Unexpected API response: {}", json)
             }
+            OrcidError::HttpError { status, body } => {
+                write!(f, "HTTP error {}: {}", status, body)
+            }
+            OrcidError::AuthError(message) => {
+                write!(f, "Authentication failed: {}", message)
+            }
+            OrcidError::Conflict(message) => {
+                write!(f, "Conflict with existing activity: {}", message)
+            }
         }
     }
 }
@@ -59,6 +109,8 @@ impl Error for OrcidError {
         match self {
             OrcidError::NetworkError(e) => Some(e),
             OrcidError::JsonError(e) => Some(e),
+            OrcidError::CsvError(e) => Some(e.as_ref()),
+            OrcidError::IoError(e) => Some(e),
             _ => None,
         }
     }
@@ -76,6 +128,19 @@ impl From<serde_json::Error> for OrcidError {
     }
 }
 
+#[cfg(any(feature = "csv", feature = "ror"))]
+impl From<csv::Error> for OrcidError {
+    fn from(err: csv::Error) -> Self {
+        OrcidError::CsvError(Box::new(err))
+    }
+}
+
+impl From<std::io::Error> for OrcidError {
+    fn from(err: std::io::Error) -> Self {
+        OrcidError::IoError(err)
+    }
+}
+
 /// Result type alias for ORCID operations
 pub type Result<T> = std::result::Result<T, OrcidError>;
 
@@ -89,6 +154,27 @@ mod tests {
         assert_eq!(error.to_string(), "1234-5678 is not a valid ORCID ID");
     }
 
+    #[test]
+    fn test_invalid_date_display() {
+        let error = OrcidError::InvalidDate("2023-13".to_string());
+        assert_eq!(error.to_string(), "2023-13 is not a valid date");
+    }
+
+    #[test]
+    fn test_invalid_org_identifier_display() {
+        let error = OrcidError::InvalidOrgIdentifier("ROR 03yrm5c27".to_string());
+        assert_eq!(
+            error.to_string(),
+            "ROR 03yrm5c27 is not a valid organization identifier"
+        );
+    }
+
+    #[test]
+    fn test_invalid_search_display() {
+        let error = OrcidError::InvalidSearch("rows 2000 exceeds 1000".to_string());
+        assert_eq!(error.to_string(), "Invalid search: rows 2000 exceeds 1000");
+    }
+
     #[test]
     fn test_api_error_display() {
         let error = OrcidError::ApiError {
@@ -110,6 +196,30 @@ mod tests {
         assert!(error.to_string().contains("unexpected"));
     }
 
+    #[test]
+    fn test_http_error_display() {
+        let error = OrcidError::HttpError {
+            status: 503,
+            body: "Service Unavailable".to_string(),
+        };
+        assert_eq!(error.to_string(), "HTTP error 503: Service Unavailable");
+    }
+
+    #[test]
+    fn test_auth_error_display() {
+        let error = OrcidError::AuthError("invalid_client".to_string());
+        assert_eq!(error.to_string(), "Authentication failed: invalid_client");
+    }
+
+    #[test]
+    fn test_conflict_display() {
+        let error = OrcidError::Conflict("duplicate DOI".to_string());
+        assert_eq!(
+            error.to_string(),
+            "Conflict with existing activity: duplicate DOI"
+        );
+    }
+
     #[test]
     fn test_error_debug() {
         let error = OrcidError::InvalidOrcidId("1234".to_string());
@@ -136,4 +246,29 @@ mod tests {
             _ => panic!("Expected JsonError variant"),
         }
     }
+
+    #[test]
+    fn test_csv_error_display() {
+        let err = OrcidError::CsvError("bad row".into());
+        assert_eq!(err.to_string(), "Failed to parse CSV: bad row");
+        assert!(err.source().is_some());
+    }
+
+    #[cfg(any(feature = "csv", feature = "ror"))]
+    #[test]
+    fn test_from_csv_error() {
+        let mut reader = csv::ReaderBuilder::new().from_reader("a,b\n1,2,3\n".as_bytes());
+        let csv_err = reader.records().next().unwrap().unwrap_err();
+        let orcid_err: OrcidError = csv_err.into();
+        assert!(orcid_err.source().is_some());
+        assert!(orcid_err.to_string().starts_with("Failed to parse CSV"));
+    }
+
+    #[test]
+    fn test_from_io_error() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "ror.json");
+        let orcid_err: OrcidError = io_err.into();
+        assert!(orcid_err.source().is_some());
+        assert_eq!(orcid_err.to_string(), "I/O error: ror.json");
+    }
 }
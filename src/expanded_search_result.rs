@@ -0,0 +1,166 @@
+use crate::orcid_id::OrcidId;
+use crate::search_results::SearchHit;
+use serde_json;
+
+/// A researcher found by `expanded-search`, with names, public emails and institutions
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExpandedSearchResult {
+    orcid_id: OrcidId,
+    given_names: Option<String>,
+    family_names: Option<String>,
+    credit_name: Option<String>,
+    other_names: Vec<String>,
+    emails: Vec<String>,
+    institution_names: Vec<String>,
+}
+
+impl ExpandedSearchResult {
+    /// Parses one element of an `expanded-result` array; `None` without a valid ORCID iD
+    pub fn new_from_json(j: &serde_json::Value) -> Option<Self> {
+        let string = |key: &str| {
+            j[key]
+                .as_str()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+        };
+        let strings = |key: &str| -> Vec<String> {
+            j[key]
+                .as_array()
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(|v| v.as_str())
+                        .map(|s| s.to_string())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Some(Self {
+            orcid_id: j["orcid-id"].as_str()?.parse().ok()?,
+            given_names: string("given-names"),
+            family_names: string("family-names"),
+            credit_name: string("credit-name"),
+            other_names: strings("other-name"),
+            emails: strings("email"),
+            institution_names: strings("institution-name"),
+        })
+    }
+
+    // Getter methods
+    pub fn orcid_id(&self) -> &OrcidId {
+        &self.orcid_id
+    }
+
+    pub fn given_names(&self) -> Option<&String> {
+        self.given_names.as_ref()
+    }
+
+    pub fn family_names(&self) -> Option<&String> {
+        self.family_names.as_ref()
+    }
+
+    pub fn credit_name(&self) -> Option<&String> {
+        self.credit_name.as_ref()
+    }
+
+    pub fn other_names(&self) -> &Vec<String> {
+        &self.other_names
+    }
+
+    /// Public email addresses
+    pub fn emails(&self) -> &Vec<String> {
+        &self.emails
+    }
+
+    /// Names of the organizations of current and past affiliations
+    pub fn institution_names(&self) -> &Vec<String> {
+        &self.institution_names
+    }
+
+    /// The credit name if set, otherwise given and family names
+    pub fn display_name(&self) -> Option<String> {
+        if let Some(ref credit_name) = self.credit_name {
+            return Some(credit_name.to_owned());
+        }
+        let parts: Vec<&str> = [&self.given_names, &self.family_names]
+            .iter()
+            .filter_map(|part| part.as_deref())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
+}
+
+impl SearchHit for ExpandedSearchResult {
+    const ENDPOINT: &'static str = "expanded-search";
+    const RESULT_KEY: &'static str = "expanded-result";
+
+    fn from_search_json(j: &serde_json::Value) -> Option<Self> {
+        Self::new_from_json(j)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search_results::SearchResults;
+    use serde_json::json;
+
+    #[test]
+    fn test_new_from_json() {
+        let result = ExpandedSearchResult::new_from_json(&json!({
+            "orcid-id": "0000-0002-1825-0097",
+            "given-names": "Josiah",
+            "family-names": "Carberry",
+            "credit-name": null,
+            "other-name": ["J. Carberry", "Josiah S. Carberry"],
+            "email": ["jcarberry@example.org"],
+            "institution-name": ["Brown University", "Wesleyan University"]
+        }))
+        .unwrap();
+        assert_eq!(result.orcid_id().as_str(), "0000-0002-1825-0097");
+        assert_eq!(result.given_names(), Some(&"Josiah".to_string()));
+        assert_eq!(result.credit_name(), None);
+        assert_eq!(result.other_names().len(), 2);
+        assert_eq!(result.emails(), &vec!["jcarberry@example.org".to_string()]);
+        assert_eq!(result.institution_names()[1], "Wesleyan University");
+        assert_eq!(result.display_name(), Some("Josiah Carberry".to_string()));
+    }
+
+    #[test]
+    fn test_search_results() {
+        let results: SearchResults<ExpandedSearchResult> = SearchResults::new_from_json(&json!({
+            "expanded-result": [
+                { "orcid-id": "0000-0002-1825-0097", "credit-name": "J. S. Carberry" },
+                { "given-names": "No ID" },
+                { "orcid-id": "0000-0002-1825-0098", "given-names": "Bad check digit" }
+            ],
+            "num-found": 40
+        }))
+        .unwrap();
+        assert_eq!(results.num_found(), 40);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results.items()[0].display_name(),
+            Some("J. S. Carberry".to_string())
+        );
+        let empty: SearchResults<ExpandedSearchResult> =
+            SearchResults::new_from_json(&json!({ "expanded-result": null, "num-found": 0 }))
+                .unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let result = ExpandedSearchResult::new_from_json(&json!({
+            "orcid-id": "0000-0002-1825-0097",
+            "family-names": "Carberry",
+            "email": ["jcarberry@example.org"]
+        }))
+        .unwrap();
+        let s = serde_json::to_string(&result).unwrap();
+        let result2: ExpandedSearchResult = serde_json::from_str(&s).unwrap();
+        assert_eq!(result, result2);
+    }
+}
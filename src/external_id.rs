@@ -0,0 +1,550 @@
+use crate::record;
+use crate::utils::strip_prefixes;
+use serde_json::json;
+use std::fmt;
+
+/// An identifier type from ORCID's list, e.g. `doi` or `grant_number`.
+/// Types ORCID does not list, such as person identifiers like `Scopus Author ID`,
+/// are kept verbatim as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ExternalIdType {
+    Agr,
+    Ark,
+    Arxiv,
+    Asin,
+    Bibcode,
+    Cienciaiul,
+    Doi,
+    Eid,
+    Ethos,
+    GrantNumber,
+    Hal,
+    Handle,
+    Isbn,
+    Ismn,
+    Issn,
+    Jstor,
+    Lccn,
+    Lensid,
+    Mr,
+    Oclc,
+    Ol,
+    Osti,
+    OtherId,
+    Pat,
+    Pdb,
+    Pmc,
+    Pmid,
+    Ppr,
+    ProposalId,
+    Rfc,
+    Rrid,
+    SourceWorkId,
+    Ssrn,
+    Uri,
+    Urn,
+    Wosuid,
+    Zbl,
+    Other(String),
+}
+
+const ID_TYPES: [(ExternalIdType, &str); 37] = [
+    (ExternalIdType::Agr, "agr"),
+    (ExternalIdType::Ark, "ark"),
+    (ExternalIdType::Arxiv, "arxiv"),
+    (ExternalIdType::Asin, "asin"),
+    (ExternalIdType::Bibcode, "bibcode"),
+    (ExternalIdType::Cienciaiul, "cienciaiul"),
+    (ExternalIdType::Doi, "doi"),
+    (ExternalIdType::Eid, "eid"),
+    (ExternalIdType::Ethos, "ethos"),
+    (ExternalIdType::GrantNumber, "grant_number"),
+    (ExternalIdType::Hal, "hal"),
+    (ExternalIdType::Handle, "handle"),
+    (ExternalIdType::Isbn, "isbn"),
+    (ExternalIdType::Ismn, "ismn"),
+    (ExternalIdType::Issn, "issn"),
+    (ExternalIdType::Jstor, "jstor"),
+    (ExternalIdType::Lccn, "lccn"),
+    (ExternalIdType::Lensid, "lensid"),
+    (ExternalIdType::Mr, "mr"),
+    (ExternalIdType::Oclc, "oclc"),
+    (ExternalIdType::Ol, "ol"),
+    (ExternalIdType::Osti, "osti"),
+    (ExternalIdType::OtherId, "other-id"),
+    (ExternalIdType::Pat, "pat"),
+    (ExternalIdType::Pdb, "pdb"),
+    (ExternalIdType::Pmc, "pmc"),
+    (ExternalIdType::Pmid, "pmid"),
+    (ExternalIdType::Ppr, "ppr"),
+    (ExternalIdType::ProposalId, "proposal-id"),
+    (ExternalIdType::Rfc, "rfc"),
+    (ExternalIdType::Rrid, "rrid"),
+    (ExternalIdType::SourceWorkId, "source-work-id"),
+    (ExternalIdType::Ssrn, "ssrn"),
+    (ExternalIdType::Uri, "uri"),
+    (ExternalIdType::Urn, "urn"),
+    (ExternalIdType::Wosuid, "wosuid"),
+    (ExternalIdType::Zbl, "zbl"),
+];
+
+impl ExternalIdType {
+    /// The name ORCID uses, e.g. `grant_number`
+    pub fn as_str(&self) -> &str {
+        match self {
+            ExternalIdType::Other(s) => s,
+            id_type => ID_TYPES
+                .iter()
+                .find(|(t, _)| t == id_type)
+                .map_or("", |(_, name)| name),
+        }
+    }
+
+    /// Brings `value` into the form used to compare IDs of this type:
+    ///
+    /// - DOIs lose `https://doi.org/` and `doi:` prefixes and are lowercased
+    /// - ISBNs lose hyphens and spaces
+    /// - PMC IDs get the `PMC` prefix
+    /// - ISSNs are uppercased and hyphenated, e.g. `0028-0836`
+    /// - arXiv IDs lose `arXiv:` and `https://arxiv.org/abs/` prefixes
+    /// - Handles lose the `https://hdl.handle.net/` prefix
+    ///
+    /// Every value is trimmed.
+    pub fn normalize(&self, value: &str) -> String {
+        let value = value.trim();
+        match self {
+            ExternalIdType::Doi => strip_prefixes(
+                value,
+                &[
+                    "https://doi.org/",
+                    "http://doi.org/",
+                    "https://dx.doi.org/",
+                    "http://dx.doi.org/",
+                    "doi.org/",
+                    "doi:",
+                ],
+            )
+            .to_lowercase(),
+            ExternalIdType::Isbn => value
+                .chars()
+                .filter(|c| *c != '-' && !c.is_whitespace())
+                .collect::<String>()
+                .to_uppercase(),
+            ExternalIdType::Pmc => {
+                let value = value.to_uppercase();
+                if value.starts_with("PMC") {
+                    value
+                } else {
+                    format!("PMC{}", value)
+                }
+            }
+            ExternalIdType::Issn => {
+                let value = value.to_uppercase();
+                if value.len() == 8 && value.is_ascii() {
+                    format!("{}-{}", &value[..4], &value[4..])
+                } else {
+                    value
+                }
+            }
+            ExternalIdType::Arxiv => strip_prefixes(
+                value,
+                &[
+                    "https://arxiv.org/abs/",
+                    "http://arxiv.org/abs/",
+                    "arxiv.org/abs/",
+                    "arxiv:",
+                ],
+            )
+            .to_string(),
+            ExternalIdType::Handle => strip_prefixes(
+                value,
+                &["https://hdl.handle.net/", "http://hdl.handle.net/"],
+            )
+            .to_string(),
+            _ => value.to_string(),
+        }
+    }
+}
+
+impl From<&str> for ExternalIdType {
+    fn from(s: &str) -> Self {
+        ID_TYPES
+            .iter()
+            .find(|(_, name)| *name == s)
+            .map_or_else(|| ExternalIdType::Other(s.to_string()), |(t, _)| t.clone())
+    }
+}
+
+impl fmt::Display for ExternalIdType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How an identifier relates to the item it is attached to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExternalIdRelationship {
+    /// Identifies the item itself
+    Self_,
+    PartOf,
+    VersionOf,
+    FundedBy,
+    Other(String),
+}
+
+impl ExternalIdRelationship {
+    /// The name ORCID uses, e.g. `part-of`
+    pub fn as_str(&self) -> &str {
+        match self {
+            ExternalIdRelationship::Self_ => "self",
+            ExternalIdRelationship::PartOf => "part-of",
+            ExternalIdRelationship::VersionOf => "version-of",
+            ExternalIdRelationship::FundedBy => "funded-by",
+            ExternalIdRelationship::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for ExternalIdRelationship {
+    fn from(s: &str) -> Self {
+        match s {
+            "self" => ExternalIdRelationship::Self_,
+            "part-of" => ExternalIdRelationship::PartOf,
+            "version-of" => ExternalIdRelationship::VersionOf,
+            "funded-by" => ExternalIdRelationship::FundedBy,
+            s => ExternalIdRelationship::Other(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ExternalIdRelationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An identifier of a work, funding, affiliation or person, e.g. a DOI.
+/// The value is normalized for its type, so IDs from different sources can be compared.
+///
+/// # Example
+///
+/// ```
+/// use orcid::{ExternalId, ExternalIdType};
+///
+/// let id = ExternalId::new(ExternalIdType::Doi, "https://doi.org/10.1234/ABC");
+/// assert_eq!(id.value(), "10.1234/abc");
+/// assert!(id.matches(&ExternalId::new("doi".into(), "10.1234/abc")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExternalId {
+    id_type: ExternalIdType,
+    value: String,
+    relationship: Option<ExternalIdRelationship>,
+    url: Option<String>,
+}
+
+impl ExternalId {
+    /// Normalizes `value` for `id_type`
+    pub fn new(id_type: ExternalIdType, value: &str) -> Self {
+        Self {
+            value: id_type.normalize(value),
+            id_type,
+            relationship: None,
+            url: None,
+        }
+    }
+
+    pub fn with_relationship(mut self, relationship: ExternalIdRelationship) -> Self {
+        self.relationship = Some(relationship);
+        self
+    }
+
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    // Getter methods
+    pub fn id_type(&self) -> &ExternalIdType {
+        &self.id_type
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn relationship(&self) -> Option<&ExternalIdRelationship> {
+        self.relationship.as_ref()
+    }
+
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+
+    /// True if both IDs have the same type and normalized value,
+    /// regardless of relationship and URL
+    pub fn matches(&self, other: &ExternalId) -> bool {
+        self.id_type == other.id_type && self.value == other.value
+    }
+
+    /// Serializes to an ORCID v3.0 `external-id` object. The relationship
+    /// defaults to `self`, which is what ORCID expects for identifiers of the item itself.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = json!({
+            "external-id-type": self.id_type.as_str(),
+            "external-id-value": self.value,
+            "external-id-relationship": self
+                .relationship
+                .as_ref()
+                .map_or("self", ExternalIdRelationship::as_str),
+        });
+        if let Some(ref url) = self.url {
+            j["external-id-url"] = json!({ "value": url });
+        }
+        j
+    }
+
+    /// IDs without a type or value are skipped
+    fn from_parts(
+        id_type: &Option<String>,
+        value: &Option<String>,
+        relationship: &Option<String>,
+        url: &Option<record::StringValue>,
+    ) -> Option<Self> {
+        let mut ret = Self::new(id_type.as_deref()?.into(), value.as_deref()?);
+        ret.relationship = relationship.as_deref().map(ExternalIdRelationship::from);
+        ret.url = url
+            .as_ref()
+            .and_then(record::StringValue::as_str)
+            .map(str::to_string);
+        Some(ret)
+    }
+
+    pub(crate) fn from_record(id: &record::ExternalId) -> Option<Self> {
+        Self::from_parts(
+            &id.external_id_type,
+            &id.external_id_value,
+            &id.external_id_relationship,
+            &id.external_id_url,
+        )
+    }
+
+    pub(crate) fn from_person_record(id: &record::PersonExternalIdentifier) -> Option<Self> {
+        Self::from_parts(
+            &id.external_id_type,
+            &id.external_id_value,
+            &id.external_id_relationship,
+            &id.external_id_url,
+        )
+    }
+}
+
+/// The IDs of an `external-ids` object that have both a type and a value
+pub(crate) fn from_record_ids(ids: &Option<record::ExternalIds>) -> Vec<ExternalId> {
+    ids.as_ref()
+        .map(|ids| {
+            ids.external_id
+                .iter()
+                .filter_map(ExternalId::from_record)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExternalIdType {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExternalIdType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExternalIdRelationship {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExternalIdRelationship {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_type() {
+        assert_eq!(ExternalIdType::from("doi"), ExternalIdType::Doi);
+        assert_eq!(
+            ExternalIdType::from("grant_number"),
+            ExternalIdType::GrantNumber
+        );
+        assert_eq!(ExternalIdType::from("other-id"), ExternalIdType::OtherId);
+        assert_eq!(
+            ExternalIdType::from("Scopus Author ID"),
+            ExternalIdType::Other("Scopus Author ID".to_string())
+        );
+        for (id_type, name) in ID_TYPES.iter() {
+            assert_eq!(id_type.as_str(), *name);
+            assert_eq!(&ExternalIdType::from(*name), id_type);
+        }
+        assert_eq!(
+            ExternalIdType::Other("ResearcherID".to_string()).to_string(),
+            "ResearcherID"
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let cases = [
+            (
+                ExternalIdType::Doi,
+                "https://doi.org/10.1234/ABC",
+                "10.1234/abc",
+            ),
+            (
+                ExternalIdType::Doi,
+                "HTTP://DX.DOI.ORG/10.1234/abc",
+                "10.1234/abc",
+            ),
+            (ExternalIdType::Doi, "doi:10.1234/Abc ", "10.1234/abc"),
+            (ExternalIdType::Isbn, "978-0-306-40615-7", "9780306406157"),
+            (ExternalIdType::Isbn, "0 8044 2957 x", "080442957X"),
+            (ExternalIdType::Pmc, "1234567", "PMC1234567"),
+            (ExternalIdType::Pmc, "pmc1234567", "PMC1234567"),
+            (ExternalIdType::Issn, "0028083x", "0028-083X"),
+            (ExternalIdType::Issn, "0028-0836", "0028-0836"),
+            (ExternalIdType::Arxiv, "arXiv:2101.00001", "2101.00001"),
+            (
+                ExternalIdType::Arxiv,
+                "https://arxiv.org/abs/2101.00001",
+                "2101.00001",
+            ),
+            (
+                ExternalIdType::Handle,
+                "https://hdl.handle.net/10419/12345",
+                "10419/12345",
+            ),
+            (ExternalIdType::Pmid, " 12345678 ", "12345678"),
+            (ExternalIdType::Eid, "2-s2.0-ABC", "2-s2.0-ABC"),
+        ];
+        for (id_type, value, expected) in cases {
+            assert_eq!(id_type.normalize(value), expected, "{} {}", id_type, value);
+        }
+    }
+
+    #[test]
+    fn test_relationship() {
+        for name in [
+            "self",
+            "part-of",
+            "version-of",
+            "funded-by",
+            "something-else",
+        ] {
+            assert_eq!(ExternalIdRelationship::from(name).as_str(), name);
+        }
+        assert_eq!(
+            ExternalIdRelationship::from("self"),
+            ExternalIdRelationship::Self_
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let a = ExternalId::new(ExternalIdType::Doi, "10.1234/ABC")
+            .with_relationship(ExternalIdRelationship::Self_);
+        let b = ExternalId::new(ExternalIdType::Doi, "https://doi.org/10.1234/abc")
+            .with_url("https://doi.org/10.1234/abc");
+        assert!(a.matches(&b));
+        assert_ne!(a, b);
+        assert!(!a.matches(&ExternalId::new(ExternalIdType::Handle, "10.1234/abc")));
+    }
+
+    #[test]
+    fn test_from_record() {
+        let ids = record::from_json::<record::ExternalIds>(&json!({ "external-id": [
+            {
+                "external-id-type": "doi",
+                "external-id-value": "10.1234/ABC",
+                "external-id-url": { "value": "https://doi.org/10.1234/ABC" },
+                "external-id-relationship": "part-of"
+            },
+            { "external-id-type": "pmc", "external-id-value": "42" },
+            { "external-id-type": "isbn" }
+        ] }));
+        let ids = from_record_ids(&Some(ids));
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].id_type(), &ExternalIdType::Doi);
+        assert_eq!(ids[0].value(), "10.1234/abc");
+        assert_eq!(ids[0].relationship(), Some(&ExternalIdRelationship::PartOf));
+        assert_eq!(
+            ids[0].url(),
+            Some(&"https://doi.org/10.1234/ABC".to_string())
+        );
+        assert_eq!(ids[1].value(), "PMC42");
+        assert_eq!(ids[1].relationship(), None);
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            ExternalId::new(ExternalIdType::GrantNumber, "GR-1").to_json(),
+            json!({
+                "external-id-type": "grant_number",
+                "external-id-value": "GR-1",
+                "external-id-relationship": "self"
+            })
+        );
+        assert_eq!(
+            ExternalId::new(ExternalIdType::Doi, "10.1/x")
+                .with_relationship(ExternalIdRelationship::FundedBy)
+                .with_url("https://doi.org/10.1/x")
+                .to_json(),
+            json!({
+                "external-id-type": "doi",
+                "external-id-value": "10.1/x",
+                "external-id-relationship": "funded-by",
+                "external-id-url": { "value": "https://doi.org/10.1/x" }
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let id = ExternalId::new(ExternalIdType::Doi, "10.1/x")
+            .with_relationship(ExternalIdRelationship::Self_);
+        let serialized = serde_json::to_value(&id).unwrap();
+        assert_eq!(
+            serialized,
+            json!({ "id_type": "doi", "value": "10.1/x", "relationship": "self", "url": null })
+        );
+        assert_eq!(
+            serde_json::from_value::<ExternalId>(serialized).unwrap(),
+            id
+        );
+    }
+}
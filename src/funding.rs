@@ -1,9 +1,15 @@
-use crate::date::Date;
+use crate::activity_group::GroupedActivity;
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::external_id::{self, ExternalId};
+use crate::fuzzy_date::FuzzyDate;
 use crate::organization::Organization;
+use crate::record;
 use serde_json;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Funding {
+    metadata: ActivityMetadata,
     title: Option<String>,
     translated_title: Option<(String, String)>, // (title, language_code)
     funding_type: Option<String>,
@@ -11,94 +17,31 @@ pub struct Funding {
     short_description: Option<String>,
     amount: Option<String>,
     currency: Option<String>,
-    start_date: Option<Date>,
-    end_date: Option<Date>,
+    start_date: Option<FuzzyDate>,
+    end_date: Option<FuzzyDate>,
     organization: Option<Organization>,
-    external_ids: Vec<(String, String)>,
+    external_ids: Vec<ExternalId>,
     url: Option<String>,
 }
 
 impl Funding {
+    /// Parses the first summary of a `fundings` group
     pub fn new_from_json(j: &serde_json::Value) -> Self {
-        let translated_title =
-            match j["funding-summary"][0]["title"]["translated-title"].as_object() {
-                Some(tt) => match (tt.get("value"), tt.get("language-code")) {
-                    (Some(title), Some(lang)) => match (title.as_str(), lang.as_str()) {
-                        (Some(t), Some(l)) => Some((t.to_string(), l.to_string())),
-                        _ => None,
-                    },
-                    _ => None,
-                },
-                None => None,
-            };
-
-        let external_ids = if let Some(ext_ids) =
-            j["funding-summary"][0]["external-ids"]["external-id"].as_array()
-        {
-            ext_ids
-                .iter()
-                .filter_map(|id| {
-                    match (
-                        id["external-id-type"].as_str(),
-                        id["external-id-value"].as_str(),
-                    ) {
-                        (Some(id_type), Some(id_value)) => {
-                            Some((id_type.to_string(), id_value.to_string()))
-                        }
-                        _ => None,
-                    }
-                })
-                .collect()
-        } else {
-            vec![]
-        };
+        Self::new_from_summary_json(&j["funding-summary"][0])
+    }
 
-        Self {
-            title: j["funding-summary"][0]["title"]["title"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-            translated_title,
-            funding_type: j["funding-summary"][0]["type"]
-                .as_str()
-                .map(|s| s.to_string()),
-            organization_defined_type: j["funding-summary"][0]["organization-defined-type"]
-                ["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-            short_description: j["funding-summary"][0]["short-description"]
-                .as_str()
-                .map(|s| s.to_string()),
-            amount: j["funding-summary"][0]["amount"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-            currency: j["funding-summary"][0]["amount"]["currency-code"]
-                .as_str()
-                .map(|s| s.to_string()),
-            start_date: if j["funding-summary"][0]["start-date"].is_object() {
-                Some(Date::new_from_json(&j["funding-summary"][0]["start-date"]))
-            } else {
-                None
-            },
-            end_date: if j["funding-summary"][0]["end-date"].is_object() {
-                Some(Date::new_from_json(&j["funding-summary"][0]["end-date"]))
-            } else {
-                None
-            },
-            organization: if j["funding-summary"][0]["organization"].is_object() {
-                Some(Organization::new_from_json(
-                    &j["funding-summary"][0]["organization"],
-                ))
-            } else {
-                None
-            },
-            external_ids,
-            url: j["funding-summary"][0]["url"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-        }
+    /// Parses a single `funding-summary` object
+    pub fn new_from_summary_json(funding_summary: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::FundingSummary>(
+            funding_summary,
+        ))
     }
 
     // Getter methods
+    pub fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+
     pub fn title(&self) -> Option<&String> {
         self.title.as_ref()
     }
@@ -127,11 +70,11 @@ impl Funding {
         self.currency.as_ref()
     }
 
-    pub fn start_date(&self) -> Option<&Date> {
+    pub fn start_date(&self) -> Option<&FuzzyDate> {
         self.start_date.as_ref()
     }
 
-    pub fn end_date(&self) -> Option<&Date> {
+    pub fn end_date(&self) -> Option<&FuzzyDate> {
         self.end_date.as_ref()
     }
 
@@ -139,7 +82,7 @@ impl Funding {
         self.organization.as_ref()
     }
 
-    pub fn external_ids(&self) -> &Vec<(String, String)> {
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
         &self.external_ids
     }
 
@@ -148,6 +91,53 @@ impl Funding {
     }
 }
 
+impl Activity for Funding {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl GroupedActivity for Funding {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId> {
+        &mut self.external_ids
+    }
+}
+
+impl From<&record::FundingSummary> for Funding {
+    fn from(summary: &record::FundingSummary) -> Self {
+        let title = summary.title.as_ref();
+        let amount = summary.amount.as_ref();
+        Self {
+            metadata: ActivityMetadata::from(&summary.metadata),
+            title: title.and_then(|t| record::string_value(&t.title)),
+            translated_title: title.and_then(|t| t.translated_title.as_ref()).and_then(
+                |tt| match (&tt.value, &tt.language_code) {
+                    (Some(title), Some(lang)) => Some((title.clone(), lang.clone())),
+                    _ => None,
+                },
+            ),
+            funding_type: summary.funding_type.clone(),
+            organization_defined_type: record::string_value(&summary.organization_defined_type),
+            short_description: summary.short_description.clone(),
+            amount: amount
+                .and_then(|a| a.value.as_ref())
+                .map(|v| v.as_str().to_string()),
+            currency: amount.and_then(|a| a.currency_code.clone()),
+            start_date: summary
+                .start_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            end_date: summary
+                .end_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            organization: summary.organization.as_ref().map(Organization::from),
+            external_ids: external_id::from_record_ids(&summary.external_ids),
+            url: record::string_value(&summary.url),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,8 +224,11 @@ mod tests {
         assert!(funding.organization().is_some());
         assert_eq!(funding.external_ids().len(), 1);
         assert_eq!(
-            funding.external_ids()[0],
-            ("grant_number".to_string(), "NSF-2023-12345".to_string())
+            (
+                funding.external_ids()[0].id_type().as_str(),
+                funding.external_ids()[0].value()
+            ),
+            ("grant_number", "NSF-2023-12345")
         );
         assert_eq!(
             funding.url(),
@@ -326,22 +319,32 @@ mod tests {
         assert_eq!(funding.title(), Some(&"Multi-ID Grant".to_string()));
         assert_eq!(funding.external_ids().len(), 3);
         assert_eq!(
-            funding.external_ids()[0],
-            ("grant_number".to_string(), "ABC-123".to_string())
+            (
+                funding.external_ids()[0].id_type().as_str(),
+                funding.external_ids()[0].value()
+            ),
+            ("grant_number", "ABC-123")
         );
         assert_eq!(
-            funding.external_ids()[1],
-            ("proposal_id".to_string(), "PROP-456".to_string())
+            (
+                funding.external_ids()[1].id_type().as_str(),
+                funding.external_ids()[1].value()
+            ),
+            ("proposal_id", "PROP-456")
         );
         assert_eq!(
-            funding.external_ids()[2],
-            ("award_number".to_string(), "AWD-789".to_string())
+            (
+                funding.external_ids()[2].id_type().as_str(),
+                funding.external_ids()[2].value()
+            ),
+            ("award_number", "AWD-789")
         );
     }
 
     #[test]
     fn test_debug_trait() {
         let funding = Funding {
+            metadata: ActivityMetadata::default(),
             title: Some("Test Grant".to_string()),
             translated_title: None,
             funding_type: Some("grant".to_string()),
@@ -365,6 +368,7 @@ mod tests {
     #[test]
     fn test_clone_trait() {
         let funding = Funding {
+            metadata: ActivityMetadata::default(),
             title: Some("Test Grant".to_string()),
             translated_title: Some(("Translated".to_string(), "en".to_string())),
             funding_type: Some("grant".to_string()),
@@ -375,7 +379,7 @@ mod tests {
             start_date: None,
             end_date: None,
             organization: None,
-            external_ids: vec![("id".to_string(), "123".to_string())],
+            external_ids: vec![ExternalId::new("id".into(), "123")],
             url: None,
         };
 
@@ -387,4 +391,45 @@ mod tests {
         assert_eq!(cloned.currency(), funding.currency());
         assert_eq!(cloned.external_ids(), funding.external_ids());
     }
+
+    #[test]
+    fn test_new_from_json_metadata() {
+        let j = json!({
+            "funding-summary": [{
+                "put-code": 4242,
+                "visibility": "limited",
+                "last-modified-date": { "value": 1700000000000u64 },
+                "source": { "source-name": { "value": "Jane Doe" } }
+            }]
+        });
+        let metadata = Funding::new_from_json(&j).metadata().clone();
+        assert_eq!(metadata.put_code(), Some(4242));
+        assert_eq!(metadata.visibility(), Some(&"limited".to_string()));
+        assert!(metadata.last_modified_date().is_some());
+        assert_eq!(
+            metadata.source().and_then(|s| s.name()),
+            Some(&"Jane Doe".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let j = json!({
+            "funding-summary": [{
+                "title": { "title": { "value": "Grant" } },
+                "type": "grant",
+                "amount": { "value": "1000", "currency-code": "EUR" },
+                "start-date": { "year": { "value": "2023" } },
+                "organization": { "name": "ERC" }
+            }]
+        });
+        let funding = Funding::new_from_json(&j);
+        let serialized = serde_json::to_value(&funding).unwrap();
+        assert_eq!(serialized["funding_type"], "grant");
+        assert_eq!(serialized["currency"], "EUR");
+        assert_eq!(serialized["organization"]["name"], "ERC");
+        let back: Funding = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, funding);
+    }
 }
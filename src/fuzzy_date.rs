@@ -0,0 +1,417 @@
+use crate::error::{OrcidError, Result};
+use crate::record;
+use serde_json;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// An ORCID date: a year, optionally with a month, and a day if there is a month.
+///
+/// Dates sort chronologically; a less precise date sorts before the more precise
+/// ones it contains, e.g. `2023` < `2023-06` < `2023-06-01`.
+///
+/// # Example
+///
+/// ```
+/// use orcid::FuzzyDate;
+///
+/// let date: FuzzyDate = "2023-06".parse().unwrap();
+/// assert_eq!(date.year(), 2023);
+/// assert_eq!(date.month(), Some(6));
+/// assert_eq!(date.to_string(), "2023-06");
+/// assert!("2023-02-30".parse::<FuzzyDate>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "FuzzyDateParts"))]
+pub struct FuzzyDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl FuzzyDate {
+    /// Fails if the month is not 1-12, the day does not exist in the month,
+    /// or there is a day without a month
+    pub fn new(year: u16, month: Option<u8>, day: Option<u8>) -> Result<Self> {
+        let valid = match (month, day) {
+            (None, None) => true,
+            (Some(month), None) => (1..=12).contains(&month),
+            (Some(month), Some(day)) => {
+                (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)
+            }
+            (None, Some(_)) => false,
+        };
+        let date = Self { year, month, day };
+        if valid {
+            Ok(date)
+        } else {
+            Err(OrcidError::InvalidDate(format!(
+                "{:04}-{}-{}",
+                year,
+                month.map_or("??".to_string(), |m| format!("{:02}", m)),
+                day.map_or("??".to_string(), |d| format!("{:02}", d)),
+            )))
+        }
+    }
+
+    /// Parses an ORCID date object, whose values may be strings like `"09"` or numbers.
+    /// Returns `None` without a valid year; an invalid month or day is dropped.
+    pub fn new_from_json(j: &serde_json::Value) -> Option<Self> {
+        Self::try_from(&record::from_json::<record::FuzzyDate>(j)).ok()
+    }
+
+    // Getter methods
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// The first day the date covers, e.g. `2023-01-01` for `2023`
+    pub fn first_day(&self) -> Self {
+        Self {
+            year: self.year,
+            month: Some(self.month.unwrap_or(1)),
+            day: Some(self.day.unwrap_or(1)),
+        }
+    }
+
+    /// The last day the date covers, e.g. `2023-12-31` for `2023`
+    pub fn last_day(&self) -> Self {
+        let month = self.month.unwrap_or(12);
+        Self {
+            year: self.year,
+            month: Some(month),
+            day: Some(self.day.unwrap_or(days_in_month(self.year, month))),
+        }
+    }
+
+    /// Today's date in UTC
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        // Days since 1970-01-01 to a civil date, after Howard Hinnant's `civil_from_days`
+        let z = (secs / 86_400) as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as u16,
+            month: Some(month as u8),
+            day: Some(day as u8),
+        }
+    }
+
+    /// Serializes to an ORCID v3.0 date object, with zero-padded month and day
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = serde_json::json!({ "year": { "value": format!("{:04}", self.year) } });
+        if let Some(month) = self.month {
+            j["month"] = serde_json::json!({ "value": format!("{:02}", month) });
+        }
+        if let Some(day) = self.day {
+            j["day"] = serde_json::json!({ "value": format!("{:02}", day) });
+        }
+        j
+    }
+
+    /// The date as a `chrono::NaiveDate`, if it has a month and a day
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(self.year as i32, self.month? as u32, self.day? as u32)
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// The serde shape of `FuzzyDate`, checked by `FuzzyDate::new` when deserializing
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FuzzyDateParts {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FuzzyDateParts> for FuzzyDate {
+    type Error = OrcidError;
+
+    fn try_from(parts: FuzzyDateParts) -> Result<Self> {
+        Self::new(parts.year, parts.month, parts.day)
+    }
+}
+
+impl TryFrom<&record::FuzzyDate> for FuzzyDate {
+    type Error = OrcidError;
+
+    fn try_from(date: &record::FuzzyDate) -> Result<Self> {
+        let year = record::parse_value(&date.year).ok_or_else(|| {
+            OrcidError::InvalidDate(record::string_value(&date.year).unwrap_or_default())
+        })?;
+        let month = record::parse_value(&date.month);
+        let day = record::parse_value(&date.day);
+        Self::new(year, month, day)
+            .or_else(|_| Self::new(year, month, None))
+            .or_else(|_| Self::new(year, None, None))
+    }
+}
+
+/// ISO 8601: `YYYY`, `YYYY-MM` or `YYYY-MM-DD`
+impl fmt::Display for FuzzyDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for FuzzyDate {
+    type Err = OrcidError;
+
+    /// Parses `YYYY`, `YYYY-MM` or `YYYY-MM-DD`
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || OrcidError::InvalidDate(s.to_string());
+        let parts: Vec<&str> = s.trim().split('-').collect();
+        if parts.len() > 3 || parts.iter().any(|p| p.is_empty()) {
+            return Err(invalid());
+        }
+        let year = parts[0].parse().map_err(|_| invalid())?;
+        let month = match parts.get(1) {
+            Some(month) => Some(month.parse().map_err(|_| invalid())?),
+            None => None,
+        };
+        let day = match parts.get(2) {
+            Some(day) => Some(day.parse().map_err(|_| invalid())?),
+            None => None,
+        };
+        Self::new(year, month, day).map_err(|_| invalid())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for FuzzyDate {
+    type Error = OrcidError;
+
+    /// Fails for years before 0 or after 65535
+    fn try_from(date: chrono::NaiveDate) -> Result<Self> {
+        use chrono::Datelike;
+        let year =
+            u16::try_from(date.year()).map_err(|_| OrcidError::InvalidDate(date.to_string()))?;
+        Ok(Self {
+            year,
+            month: Some(date.month() as u8),
+            day: Some(date.day() as u8),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_new() {
+        assert!(FuzzyDate::new(2023, Some(12), Some(31)).is_ok());
+        assert!(FuzzyDate::new(2023, None, None).is_ok());
+        assert!(FuzzyDate::new(2024, Some(2), Some(29)).is_ok());
+        assert!(FuzzyDate::new(2023, Some(2), Some(29)).is_err());
+        assert!(FuzzyDate::new(1900, Some(2), Some(29)).is_err());
+        assert!(FuzzyDate::new(2000, Some(2), Some(29)).is_ok());
+        assert!(FuzzyDate::new(2023, Some(13), None).is_err());
+        assert!(FuzzyDate::new(2023, Some(0), None).is_err());
+        assert!(FuzzyDate::new(2023, Some(4), Some(31)).is_err());
+        assert!(FuzzyDate::new(2023, None, Some(1)).is_err());
+    }
+
+    #[test]
+    fn test_new_from_json_strings() {
+        let j = json!({
+            "year": { "value": "2023" },
+            "month": { "value": "09" },
+            "day": { "value": "01" }
+        });
+        let date = FuzzyDate::new_from_json(&j).unwrap();
+        assert_eq!(date.year(), 2023);
+        assert_eq!(date.month(), Some(9));
+        assert_eq!(date.day(), Some(1));
+    }
+
+    #[test]
+    fn test_new_from_json_numbers() {
+        let j = json!({
+            "year": { "value": 2023 },
+            "month": { "value": 12 },
+            "day": null
+        });
+        let date = FuzzyDate::new_from_json(&j).unwrap();
+        assert_eq!(date.year(), 2023);
+        assert_eq!(date.month(), Some(12));
+        assert_eq!(date.day(), None);
+    }
+
+    #[test]
+    fn test_new_from_json_invalid() {
+        let j = json!({
+            "year": { "value": "not a number" },
+            "month": { "value": "12" }
+        });
+        assert!(FuzzyDate::new_from_json(&j).is_none());
+        assert!(FuzzyDate::new_from_json(&json!({})).is_none());
+
+        // Invalid parts are dropped, the year is kept
+        let j = json!({
+            "year": { "value": "2023" },
+            "month": { "value": "13" },
+            "day": { "value": "32" }
+        });
+        assert_eq!(
+            FuzzyDate::new_from_json(&j),
+            Some(FuzzyDate::new(2023, None, None).unwrap())
+        );
+        let j = json!({
+            "year": { "value": "2023" },
+            "month": { "value": "02" },
+            "day": { "value": "30" }
+        });
+        assert_eq!(
+            FuzzyDate::new_from_json(&j),
+            Some(FuzzyDate::new(2023, Some(2), None).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut dates: Vec<FuzzyDate> = ["2023-06-01", "2022", "2023", "2023-06", "2023-01-15"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        dates.sort();
+        let dates: Vec<String> = dates.iter().map(FuzzyDate::to_string).collect();
+        assert_eq!(
+            dates,
+            vec!["2022", "2023", "2023-01-15", "2023-06", "2023-06-01"]
+        );
+    }
+
+    #[test]
+    fn test_first_and_last_day() {
+        let date: FuzzyDate = "2024".parse().unwrap();
+        assert_eq!(date.first_day().to_string(), "2024-01-01");
+        assert_eq!(date.last_day().to_string(), "2024-12-31");
+        let date: FuzzyDate = "2024-02".parse().unwrap();
+        assert_eq!(date.first_day().to_string(), "2024-02-01");
+        assert_eq!(date.last_day().to_string(), "2024-02-29");
+        let date: FuzzyDate = "2023-02-14".parse().unwrap();
+        assert_eq!(date.first_day(), date);
+        assert_eq!(date.last_day(), date);
+    }
+
+    #[test]
+    fn test_today() {
+        let today = FuzzyDate::today();
+        assert!(today.year() >= 2024);
+        // A valid full date
+        assert_eq!(today.to_string().parse::<FuzzyDate>().unwrap(), today);
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        for s in ["2023", "2023-06", "2023-06-05", "0999-01"] {
+            assert_eq!(s.parse::<FuzzyDate>().unwrap().to_string(), s);
+        }
+        for s in [
+            "",
+            "2023-",
+            "2023-13",
+            "2023-06-31",
+            "2023-06-01-01",
+            "June 2023",
+        ] {
+            assert!(s.parse::<FuzzyDate>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let date = FuzzyDate::new(2019, Some(9), Some(1)).unwrap();
+        assert_eq!(
+            date.to_json(),
+            json!({
+                "year": { "value": "2019" },
+                "month": { "value": "09" },
+                "day": { "value": "01" }
+            })
+        );
+        assert_eq!(FuzzyDate::new_from_json(&date.to_json()), Some(date));
+        assert_eq!(
+            FuzzyDate::new(2019, None, None).unwrap().to_json(),
+            json!({ "year": { "value": "2019" } })
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let date = FuzzyDate::try_from(naive).unwrap();
+        assert_eq!(date.to_string(), "2024-02-29");
+        for year in [-1, 70_000] {
+            let naive = chrono::NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+            assert!(matches!(
+                FuzzyDate::try_from(naive),
+                Err(OrcidError::InvalidDate(_))
+            ));
+        }
+        assert_eq!(date.to_naive_date(), Some(naive));
+        assert_eq!(
+            "2024-02".parse::<FuzzyDate>().unwrap().to_naive_date(),
+            None
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let date = FuzzyDate::new(2023, Some(6), None).unwrap();
+        let serialized = serde_json::to_value(date).unwrap();
+        assert_eq!(serialized, json!({ "year": 2023, "month": 6, "day": null }));
+        let back: FuzzyDate = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, date);
+        for invalid in [
+            json!({ "year": 2023, "month": 13, "day": 99 }),
+            json!({ "year": 2023, "month": 2, "day": 29 }),
+            json!({ "year": 2023, "day": 1 }),
+        ] {
+            assert!(serde_json::from_value::<FuzzyDate>(invalid).is_err());
+        }
+    }
+}
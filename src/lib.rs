@@ -1,31 +1,79 @@
+pub mod activity_group;
+pub mod activity_metadata;
 pub mod author;
 pub mod client;
 pub mod client_blocking;
-pub mod date;
+pub mod client_builder;
+pub mod contributor;
+#[cfg(feature = "csv")]
+pub mod csv_search;
+pub mod diagnostic;
 pub mod error;
+pub mod expanded_search_result;
+pub mod external_id;
 pub mod funding;
+pub mod fuzzy_date;
+mod member_api;
 pub mod membership;
+pub mod new_funding;
+pub mod new_peer_review;
+pub mod new_work;
+pub mod oauth;
+pub mod orcid_id;
+pub mod org_identifier;
 pub mod organization;
 pub mod peer_review;
-pub mod publication_date;
 pub mod qualification;
+pub mod query;
+pub mod record;
+pub mod research_resource;
 pub mod role;
+#[cfg(feature = "ror")]
+pub mod ror;
 pub mod search_builder;
+pub mod search_query;
+pub mod search_results;
+pub mod timeline;
+pub mod transport;
 pub mod utils;
 pub mod work;
+pub mod work_detail;
 
 // Re-export public structs for convenience
+pub use activity_group::ActivityGroup;
+pub use activity_metadata::{Activity, ActivityMetadata, Source};
 pub use author::Author;
 pub use client::Client;
 pub use client_blocking::ClientBlocking;
-pub use date::Date;
+pub use client_builder::{ClientBuilder, Environment};
+pub use contributor::Contributor;
+#[cfg(feature = "csv")]
+pub use csv_search::{CsvColumn, CsvSearchRow};
+pub use diagnostic::Diagnostic;
 pub use error::{OrcidError, Result};
+pub use expanded_search_result::ExpandedSearchResult;
+pub use external_id::{ExternalId, ExternalIdRelationship, ExternalIdType};
 pub use funding::Funding;
+pub use fuzzy_date::FuzzyDate;
 pub use membership::Membership;
+pub use new_funding::NewFunding;
+pub use new_peer_review::NewPeerReview;
+pub use new_work::NewWork;
+pub use oauth::{AuthorizationCode, ClientCredentials, TokenProvider, UserToken};
+pub use orcid_id::OrcidId;
+pub use org_identifier::OrgIdentifier;
 pub use organization::Organization;
 pub use peer_review::PeerReview;
-pub use publication_date::PublicationDate;
 pub use qualification::Qualification;
+pub use query::Q;
+pub use research_resource::{ResearchResource, ResourceItem};
 pub use role::Role;
-pub use search_builder::SearchBuilder;
+#[cfg(feature = "ror")]
+pub use ror::{RorCandidate, RorIndex, RorOrganization};
+pub use search_builder::{ExpandedSearchBuilder, SearchBuilder};
+pub use search_query::SearchQuery;
+pub use search_results::{SearchHit, SearchIter, SearchResults};
+pub use timeline::{Timeline, TimelineEntry, TimelineItem};
+pub use transport::{BlockingTransport, FixtureTransport, Transport};
 pub use work::Work;
+pub use work_detail::WorkDetail;
@@ -0,0 +1,189 @@
+use crate::error::{OrcidError, Result};
+use crate::transport::{Method, Request, Response};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+
+/// Maximum number of items in one bulk request
+pub(crate) const BULK_LIMIT: usize = 100;
+
+pub(crate) fn json_request(method: Method, url: &str, body: Option<serde_json::Value>) -> Request {
+    let request = Request::new(method, url).with_header(ACCEPT.as_str(), "application/json");
+    match body {
+        Some(body) => request
+            .with_header(CONTENT_TYPE.as_str(), "application/json")
+            .with_body(body.to_string()),
+        None => request,
+    }
+}
+
+/// Adds the put-code to an activity body, as required for updates
+pub(crate) fn with_put_code(mut body: serde_json::Value, put_code: u64) -> serde_json::Value {
+    body["put-code"] = serde_json::json!(put_code);
+    body
+}
+
+/// Turns unsuccessful responses into errors
+pub(crate) fn check_response(orcid_id: &str, response: Response) -> Result<Response> {
+    if response.is_success() {
+        return Ok(response);
+    }
+    let json = serde_json::from_str::<serde_json::Value>(&response.body).unwrap_or_default();
+    let developer_message = json["developer-message"]
+        .as_str()
+        .unwrap_or("no developer-message")
+        .to_string();
+    if response.status == 409 {
+        return Err(OrcidError::Conflict(developer_message));
+    }
+    match error_code(&json) {
+        Some(error_code) => Err(OrcidError::ApiError {
+            orcid_id: orcid_id.to_string(),
+            error_code,
+            developer_message,
+        }),
+        None => Err(OrcidError::HttpError {
+            status: response.status,
+            body: response.body,
+        }),
+    }
+}
+
+/// The ORCID error code of an error response, which may be a number or a string
+pub(crate) fn error_code(json: &serde_json::Value) -> Option<String> {
+    match &json["error-code"] {
+        serde_json::Value::String(s) => Some(s.to_owned()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Reads the put-code of a created item from the `Location` header,
+/// e.g. `https://api.orcid.org/v3.0/0000-0001-2345-6789/work/12345`
+pub(crate) fn put_code_from_location(response: &Response) -> Result<u64> {
+    response
+        .header("location")
+        .and_then(|location| location.trim_end_matches('/').rsplit('/').next())
+        .and_then(|put_code| put_code.parse().ok())
+        .ok_or_else(|| OrcidError::HttpError {
+            status: response.status,
+            body: format!(
+                "No put-code in Location header: {:?}",
+                response.header("location")
+            ),
+        })
+}
+
+/// Splits a bulk response of `expected` items into per-item results, in request order.
+/// Successful items are the value under `item_key`, e.g. `work`. Fails with
+/// `BadApiResponse` if the response has a different number of items.
+pub(crate) fn bulk_items<'a>(
+    orcid_id: &str,
+    item_key: &str,
+    json: &'a serde_json::Value,
+    expected: usize,
+) -> Result<Vec<Result<&'a serde_json::Value>>> {
+    let items = json["bulk"]
+        .as_array()
+        .filter(|items| items.len() == expected)
+        .ok_or_else(|| OrcidError::BadApiResponse(json.clone()))?;
+    Ok(items
+        .iter()
+        .map(|item| {
+            if let Some(value) = item.get(item_key) {
+                return Ok(value);
+            }
+            let error = &item["error"];
+            let developer_message = error["developer-message"]
+                .as_str()
+                .unwrap_or("no developer-message")
+                .to_string();
+            if error["response-code"].as_u64() == Some(409) {
+                return Err(OrcidError::Conflict(developer_message));
+            }
+            Err(OrcidError::ApiError {
+                orcid_id: orcid_id.to_string(),
+                error_code: error_code(error).unwrap_or_default(),
+                developer_message,
+            })
+        })
+        .collect())
+}
+
+/// Reads the put-codes of a bulk create request of `expected` items, in request order
+pub(crate) fn bulk_results(
+    orcid_id: &str,
+    item_key: &str,
+    json: &serde_json::Value,
+    expected: usize,
+) -> Result<Vec<Result<u64>>> {
+    Ok(bulk_items(orcid_id, item_key, json, expected)?
+        .into_iter()
+        .map(|item| {
+            let item = item?;
+            item["put-code"]
+                .as_u64()
+                .ok_or_else(|| OrcidError::BadApiResponse(item.clone()))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_put_code_from_location() {
+        let response = Response::new(201, "").with_header(
+            "Location",
+            "https://api.orcid.org/v3.0/0000-0001-2345-6789/work/12345",
+        );
+        assert_eq!(put_code_from_location(&response).unwrap(), 12345);
+        assert!(put_code_from_location(&Response::new(201, "")).is_err());
+    }
+
+    #[test]
+    fn test_check_response() {
+        assert!(check_response("x", Response::new(204, "")).is_ok());
+        let conflict = Response::new(
+            409,
+            r#"{"response-code":409,"developer-message":"Duplicate","error-code":9021}"#,
+        );
+        match check_response("x", conflict) {
+            Err(OrcidError::Conflict(message)) => assert_eq!(message, "Duplicate"),
+            _ => panic!("Expected Conflict"),
+        }
+        let forbidden = Response::new(
+            403,
+            r#"{"response-code":403,"developer-message":"Wrong source","error-code":9010}"#,
+        );
+        match check_response("x", forbidden) {
+            Err(OrcidError::ApiError { error_code, .. }) => assert_eq!(error_code, "9010"),
+            _ => panic!("Expected ApiError"),
+        }
+        match check_response("x", Response::new(502, "Bad Gateway")) {
+            Err(OrcidError::HttpError { status, .. }) => assert_eq!(status, 502),
+            _ => panic!("Expected HttpError"),
+        }
+    }
+
+    #[test]
+    fn test_bulk_results() {
+        let j = json!({
+            "bulk": [
+                { "work": { "put-code": 1 } },
+                { "error": { "response-code": 409, "developer-message": "Duplicate" } },
+                { "error": { "response-code": 400, "developer-message": "Bad", "error-code": 9001 } }
+            ]
+        });
+        let results = bulk_results("x", "work", &j, 3).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(*results[0].as_ref().unwrap(), 1);
+        assert!(matches!(results[1], Err(OrcidError::Conflict(_))));
+        assert!(matches!(results[2], Err(OrcidError::ApiError { .. })));
+        assert!(bulk_results("x", "work", &json!({}), 0).is_err());
+        assert!(matches!(
+            bulk_results("x", "work", &j, 4),
+            Err(OrcidError::BadApiResponse(_))
+        ));
+    }
+}
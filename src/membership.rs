@@ -1,74 +1,47 @@
-use crate::date::Date;
+use crate::activity_group::GroupedActivity;
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::external_id::{self, ExternalId};
+use crate::fuzzy_date::FuzzyDate;
 use crate::organization::Organization;
+use crate::record;
+use crate::utils::external_ids_to_json;
 use serde_json;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Membership {
+    metadata: ActivityMetadata,
     organization: Option<Organization>,
     department_name: Option<String>,
     role_title: Option<String>,
-    start_date: Option<Date>,
-    end_date: Option<Date>,
-    external_ids: Vec<(String, String)>,
+    start_date: Option<FuzzyDate>,
+    end_date: Option<FuzzyDate>,
+    external_ids: Vec<ExternalId>,
     url: Option<String>,
 }
 
 impl Membership {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the first summary of a `memberships` group
     pub fn new_from_json(j: &serde_json::Value) -> Self {
-        let membership_summary = &j["membership-summary"][0];
-
-        let external_ids =
-            if let Some(ext_ids) = membership_summary["external-ids"]["external-id"].as_array() {
-                ext_ids
-                    .iter()
-                    .filter_map(|id| {
-                        match (
-                            id["external-id-type"].as_str(),
-                            id["external-id-value"].as_str(),
-                        ) {
-                            (Some(id_type), Some(id_value)) => {
-                                Some((id_type.to_string(), id_value.to_string()))
-                            }
-                            _ => None,
-                        }
-                    })
-                    .collect()
-            } else {
-                vec![]
-            };
+        Self::new_from_summary_json(&j["membership-summary"][0])
+    }
 
-        Self {
-            organization: if membership_summary["organization"].is_object() {
-                Some(Organization::new_from_json(
-                    &membership_summary["organization"],
-                ))
-            } else {
-                None
-            },
-            department_name: membership_summary["department-name"]
-                .as_str()
-                .map(|s| s.to_string()),
-            role_title: membership_summary["role-title"]
-                .as_str()
-                .map(|s| s.to_string()),
-            start_date: if membership_summary["start-date"].is_object() {
-                Some(Date::new_from_json(&membership_summary["start-date"]))
-            } else {
-                None
-            },
-            end_date: if membership_summary["end-date"].is_object() {
-                Some(Date::new_from_json(&membership_summary["end-date"]))
-            } else {
-                None
-            },
-            external_ids,
-            url: membership_summary["url"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-        }
+    /// Parses a single `membership-summary` object
+    pub fn new_from_summary_json(membership_summary: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::AffiliationSummary>(
+            membership_summary,
+        ))
     }
 
     // Getter methods
+    pub fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+
     pub fn organization(&self) -> Option<&Organization> {
         self.organization.as_ref()
     }
@@ -81,21 +54,105 @@ impl Membership {
         self.role_title.as_ref()
     }
 
-    pub fn start_date(&self) -> Option<&Date> {
+    pub fn start_date(&self) -> Option<&FuzzyDate> {
         self.start_date.as_ref()
     }
 
-    pub fn end_date(&self) -> Option<&Date> {
+    pub fn end_date(&self) -> Option<&FuzzyDate> {
         self.end_date.as_ref()
     }
 
-    pub fn external_ids(&self) -> &Vec<(String, String)> {
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
         &self.external_ids
     }
 
     pub fn url(&self) -> Option<&String> {
         self.url.as_ref()
     }
+
+    // Setter methods
+    pub fn set_organization(&mut self, organization: Option<Organization>) {
+        self.organization = organization;
+    }
+
+    pub fn set_department_name(&mut self, department_name: Option<String>) {
+        self.department_name = department_name;
+    }
+
+    pub fn set_role_title(&mut self, role_title: Option<String>) {
+        self.role_title = role_title;
+    }
+
+    pub fn set_start_date(&mut self, start_date: Option<FuzzyDate>) {
+        self.start_date = start_date;
+    }
+
+    pub fn set_end_date(&mut self, end_date: Option<FuzzyDate>) {
+        self.end_date = end_date;
+    }
+
+    pub fn set_external_ids(&mut self, external_ids: Vec<ExternalId>) {
+        self.external_ids = external_ids;
+    }
+
+    pub fn set_url(&mut self, url: Option<String>) {
+        self.url = url;
+    }
+
+    /// Serializes to an ORCID v3.0 `membership` object
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = serde_json::json!({
+            "department-name": self.department_name,
+            "role-title": self.role_title,
+            "organization": self.organization.as_ref().map(|o| o.to_json()),
+        });
+        if let Some(ref start_date) = self.start_date {
+            j["start-date"] = start_date.to_json();
+        }
+        if let Some(ref end_date) = self.end_date {
+            j["end-date"] = end_date.to_json();
+        }
+        if !self.external_ids.is_empty() {
+            j["external-ids"] = external_ids_to_json(&self.external_ids);
+        }
+        if let Some(ref url) = self.url {
+            j["url"] = serde_json::json!({ "value": url });
+        }
+        j
+    }
+}
+
+impl Activity for Membership {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl GroupedActivity for Membership {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId> {
+        &mut self.external_ids
+    }
+}
+
+impl From<&record::AffiliationSummary> for Membership {
+    fn from(summary: &record::AffiliationSummary) -> Self {
+        Self {
+            metadata: ActivityMetadata::from(&summary.metadata),
+            organization: summary.organization.as_ref().map(Organization::from),
+            department_name: summary.department_name.clone(),
+            role_title: summary.role_title.clone(),
+            start_date: summary
+                .start_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            end_date: summary
+                .end_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            external_ids: external_id::from_record_ids(&summary.external_ids),
+            url: record::string_value(&summary.url),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -155,8 +212,11 @@ mod tests {
         assert!(membership.end_date().is_some());
         assert_eq!(membership.external_ids().len(), 1);
         assert_eq!(
-            membership.external_ids()[0],
-            ("membership-id".to_string(), "MEM-2020-12345".to_string())
+            (
+                membership.external_ids()[0].id_type().as_str(),
+                membership.external_ids()[0].value()
+            ),
+            ("membership-id", "MEM-2020-12345")
         );
         assert_eq!(
             membership.url(),
@@ -232,22 +292,32 @@ mod tests {
 
         assert_eq!(membership.external_ids().len(), 3);
         assert_eq!(
-            membership.external_ids()[0],
-            ("membership-id".to_string(), "MEM-123".to_string())
+            (
+                membership.external_ids()[0].id_type().as_str(),
+                membership.external_ids()[0].value()
+            ),
+            ("membership-id", "MEM-123")
         );
         assert_eq!(
-            membership.external_ids()[1],
-            ("legacy-id".to_string(), "OLD-456".to_string())
+            (
+                membership.external_ids()[1].id_type().as_str(),
+                membership.external_ids()[1].value()
+            ),
+            ("legacy-id", "OLD-456")
         );
         assert_eq!(
-            membership.external_ids()[2],
-            ("doi".to_string(), "10.1234/membership".to_string())
+            (
+                membership.external_ids()[2].id_type().as_str(),
+                membership.external_ids()[2].value()
+            ),
+            ("doi", "10.1234/membership")
         );
     }
 
     #[test]
     fn test_debug_trait() {
         let membership = Membership {
+            metadata: ActivityMetadata::default(),
             organization: None,
             department_name: Some("Test Dept".to_string()),
             role_title: Some("Member".to_string()),
@@ -266,12 +336,13 @@ mod tests {
     #[test]
     fn test_clone_trait() {
         let membership = Membership {
+            metadata: ActivityMetadata::default(),
             organization: None,
             department_name: Some("Engineering".to_string()),
             role_title: Some("Senior Member".to_string()),
             start_date: None,
             end_date: None,
-            external_ids: vec![("id".to_string(), "123".to_string())],
+            external_ids: vec![ExternalId::new("id".into(), "123")],
             url: Some("https://example.com".to_string()),
         };
 
@@ -281,4 +352,64 @@ mod tests {
         assert_eq!(cloned.external_ids(), membership.external_ids());
         assert_eq!(cloned.url(), membership.url());
     }
+
+    #[test]
+    fn test_to_json() {
+        let mut membership = Membership::new();
+        membership.set_organization(Some(Organization::new("Test Org")));
+        membership.set_department_name(Some("Physics".to_string()));
+        membership.set_role_title(Some("Fellow".to_string()));
+        membership.set_end_date(Some(FuzzyDate::new(2025, Some(5), Some(31)).unwrap()));
+        membership.set_external_ids(vec![ExternalId::new("membership-id".into(), "ID-1")]);
+        membership.set_url(Some("https://example.org/1".to_string()));
+
+        let j = membership.to_json();
+        assert_eq!(j["organization"]["name"], "Test Org");
+        assert_eq!(j["department-name"], "Physics");
+        assert_eq!(j["role-title"], "Fellow");
+        assert!(j.get("start-date").is_none());
+        assert_eq!(j["end-date"]["day"]["value"], "31");
+        assert_eq!(
+            j["external-ids"]["external-id"][0]["external-id-value"],
+            "ID-1"
+        );
+        assert_eq!(j["url"]["value"], "https://example.org/1");
+
+        // What we send can be read back by the summary parser
+        let parsed = Membership::new_from_json(&json!({ "membership-summary": [j] }));
+        assert_eq!(parsed.role_title(), Some(&"Fellow".to_string()));
+        assert_eq!(parsed.url(), Some(&"https://example.org/1".to_string()));
+    }
+
+    #[test]
+    fn test_new_from_json_metadata() {
+        let j = json!({
+            "membership-summary": [{
+                "put-code": 4242,
+                "visibility": "limited",
+                "last-modified-date": { "value": 1700000000000u64 },
+                "source": { "source-name": { "value": "Jane Doe" } }
+            }]
+        });
+        let metadata = Membership::new_from_json(&j).metadata().clone();
+        assert_eq!(metadata.put_code(), Some(4242));
+        assert_eq!(metadata.visibility(), Some(&"limited".to_string()));
+        assert!(metadata.last_modified_date().is_some());
+        assert_eq!(
+            metadata.source().and_then(|s| s.name()),
+            Some(&"Jane Doe".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut membership = Membership::new();
+        membership.set_role_title(Some("Fellow".to_string()));
+        membership.set_organization(Some(Organization::new("ACM")));
+        let serialized = serde_json::to_value(&membership).unwrap();
+        assert_eq!(serialized["role_title"], "Fellow");
+        let back: Membership = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, membership);
+    }
 }
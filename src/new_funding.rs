@@ -0,0 +1,234 @@
+use crate::contributor::Contributor;
+use crate::external_id::{ExternalId, ExternalIdRelationship, ExternalIdType};
+use crate::fuzzy_date::FuzzyDate;
+use crate::organization::Organization;
+use crate::utils::external_ids_to_json;
+use serde_json::json;
+
+/// A funding to add to, or update on, an ORCID record via the member API
+///
+/// # Example
+///
+/// ```
+/// use orcid::{FuzzyDate, NewFunding, Organization};
+///
+/// let funding = NewFunding::new("Climate grant", "grant", Organization::new("NSF"))
+///     .with_amount("100000", "USD")
+///     .with_start_date(FuzzyDate::new(2023, Some(1), None).unwrap())
+///     .with_grant_number("NSF-2023-12345");
+/// assert_eq!(funding.to_json()["amount"]["currency-code"], "USD");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NewFunding {
+    title: String,
+    funding_type: String,
+    organization: Organization,
+    amount: Option<(String, String)>, // (amount, currency_code)
+    start_date: Option<FuzzyDate>,
+    end_date: Option<FuzzyDate>,
+    external_ids: Vec<ExternalId>,
+    contributors: Vec<Contributor>,
+    short_description: Option<String>,
+    url: Option<String>,
+}
+
+impl NewFunding {
+    /// `funding_type` is an ORCID funding type, e.g. `grant` or `award`;
+    /// `organization` is the funder
+    pub fn new(title: &str, funding_type: &str, organization: Organization) -> Self {
+        Self {
+            title: title.to_string(),
+            funding_type: funding_type.to_string(),
+            organization,
+            amount: None,
+            start_date: None,
+            end_date: None,
+            external_ids: vec![],
+            contributors: vec![],
+            short_description: None,
+            url: None,
+        }
+    }
+
+    /// Set the amount, e.g. `("100000", "USD")`
+    pub fn with_amount(mut self, amount: &str, currency_code: &str) -> Self {
+        self.amount = Some((amount.to_string(), currency_code.to_string()));
+        self
+    }
+
+    pub fn with_start_date(mut self, start_date: FuzzyDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn with_end_date(mut self, end_date: FuzzyDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Add a `grant_number` external ID
+    pub fn with_grant_number(self, grant_number: &str) -> Self {
+        self.with_external_id("grant_number", grant_number)
+    }
+
+    /// Add an external ID with relationship `self`
+    pub fn with_external_id(mut self, id_type: impl Into<ExternalIdType>, id_value: &str) -> Self {
+        self.external_ids.push(
+            ExternalId::new(id_type.into(), id_value)
+                .with_relationship(ExternalIdRelationship::Self_),
+        );
+        self
+    }
+
+    /// Add a contributor; the role is e.g. `lead`, `co-lead` or `supported-by`
+    pub fn with_contributor(mut self, contributor: Contributor) -> Self {
+        self.contributors.push(contributor);
+        self
+    }
+
+    pub fn with_short_description(mut self, short_description: &str) -> Self {
+        self.short_description = Some(short_description.to_string());
+        self
+    }
+
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    // Getter methods
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn funding_type(&self) -> &str {
+        &self.funding_type
+    }
+
+    pub fn organization(&self) -> &Organization {
+        &self.organization
+    }
+
+    pub fn amount(&self) -> Option<&(String, String)> {
+        self.amount.as_ref()
+    }
+
+    pub fn start_date(&self) -> Option<&FuzzyDate> {
+        self.start_date.as_ref()
+    }
+
+    pub fn end_date(&self) -> Option<&FuzzyDate> {
+        self.end_date.as_ref()
+    }
+
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
+        &self.external_ids
+    }
+
+    pub fn contributors(&self) -> &Vec<Contributor> {
+        &self.contributors
+    }
+
+    /// Serializes to an ORCID v3.0 `funding` object
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = json!({
+            "title": { "title": { "value": self.title } },
+            "type": self.funding_type,
+            "organization": self.organization.to_json(),
+            "external-ids": external_ids_to_json(&self.external_ids),
+        });
+        if let Some((ref amount, ref currency_code)) = self.amount {
+            j["amount"] = json!({ "value": amount, "currency-code": currency_code });
+        }
+        if let Some(ref start_date) = self.start_date {
+            j["start-date"] = start_date.to_json();
+        }
+        if let Some(ref end_date) = self.end_date {
+            j["end-date"] = end_date.to_json();
+        }
+        if !self.contributors.is_empty() {
+            j["contributors"] = json!({
+                "contributor": self.contributors.iter().map(|c| c.to_json()).collect::<Vec<serde_json::Value>>()
+            });
+        }
+        if let Some(ref short_description) = self.short_description {
+            j["short-description"] = json!(short_description);
+        }
+        if let Some(ref url) = self.url {
+            j["url"] = json!({ "value": url });
+        }
+        j
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_minimal() {
+        let funding = NewFunding::new("Basic Grant", "grant", Organization::new("NSF"));
+        let j = funding.to_json();
+        assert_eq!(j["title"]["title"]["value"], "Basic Grant");
+        assert_eq!(j["type"], "grant");
+        assert_eq!(j["organization"]["name"], "NSF");
+        assert_eq!(j["external-ids"], json!({ "external-id": [] }));
+        assert!(j.get("amount").is_none());
+        assert!(j.get("contributors").is_none());
+    }
+
+    #[test]
+    fn test_to_json_complete() {
+        let mut lead = Contributor::new();
+        lead.set_orcid(Some("0000-0002-1825-0097".to_string()));
+        lead.set_role(Some("lead".to_string()));
+        let funding = NewFunding::new("Climate Grant", "grant", Organization::new("NSF"))
+            .with_amount("100000", "USD")
+            .with_start_date(FuzzyDate::new(2023, Some(1), Some(15)).unwrap())
+            .with_end_date(FuzzyDate::new(2025, None, None).unwrap())
+            .with_grant_number("NSF-2023-12345")
+            .with_contributor(lead)
+            .with_short_description("Studying the climate")
+            .with_url("https://example.com/grant");
+
+        let j = funding.to_json();
+        assert_eq!(
+            j["amount"],
+            json!({ "value": "100000", "currency-code": "USD" })
+        );
+        assert_eq!(j["start-date"]["day"]["value"], "15");
+        assert_eq!(j["end-date"]["year"]["value"], "2025");
+        assert_eq!(
+            j["external-ids"]["external-id"][0]["external-id-type"],
+            "grant_number"
+        );
+        assert_eq!(
+            j["contributors"]["contributor"][0]["contributor-attributes"],
+            json!({ "contributor-role": "lead" })
+        );
+        assert_eq!(j["short-description"], "Studying the climate");
+        assert_eq!(j["url"]["value"], "https://example.com/grant");
+    }
+
+    #[test]
+    fn test_to_json_parses_back() {
+        let funding = NewFunding::new("Round trip", "award", Organization::new("ERC"))
+            .with_amount("5000", "EUR")
+            .with_grant_number("ERC-1");
+        let parsed =
+            crate::Funding::new_from_json(&json!({ "funding-summary": [funding.to_json()] }));
+        assert_eq!(parsed.title(), Some(&"Round trip".to_string()));
+        assert_eq!(parsed.funding_type(), Some(&"award".to_string()));
+        assert_eq!(parsed.amount(), Some(&"5000".to_string()));
+        assert_eq!(parsed.currency(), Some(&"EUR".to_string()));
+        assert_eq!(
+            parsed
+                .external_ids()
+                .iter()
+                .map(|id| (id.id_type().as_str(), id.value()))
+                .collect::<Vec<_>>(),
+            vec![("grant_number", "ERC-1")]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::external_id::{ExternalId, ExternalIdRelationship, ExternalIdType};
+use crate::fuzzy_date::FuzzyDate;
+use crate::organization::Organization;
+use crate::utils::external_ids_to_json;
+use serde_json::json;
+
+/// A peer review to add to, or update on, an ORCID record via the member API
+///
+/// # Example
+///
+/// ```
+/// use orcid::{FuzzyDate, NewPeerReview, Organization};
+///
+/// let review = NewPeerReview::new("reviewer", "review", "issn:0028-0836", Organization::new("Nature"))
+///     .with_completion_date(FuzzyDate::new(2023, Some(3), Some(15)).unwrap())
+///     .with_review_identifier("source-work-id", "R-12345")
+///     .with_subject_external_identifier("doi", "10.1038/example");
+/// assert_eq!(review.to_json()["reviewer-role"], "reviewer");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NewPeerReview {
+    reviewer_role: String,
+    review_type: String,
+    review_group_id: String,
+    convening_organization: Organization,
+    completion_date: Option<FuzzyDate>,
+    review_identifiers: Vec<ExternalId>,
+    review_url: Option<String>,
+    subject_external_identifier: Option<ExternalId>,
+    subject_type: Option<String>,
+    subject_name: Option<String>,
+    subject_url: Option<String>,
+}
+
+impl NewPeerReview {
+    /// `reviewer_role` is e.g. `reviewer` or `editor`, `review_type` is `review` or
+    /// `evaluation`, and `review_group_id` identifies the group, e.g. `issn:0028-0836`
+    pub fn new(
+        reviewer_role: &str,
+        review_type: &str,
+        review_group_id: &str,
+        convening_organization: Organization,
+    ) -> Self {
+        Self {
+            reviewer_role: reviewer_role.to_string(),
+            review_type: review_type.to_string(),
+            review_group_id: review_group_id.to_string(),
+            convening_organization,
+            completion_date: None,
+            review_identifiers: vec![],
+            review_url: None,
+            subject_external_identifier: None,
+            subject_type: None,
+            subject_name: None,
+            subject_url: None,
+        }
+    }
+
+    pub fn with_completion_date(mut self, completion_date: FuzzyDate) -> Self {
+        self.completion_date = Some(completion_date);
+        self
+    }
+
+    /// Add an identifier of the review itself, e.g. `("source-work-id", "R-12345")`
+    pub fn with_review_identifier(
+        mut self,
+        id_type: impl Into<ExternalIdType>,
+        id_value: &str,
+    ) -> Self {
+        self.review_identifiers.push(
+            ExternalId::new(id_type.into(), id_value)
+                .with_relationship(ExternalIdRelationship::Self_),
+        );
+        self
+    }
+
+    pub fn with_review_url(mut self, review_url: &str) -> Self {
+        self.review_url = Some(review_url.to_string());
+        self
+    }
+
+    /// Set the identifier of the reviewed item, e.g. `("doi", "10.1038/example")`
+    pub fn with_subject_external_identifier(
+        mut self,
+        id_type: impl Into<ExternalIdType>,
+        id_value: &str,
+    ) -> Self {
+        self.subject_external_identifier = Some(
+            ExternalId::new(id_type.into(), id_value)
+                .with_relationship(ExternalIdRelationship::Self_),
+        );
+        self
+    }
+
+    /// Set the work type of the reviewed item, e.g. `journal-article`
+    pub fn with_subject_type(mut self, subject_type: &str) -> Self {
+        self.subject_type = Some(subject_type.to_string());
+        self
+    }
+
+    pub fn with_subject_name(mut self, subject_name: &str) -> Self {
+        self.subject_name = Some(subject_name.to_string());
+        self
+    }
+
+    pub fn with_subject_url(mut self, subject_url: &str) -> Self {
+        self.subject_url = Some(subject_url.to_string());
+        self
+    }
+
+    // Getter methods
+    pub fn reviewer_role(&self) -> &str {
+        &self.reviewer_role
+    }
+
+    pub fn review_type(&self) -> &str {
+        &self.review_type
+    }
+
+    pub fn review_group_id(&self) -> &str {
+        &self.review_group_id
+    }
+
+    pub fn convening_organization(&self) -> &Organization {
+        &self.convening_organization
+    }
+
+    pub fn subject_external_identifier(&self) -> Option<&ExternalId> {
+        self.subject_external_identifier.as_ref()
+    }
+
+    /// Serializes to an ORCID v3.0 `peer-review` object
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = json!({
+            "reviewer-role": self.reviewer_role,
+            "review-type": self.review_type,
+            "review-group-id": self.review_group_id,
+            "convening-organization": self.convening_organization.to_json(),
+            "review-identifiers": external_ids_to_json(&self.review_identifiers),
+        });
+        if let Some(ref completion_date) = self.completion_date {
+            j["review-completion-date"] = completion_date.to_json();
+        }
+        if let Some(ref review_url) = self.review_url {
+            j["review-url"] = json!({ "value": review_url });
+        }
+        if let Some(ref subject_external_identifier) = self.subject_external_identifier {
+            j["subject-external-identifier"] = subject_external_identifier.to_json();
+        }
+        if let Some(ref subject_type) = self.subject_type {
+            j["subject-type"] = json!(subject_type);
+        }
+        if let Some(ref subject_name) = self.subject_name {
+            j["subject-name"] = json!({ "title": { "value": subject_name } });
+        }
+        if let Some(ref subject_url) = self.subject_url {
+            j["subject-url"] = json!({ "value": subject_url });
+        }
+        j
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_minimal() {
+        let review = NewPeerReview::new(
+            "reviewer",
+            "review",
+            "issn:0028-0836",
+            Organization::new("Nature"),
+        );
+        assert_eq!(
+            review.to_json(),
+            json!({
+                "reviewer-role": "reviewer",
+                "review-type": "review",
+                "review-group-id": "issn:0028-0836",
+                "convening-organization": {
+                    "name": "Nature",
+                    "address": { "city": null, "region": null, "country": null }
+                },
+                "review-identifiers": { "external-id": [] }
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_json_complete() {
+        let review = NewPeerReview::new(
+            "editor",
+            "evaluation",
+            "issn:1234-5678",
+            Organization::new("Journal"),
+        )
+        .with_completion_date(FuzzyDate::new(2023, Some(3), Some(15)).unwrap())
+        .with_review_identifier("source-work-id", "R-1")
+        .with_review_url("https://example.com/review/1")
+        .with_subject_external_identifier("doi", "10.1234/subject")
+        .with_subject_type("journal-article")
+        .with_subject_name("The reviewed paper")
+        .with_subject_url("https://example.com/paper");
+
+        let j = review.to_json();
+        assert_eq!(j["review-completion-date"]["month"]["value"], "03");
+        assert_eq!(
+            j["review-identifiers"]["external-id"][0]["external-id-value"],
+            "R-1"
+        );
+        assert_eq!(j["review-url"]["value"], "https://example.com/review/1");
+        assert_eq!(
+            j["subject-external-identifier"],
+            json!({
+                "external-id-type": "doi",
+                "external-id-value": "10.1234/subject",
+                "external-id-relationship": "self"
+            })
+        );
+        assert_eq!(j["subject-type"], "journal-article");
+        assert_eq!(j["subject-name"]["title"]["value"], "The reviewed paper");
+        assert_eq!(j["subject-url"]["value"], "https://example.com/paper");
+    }
+}
@@ -0,0 +1,202 @@
+use crate::contributor::Contributor;
+use crate::external_id::{ExternalId, ExternalIdRelationship, ExternalIdType};
+use crate::fuzzy_date::FuzzyDate;
+use crate::utils::external_ids_to_json;
+use serde_json::json;
+
+/// A work to add to, or update on, an ORCID record via the member API
+///
+/// # Example
+///
+/// ```
+/// use orcid::{FuzzyDate, NewWork};
+///
+/// let work = NewWork::new("A study of things", "journal-article")
+///     .with_external_id("doi", "10.1234/things")
+///     .with_journal_title("Journal of Things")
+///     .with_publication_date(FuzzyDate::new(2023, Some(6), None).unwrap());
+/// assert_eq!(work.to_json()["type"], "journal-article");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NewWork {
+    title: String,
+    work_type: String,
+    external_ids: Vec<ExternalId>,
+    contributors: Vec<Contributor>,
+    publication_date: Option<FuzzyDate>,
+    journal_title: Option<String>,
+    url: Option<String>,
+}
+
+impl NewWork {
+    /// `work_type` is an ORCID work type, e.g. `journal-article` or `book`
+    pub fn new(title: &str, work_type: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            work_type: work_type.to_string(),
+            external_ids: vec![],
+            contributors: vec![],
+            publication_date: None,
+            journal_title: None,
+            url: None,
+        }
+    }
+
+    /// Add an external ID, e.g. `("doi", "10.1234/test")`, with relationship `self`
+    pub fn with_external_id(mut self, id_type: impl Into<ExternalIdType>, id_value: &str) -> Self {
+        self.external_ids.push(
+            ExternalId::new(id_type.into(), id_value)
+                .with_relationship(ExternalIdRelationship::Self_),
+        );
+        self
+    }
+
+    pub fn with_contributor(mut self, contributor: Contributor) -> Self {
+        self.contributors.push(contributor);
+        self
+    }
+
+    pub fn with_publication_date(mut self, publication_date: FuzzyDate) -> Self {
+        self.publication_date = Some(publication_date);
+        self
+    }
+
+    pub fn with_journal_title(mut self, journal_title: &str) -> Self {
+        self.journal_title = Some(journal_title.to_string());
+        self
+    }
+
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    // Getter methods
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn work_type(&self) -> &str {
+        &self.work_type
+    }
+
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
+        &self.external_ids
+    }
+
+    pub fn contributors(&self) -> &Vec<Contributor> {
+        &self.contributors
+    }
+
+    pub fn publication_date(&self) -> Option<&FuzzyDate> {
+        self.publication_date.as_ref()
+    }
+
+    pub fn journal_title(&self) -> Option<&String> {
+        self.journal_title.as_ref()
+    }
+
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+
+    /// Serializes to an ORCID v3.0 `work` object
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = json!({
+            "title": { "title": { "value": self.title } },
+            "type": self.work_type,
+            "external-ids": external_ids_to_json(&self.external_ids),
+        });
+        if !self.contributors.is_empty() {
+            j["contributors"] = json!({
+                "contributor": self.contributors.iter().map(|c| c.to_json()).collect::<Vec<serde_json::Value>>()
+            });
+        }
+        if let Some(ref publication_date) = self.publication_date {
+            j["publication-date"] = publication_date.to_json();
+        }
+        if let Some(ref journal_title) = self.journal_title {
+            j["journal-title"] = json!({ "value": journal_title });
+        }
+        if let Some(ref url) = self.url {
+            j["url"] = json!({ "value": url });
+        }
+        j
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_minimal() {
+        let work = NewWork::new("Test Publication", "book");
+        assert_eq!(
+            work.to_json(),
+            json!({
+                "title": { "title": { "value": "Test Publication" } },
+                "type": "book",
+                "external-ids": { "external-id": [] }
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_json_complete() {
+        let mut contributor = Contributor::new();
+        contributor.set_name(Some("Jane Doe".to_string()));
+        contributor.set_sequence(Some("first".to_string()));
+        let work = NewWork::new("Test Publication", "journal-article")
+            .with_external_id("doi", "10.1234/test")
+            .with_external_id("pmid", "12345678")
+            .with_contributor(contributor)
+            .with_publication_date(FuzzyDate::new(2023, Some(6), Some(15)).unwrap())
+            .with_journal_title("Journal of Tests")
+            .with_url("https://example.com/paper");
+
+        let j = work.to_json();
+        assert_eq!(j["title"]["title"]["value"], "Test Publication");
+        assert_eq!(j["type"], "journal-article");
+        assert_eq!(
+            j["external-ids"]["external-id"][0],
+            json!({
+                "external-id-type": "doi",
+                "external-id-value": "10.1234/test",
+                "external-id-relationship": "self"
+            })
+        );
+        assert_eq!(
+            j["external-ids"]["external-id"][1]["external-id-value"],
+            "12345678"
+        );
+        assert_eq!(
+            j["contributors"]["contributor"][0]["credit-name"]["value"],
+            "Jane Doe"
+        );
+        assert_eq!(j["publication-date"]["month"]["value"], "06");
+        assert_eq!(j["journal-title"]["value"], "Journal of Tests");
+        assert_eq!(j["url"]["value"], "https://example.com/paper");
+    }
+
+    #[test]
+    fn test_to_json_parses_back() {
+        // The summary parser should understand what we send
+        let work = NewWork::new("Round trip", "book").with_external_id("isbn", "9780000000002");
+        let parsed = crate::Work::new_from_json(&json!({
+            "work-summary": [work.to_json()],
+            "external-ids": work.to_json()["external-ids"]
+        }));
+        assert_eq!(parsed.title, Some("Round trip".to_string()));
+        assert_eq!(parsed.pub_type, Some("book".to_string()));
+        assert_eq!(
+            parsed
+                .external_ids
+                .iter()
+                .map(|id| (id.id_type().as_str(), id.value()))
+                .collect::<Vec<_>>(),
+            vec![("isbn", "9780000000002")]
+        );
+    }
+}
@@ -0,0 +1,744 @@
+use crate::client_builder::Environment;
+use crate::error::{OrcidError, Result};
+use crate::transport::{BlockingTransport, BoxFuture, Method, Request, Response, Transport};
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Scope for reading public data with higher rate limits
+pub const SCOPE_READ_PUBLIC: &str = "/read-public";
+
+/// Scope for reading limited-visibility data of a record
+pub const SCOPE_READ_LIMITED: &str = "/read-limited";
+
+/// Scope for adding, updating and deleting activities of a record
+pub const SCOPE_ACTIVITIES_UPDATE: &str = "/activities/update";
+
+/// Scope for adding, updating and deleting biographical data of a record
+pub const SCOPE_PERSON_UPDATE: &str = "/person/update";
+
+/// Scope for authenticating a user, i.e. just getting their ORCID iD
+pub const SCOPE_AUTHENTICATE: &str = "/authenticate";
+
+/// Tokens are refreshed this long before they actually expire
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// Supplies the bearer token a client attaches to its requests
+pub trait TokenProvider: fmt::Debug + Send + Sync {
+    /// Returns a valid access token, fetching a new one via `transport` if needed
+    fn access_token<'a>(&'a self, transport: &'a dyn Transport) -> BoxFuture<'a, Result<String>>;
+
+    /// Blocking twin of `access_token`
+    fn access_token_blocking(&self, transport: &dyn BlockingTransport) -> Result<String>;
+
+    /// Drops any cached token, e.g. after the API rejected it. Returns true if
+    /// `access_token` can then fetch a new one, so the request is worth retrying.
+    fn invalidate(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    access_token: String,
+    expires_at: Option<Instant>,
+}
+
+impl CachedToken {
+    fn is_fresh(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => Instant::now() + EXPIRY_MARGIN < expires_at,
+            None => true,
+        }
+    }
+}
+
+/// Obtains `/read-public` tokens via the OAuth2 client-credentials grant,
+/// caches them until they expire and fetches a new one transparently.
+///
+/// # Example
+///
+/// ```
+/// use orcid::ClientBuilder;
+///
+/// let client = ClientBuilder::new()
+///     .client_credentials("APP-XXXXXXXXXXXXXXXX", "secret")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct ClientCredentials {
+    client_id: String,
+    client_secret: String,
+    token_url: String,
+    scope: String,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+impl ClientCredentials {
+    /// Uses the production token endpoint and the `/read-public` scope
+    pub fn new(client_id: &str, client_secret: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            token_url: "https://orcid.org/oauth/token".to_string(),
+            scope: SCOPE_READ_PUBLIC.to_string(),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Set the token endpoint, e.g. the sandbox or a local stand-in
+    pub fn with_token_url(mut self, token_url: &str) -> Self {
+        self.token_url = token_url.to_string();
+        self
+    }
+
+    /// Set the requested scope (default: `/read-public`)
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = scope.to_string();
+        self
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn token_url(&self) -> &str {
+        &self.token_url
+    }
+
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    fn token_request(&self) -> Request {
+        form_request(
+            &self.token_url,
+            &[
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("grant_type", "client_credentials"),
+                ("scope", &self.scope),
+            ],
+        )
+    }
+
+    fn cached_token(&self) -> Option<String> {
+        self.cached
+            .lock()
+            .ok()?
+            .as_ref()
+            .filter(|token| token.is_fresh())
+            .map(|token| token.access_token.to_owned())
+    }
+
+    fn store(&self, response: Response) -> Result<String> {
+        let json = token_json(response)?;
+        let access_token = json["access_token"]
+            .as_str()
+            .ok_or_else(|| OrcidError::BadApiResponse(json.clone()))?
+            .to_string();
+        // An expiry too far in the future to represent is treated as none
+        let expires_at = json["expires_in"]
+            .as_u64()
+            .and_then(|secs| Instant::now().checked_add(Duration::from_secs(secs)));
+        if let Ok(mut cached) = self.cached.lock() {
+            *cached = Some(CachedToken {
+                access_token: access_token.to_owned(),
+                expires_at,
+            });
+        }
+        Ok(access_token)
+    }
+}
+
+impl fmt::Debug for ClientCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Keep the secret and the token out of logs
+        f.debug_struct("ClientCredentials")
+            .field("client_id", &self.client_id)
+            .field("token_url", &self.token_url)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+impl TokenProvider for ClientCredentials {
+    fn access_token<'a>(&'a self, transport: &'a dyn Transport) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            if let Some(token) = self.cached_token() {
+                return Ok(token);
+            }
+            let response = transport.execute(self.token_request()).await?;
+            self.store(response)
+        })
+    }
+
+    fn access_token_blocking(&self, transport: &dyn BlockingTransport) -> Result<String> {
+        if let Some(token) = self.cached_token() {
+            return Ok(token);
+        }
+        let response = transport.execute(self.token_request())?;
+        self.store(response)
+    }
+
+    fn invalidate(&self) -> bool {
+        if let Ok(mut cached) = self.cached.lock() {
+            *cached = None;
+        }
+        true
+    }
+}
+
+/// A token issued for a specific ORCID record via the authorization-code flow
+#[derive(Clone, PartialEq, Eq)]
+pub struct UserToken {
+    access_token: String,
+    refresh_token: Option<String>,
+    token_type: Option<String>,
+    scopes: Vec<String>,
+    expires_at: Option<SystemTime>,
+    orcid: String,
+    name: Option<String>,
+}
+
+impl UserToken {
+    /// Creates a token from previously stored values
+    pub fn new(access_token: &str, orcid: &str) -> Self {
+        Self {
+            access_token: access_token.to_string(),
+            refresh_token: None,
+            token_type: None,
+            scopes: vec![],
+            expires_at: None,
+            orcid: orcid.to_string(),
+            name: None,
+        }
+    }
+
+    /// Parses the JSON returned by the token endpoint
+    pub fn new_from_json(j: &serde_json::Value) -> Result<Self> {
+        let (access_token, orcid) = match (j["access_token"].as_str(), j["orcid"].as_str()) {
+            (Some(access_token), Some(orcid)) => (access_token, orcid),
+            _ => return Err(OrcidError::BadApiResponse(j.clone())),
+        };
+        Ok(Self {
+            access_token: access_token.to_string(),
+            refresh_token: j["refresh_token"].as_str().map(|s| s.to_string()),
+            token_type: j["token_type"].as_str().map(|s| s.to_string()),
+            scopes: j["scope"]
+                .as_str()
+                .map(|s| s.split_whitespace().map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
+            // An expiry too far in the future to represent is treated as none
+            expires_at: j["expires_in"]
+                .as_u64()
+                .and_then(|secs| SystemTime::now().checked_add(Duration::from_secs(secs))),
+            orcid: orcid.to_string(),
+            name: j["name"].as_str().map(|s| s.to_string()),
+        })
+    }
+
+    pub fn with_refresh_token(mut self, refresh_token: &str) -> Self {
+        self.refresh_token = Some(refresh_token.to_string());
+        self
+    }
+
+    pub fn with_scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = scopes.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn with_expires_at(mut self, expires_at: SystemTime) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.refresh_token.as_deref()
+    }
+
+    pub fn token_type(&self) -> Option<&str> {
+        self.token_type.as_deref()
+    }
+
+    pub fn scopes(&self) -> &Vec<String> {
+        &self.scopes
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .map(|expires_at| expires_at <= SystemTime::now())
+            .unwrap_or(false)
+    }
+
+    /// The ORCID iD of the record the token was issued for
+    pub fn orcid(&self) -> &str {
+        &self.orcid
+    }
+
+    /// The name of the user, as returned by the token endpoint
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl fmt::Debug for UserToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Keep the tokens out of logs
+        f.debug_struct("UserToken")
+            .field("orcid", &self.orcid)
+            .field("name", &self.name)
+            .field("scopes", &self.scopes)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+impl TokenProvider for UserToken {
+    fn access_token<'a>(&'a self, _transport: &'a dyn Transport) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move { Ok(self.access_token.to_owned()) })
+    }
+
+    fn access_token_blocking(&self, _transport: &dyn BlockingTransport) -> Result<String> {
+        Ok(self.access_token.to_owned())
+    }
+}
+
+/// Three-legged OAuth: asks a user for permission to access their record,
+/// then exchanges the returned code for a `UserToken`.
+///
+/// # Example
+///
+/// ```
+/// use orcid::oauth::{AuthorizationCode, SCOPE_ACTIVITIES_UPDATE, SCOPE_READ_LIMITED};
+///
+/// let oauth = AuthorizationCode::new("APP-123", "secret", "https://example.org/callback");
+/// let url = oauth.authorize_url(&[SCOPE_READ_LIMITED, SCOPE_ACTIVITIES_UPDATE], Some("xyz"));
+/// assert!(url.starts_with("https://orcid.org/oauth/authorize?client_id=APP-123"));
+/// // Send the user to `url`; ORCID redirects back with `?code=...&state=xyz`.
+/// // Then: `let token = oauth.exchange_code(client.transport(), &code).await?;`
+/// ```
+#[derive(Clone)]
+pub struct AuthorizationCode {
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    oauth_base_url: String,
+}
+
+impl AuthorizationCode {
+    /// Uses the production OAuth endpoints
+    pub fn new(client_id: &str, client_secret: &str, redirect_uri: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            oauth_base_url: Environment::Production.oauth_base_url(),
+        }
+    }
+
+    /// Use the OAuth endpoints of the given environment
+    pub fn with_environment(mut self, environment: &Environment) -> Self {
+        self.oauth_base_url = environment.oauth_base_url();
+        self
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// The URL to send the user to for granting the given scopes
+    pub fn authorize_url(&self, scopes: &[&str], state: Option<&str>) -> String {
+        let mut url = format!(
+            "{}oauth/authorize?client_id={}&response_type=code&scope={}&redirect_uri={}",
+            self.oauth_base_url,
+            urlencoding::encode(&self.client_id),
+            urlencoding::encode(&scopes.join(" ")),
+            urlencoding::encode(&self.redirect_uri),
+        );
+        if let Some(state) = state {
+            url += &format!("&state={}", urlencoding::encode(state));
+        }
+        url
+    }
+
+    fn code_request(&self, code: &str) -> Request {
+        form_request(
+            &self.token_url(),
+            &[
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", &self.redirect_uri),
+            ],
+        )
+    }
+
+    fn refresh_request(&self, token: &UserToken) -> Result<Request> {
+        let refresh_token = token
+            .refresh_token()
+            .ok_or_else(|| OrcidError::AuthError("token has no refresh_token".to_string()))?;
+        Ok(form_request(
+            &self.token_url(),
+            &[
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token),
+            ],
+        ))
+    }
+
+    fn revoke_request(&self, token: &UserToken) -> Request {
+        form_request(
+            &format!("{}oauth/revoke", self.oauth_base_url),
+            &[
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("token", token.access_token()),
+            ],
+        )
+    }
+
+    fn token_url(&self) -> String {
+        format!("{}oauth/token", self.oauth_base_url)
+    }
+
+    /// Exchanges the code from the redirect for a `UserToken`
+    pub async fn exchange_code(&self, transport: &dyn Transport, code: &str) -> Result<UserToken> {
+        let response = transport.execute(self.code_request(code)).await?;
+        UserToken::new_from_json(&token_json(response)?)
+    }
+
+    /// Blocking twin of `exchange_code`
+    pub fn exchange_code_blocking(
+        &self,
+        transport: &dyn BlockingTransport,
+        code: &str,
+    ) -> Result<UserToken> {
+        let response = transport.execute(self.code_request(code))?;
+        UserToken::new_from_json(&token_json(response)?)
+    }
+
+    /// Gets a new access token using the refresh token of `token`
+    pub async fn refresh(&self, transport: &dyn Transport, token: &UserToken) -> Result<UserToken> {
+        let response = transport.execute(self.refresh_request(token)?).await?;
+        UserToken::new_from_json(&token_json(response)?)
+    }
+
+    /// Blocking twin of `refresh`
+    pub fn refresh_blocking(
+        &self,
+        transport: &dyn BlockingTransport,
+        token: &UserToken,
+    ) -> Result<UserToken> {
+        let response = transport.execute(self.refresh_request(token)?)?;
+        UserToken::new_from_json(&token_json(response)?)
+    }
+
+    /// Revokes the access token of `token`
+    pub async fn revoke(&self, transport: &dyn Transport, token: &UserToken) -> Result<()> {
+        let response = transport.execute(self.revoke_request(token)).await?;
+        check_revoke_response(response)
+    }
+
+    /// Blocking twin of `revoke`
+    pub fn revoke_blocking(
+        &self,
+        transport: &dyn BlockingTransport,
+        token: &UserToken,
+    ) -> Result<()> {
+        let response = transport.execute(self.revoke_request(token))?;
+        check_revoke_response(response)
+    }
+}
+
+impl fmt::Debug for AuthorizationCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Keep the secret out of logs
+        f.debug_struct("AuthorizationCode")
+            .field("client_id", &self.client_id)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("oauth_base_url", &self.oauth_base_url)
+            .finish()
+    }
+}
+
+fn check_revoke_response(response: Response) -> Result<()> {
+    if response.is_success() {
+        return Ok(());
+    }
+    // Surfaces OAuth errors, falling back to the HTTP status
+    token_json(response.clone())?;
+    Err(OrcidError::HttpError {
+        status: response.status,
+        body: response.body,
+    })
+}
+
+/// Builds a form-encoded `POST` request as expected by the ORCID OAuth endpoints
+pub(crate) fn form_request(url: &str, params: &[(&str, &str)]) -> Request {
+    let body = params
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
+        .collect::<Vec<String>>()
+        .join("&");
+    Request::new(Method::Post, url)
+        .with_header("Accept", "application/json")
+        .with_header("Content-Type", "application/x-www-form-urlencoded")
+        .with_body(body)
+}
+
+/// Parses a token endpoint response, turning OAuth errors into `OrcidError::AuthError`
+pub(crate) fn token_json(response: Response) -> Result<serde_json::Value> {
+    let json = response.json()?;
+    if let Some(error) = json["error"].as_str() {
+        return Err(OrcidError::AuthError(format!(
+            "{}: {}",
+            error,
+            json["error_description"]
+                .as_str()
+                .unwrap_or("no error_description")
+        )));
+    }
+    if !response.is_success() {
+        return Err(OrcidError::HttpError {
+            status: response.status,
+            body: response.body,
+        });
+    }
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::FixtureTransport;
+
+    const TOKEN_URL: &str = "http://localhost:8080/oauth/token";
+
+    fn token_fixture(body: &str) -> FixtureTransport {
+        FixtureTransport::new().with_response(Method::Post, TOKEN_URL, Response::new(200, body))
+    }
+
+    #[test]
+    fn test_token_request() {
+        let credentials = ClientCredentials::new("APP-123", "s3cr/t").with_token_url(TOKEN_URL);
+        let request = credentials.token_request();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, TOKEN_URL);
+        assert_eq!(
+            request.header("content-type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(
+            request.body.as_deref(),
+            Some("client_id=APP-123&client_secret=s3cr%2Ft&grant_type=client_credentials&scope=%2Fread-public")
+        );
+    }
+
+    #[test]
+    fn test_token_is_cached() {
+        let fixture =
+            token_fixture(r#"{"access_token":"abc","token_type":"bearer","expires_in":631138518}"#);
+        let credentials = ClientCredentials::new("id", "secret").with_token_url(TOKEN_URL);
+        assert_eq!(credentials.access_token_blocking(&fixture).unwrap(), "abc");
+        assert_eq!(credentials.access_token_blocking(&fixture).unwrap(), "abc");
+        assert_eq!(fixture.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_huge_expiry() {
+        let fixture = token_fixture(&format!(
+            r#"{{"access_token":"abc","expires_in":{}}}"#,
+            u64::MAX
+        ));
+        let credentials = ClientCredentials::new("id", "secret").with_token_url(TOKEN_URL);
+        assert_eq!(credentials.access_token_blocking(&fixture).unwrap(), "abc");
+        assert_eq!(credentials.access_token_blocking(&fixture).unwrap(), "abc");
+        assert_eq!(fixture.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_expired_token_is_refreshed() {
+        let fixture = token_fixture(r#"{"access_token":"abc","expires_in":0}"#);
+        let credentials = ClientCredentials::new("id", "secret").with_token_url(TOKEN_URL);
+        credentials.access_token_blocking(&fixture).unwrap();
+        credentials.access_token_blocking(&fixture).unwrap();
+        assert_eq!(fixture.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let fixture = token_fixture(r#"{"access_token":"abc"}"#);
+        let credentials = ClientCredentials::new("id", "secret").with_token_url(TOKEN_URL);
+        credentials.access_token_blocking(&fixture).unwrap();
+        assert!(credentials.invalidate());
+        credentials.access_token_blocking(&fixture).unwrap();
+        assert_eq!(fixture.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_access_token_async() {
+        let fixture = token_fixture(r#"{"access_token":"xyz","expires_in":3600}"#);
+        let credentials = ClientCredentials::new("id", "secret").with_token_url(TOKEN_URL);
+        assert_eq!(credentials.access_token(&fixture).await.unwrap(), "xyz");
+        assert_eq!(credentials.access_token(&fixture).await.unwrap(), "xyz");
+        assert_eq!(fixture.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_auth_error() {
+        let fixture = FixtureTransport::new().with_response(
+            Method::Post,
+            TOKEN_URL,
+            Response::new(
+                401,
+                r#"{"error":"invalid_client","error_description":"Bad client credentials"}"#,
+            ),
+        );
+        let credentials = ClientCredentials::new("id", "wrong").with_token_url(TOKEN_URL);
+        match credentials.access_token_blocking(&fixture) {
+            Err(OrcidError::AuthError(message)) => {
+                assert_eq!(message, "invalid_client: Bad client credentials")
+            }
+            _ => panic!("Expected AuthError"),
+        }
+    }
+
+    const USER_TOKEN_JSON: &str = r#"{"access_token":"f5af9f51","token_type":"bearer","refresh_token":"f725f747","expires_in":631138518,"scope":"/read-limited /activities/update","name":"Sofia Garcia","orcid":"0000-0001-2345-6789"}"#;
+
+    fn authorization_code() -> AuthorizationCode {
+        AuthorizationCode::new("APP-123", "secret", "https://example.org/callback")
+            .with_environment(&Environment::Custom("http://localhost:8080".to_string()))
+    }
+
+    #[test]
+    fn test_authorize_url() {
+        let oauth = AuthorizationCode::new("APP-123", "secret", "https://example.org/cb?x=1")
+            .with_environment(&Environment::Sandbox);
+        assert_eq!(
+            oauth.authorize_url(&[SCOPE_READ_LIMITED, SCOPE_ACTIVITIES_UPDATE], Some("abc")),
+            "https://sandbox.orcid.org/oauth/authorize?client_id=APP-123&response_type=code\
+             &scope=%2Fread-limited%20%2Factivities%2Fupdate\
+             &redirect_uri=https%3A%2F%2Fexample.org%2Fcb%3Fx%3D1&state=abc"
+        );
+        assert!(!oauth
+            .authorize_url(&[SCOPE_AUTHENTICATE], None)
+            .contains("state="));
+    }
+
+    #[test]
+    fn test_exchange_code() {
+        let fixture = FixtureTransport::new().with_response(
+            Method::Post,
+            TOKEN_URL,
+            Response::new(200, USER_TOKEN_JSON),
+        );
+        let token = authorization_code()
+            .exchange_code_blocking(&fixture, "Q70Y3A")
+            .unwrap();
+        assert_eq!(token.access_token(), "f5af9f51");
+        assert_eq!(token.refresh_token(), Some("f725f747"));
+        assert_eq!(token.orcid(), "0000-0001-2345-6789");
+        assert_eq!(token.name(), Some("Sofia Garcia"));
+        assert!(token.has_scope(SCOPE_ACTIVITIES_UPDATE));
+        assert!(!token.has_scope(SCOPE_PERSON_UPDATE));
+        assert!(!token.is_expired());
+
+        let body = fixture.requests()[0].body.clone().unwrap();
+        assert!(body.contains("grant_type=authorization_code"));
+        assert!(body.contains("code=Q70Y3A"));
+        assert!(body.contains("redirect_uri=https%3A%2F%2Fexample.org%2Fcallback"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh() {
+        let fixture = FixtureTransport::new().with_response(
+            Method::Post,
+            TOKEN_URL,
+            Response::new(200, USER_TOKEN_JSON),
+        );
+        let oauth = authorization_code();
+        let old = UserToken::new("old", "0000-0001-2345-6789").with_refresh_token("f725f747");
+        let token = oauth.refresh(&fixture, &old).await.unwrap();
+        assert_eq!(token.access_token(), "f5af9f51");
+        let body = fixture.requests()[0].body.clone().unwrap();
+        assert!(body.contains("grant_type=refresh_token&refresh_token=f725f747"));
+
+        let no_refresh = UserToken::new("old", "0000-0001-2345-6789");
+        match oauth.refresh(&fixture, &no_refresh).await {
+            Err(OrcidError::AuthError(_)) => (),
+            _ => panic!("Expected AuthError"),
+        }
+    }
+
+    #[test]
+    fn test_revoke() {
+        let fixture = FixtureTransport::new().with_response(
+            Method::Post,
+            "http://localhost:8080/oauth/revoke",
+            Response::new(200, ""),
+        );
+        let oauth = authorization_code();
+        let token = UserToken::new("f5af9f51", "0000-0001-2345-6789");
+        oauth.revoke_blocking(&fixture, &token).unwrap();
+        assert_eq!(
+            fixture.requests()[0].body.as_deref(),
+            Some("client_id=APP-123&client_secret=secret&token=f5af9f51")
+        );
+
+        match oauth.revoke_blocking(&FixtureTransport::new(), &token) {
+            Err(OrcidError::HttpError { status, .. }) => assert_eq!(status, 404),
+            _ => panic!("Expected HttpError"),
+        }
+    }
+
+    #[test]
+    fn test_user_token_huge_expiry() {
+        let j = serde_json::json!({
+            "access_token": "abc",
+            "orcid": "0000-0001-2345-6789",
+            "expires_in": u64::MAX
+        });
+        let token = UserToken::new_from_json(&j).unwrap();
+        assert_eq!(token.expires_at(), None);
+        assert!(!token.is_expired());
+    }
+
+    #[test]
+    fn test_user_token_missing_orcid() {
+        let j = serde_json::json!({ "access_token": "abc" });
+        assert!(UserToken::new_from_json(&j).is_err());
+    }
+
+    #[test]
+    fn test_debug_hides_secret() {
+        let credentials = ClientCredentials::new("APP-123", "top-secret");
+        let debug_str = format!("{:?}", credentials);
+        assert!(debug_str.contains("APP-123"));
+        assert!(!debug_str.contains("top-secret"));
+
+        let token = UserToken::new("top-secret", "0000-0001-2345-6789");
+        let debug_str = format!("{:?}", token);
+        assert!(debug_str.contains("0000-0001-2345-6789"));
+        assert!(!debug_str.contains("top-secret"));
+    }
+}
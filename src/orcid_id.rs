@@ -0,0 +1,278 @@
+use crate::error::{OrcidError, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// URI prefixes stripped when parsing, lowercased
+const PREFIXES: [&str; 6] = [
+    "https://orcid.org/",
+    "http://orcid.org/",
+    "https://sandbox.orcid.org/",
+    "http://sandbox.orcid.org/",
+    "orcid.org/",
+    "sandbox.orcid.org/",
+];
+
+/// An ORCID iD in its canonical form, e.g. `0000-0002-1825-0097`.
+///
+/// Parsing accepts the bare form, the form without hyphens, and `https://orcid.org/`,
+/// `http://` and sandbox URIs, and checks the ISO 7064 11-2 check digit.
+/// A lowercase `x` check digit is rejected unless parsed with `parse_lenient`.
+///
+/// An `OrcidId` is always valid. Client methods take `impl AsRef<str>` rather than
+/// `impl Into<OrcidId>`, so strings and `OrcidId`s can be passed directly. The client
+/// parses them with `parse_lenient`, so a lowercase `x` still reaches the API as `X`,
+/// and rejects invalid ones with `OrcidError::InvalidOrcidId` before sending a request.
+///
+/// # Example
+///
+/// ```
+/// use orcid::OrcidId;
+///
+/// let id: OrcidId = "https://orcid.org/0000000218250097".parse().unwrap();
+/// assert_eq!(id.to_string(), "0000-0002-1825-0097");
+/// assert_eq!(id.uri(), "https://orcid.org/0000-0002-1825-0097");
+/// assert_eq!(OrcidId::check_digit("000000021694233").unwrap(), 'X');
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OrcidId {
+    id: String,
+}
+
+impl OrcidId {
+    /// Parses an ORCID iD, also accepting a lowercase `x` check digit
+    pub fn parse_lenient(s: &str) -> Result<Self> {
+        Self::parse(s, true)
+    }
+
+    fn parse(s: &str, lowercase_x: bool) -> Result<Self> {
+        let invalid = || OrcidError::InvalidOrcidId(s.to_string());
+        let trimmed = s.trim().trim_end_matches('/');
+        let lower = trimmed.to_ascii_lowercase();
+        let bare = PREFIXES
+            .iter()
+            .find(|prefix| lower.starts_with(*prefix))
+            .map_or(trimmed, |prefix| &trimmed[prefix.len()..]);
+        // Lengths below count bytes, and slicing needs char boundaries
+        if !bare.is_ascii() {
+            return Err(invalid());
+        }
+        let digits: String = match bare.len() {
+            16 => bare.to_string(),
+            19 if bare.char_indices().all(|(i, c)| (c == '-') == (i % 5 == 4)) => {
+                bare.replace('-', "")
+            }
+            _ => return Err(invalid()),
+        };
+        let (base, last) = digits.split_at(15);
+        let last = match last {
+            "x" if lowercase_x => 'X',
+            _ => last.chars().next().ok_or_else(invalid)?,
+        };
+        if Self::check_digit(base).map_err(|_| invalid())? != last {
+            return Err(invalid());
+        }
+        Ok(Self {
+            id: format!(
+                "{}-{}-{}-{}{}",
+                &base[0..4],
+                &base[4..8],
+                &base[8..12],
+                &base[12..15],
+                last
+            ),
+        })
+    }
+
+    /// The ISO 7064 11-2 check digit for the first 15 digits of an ORCID iD,
+    /// `0`-`9` or `X`
+    pub fn check_digit(base: &str) -> Result<char> {
+        let digits: Vec<u32> = base.chars().filter_map(|c| c.to_digit(10)).collect();
+        if digits.len() != 15 || base.len() != 15 {
+            return Err(OrcidError::InvalidOrcidId(base.to_string()));
+        }
+        let total = digits.iter().fold(0, |total, digit| (total + digit) * 2);
+        match (12 - total % 11) % 11 {
+            10 => Ok('X'),
+            digit => Ok(char::from_digit(digit, 10).unwrap_or('0')),
+        }
+    }
+
+    /// The canonical form, e.g. `0000-0002-1825-0097`
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+
+    /// The URI on the production ORCID site, e.g. `https://orcid.org/0000-0002-1825-0097`
+    pub fn uri(&self) -> String {
+        format!("https://orcid.org/{}", self.id)
+    }
+}
+
+impl fmt::Display for OrcidId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.id)
+    }
+}
+
+impl FromStr for OrcidId {
+    type Err = OrcidError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s, false)
+    }
+}
+
+impl TryFrom<&str> for OrcidId {
+    type Error = OrcidError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl TryFrom<String> for OrcidId {
+    type Error = OrcidError;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl AsRef<str> for OrcidId {
+    fn as_ref(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Serializes to the canonical string; deserializing rejects invalid iDs
+#[cfg(feature = "serde")]
+impl serde::Serialize for OrcidId {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.id)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OrcidId {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forms() {
+        for s in [
+            "0000-0002-1825-0097",
+            "0000000218250097",
+            "https://orcid.org/0000-0002-1825-0097",
+            "http://orcid.org/0000-0002-1825-0097",
+            "https://sandbox.orcid.org/0000-0002-1825-0097",
+            "HTTPS://ORCID.ORG/0000000218250097/",
+            "orcid.org/0000-0002-1825-0097",
+            " 0000-0002-1825-0097\n",
+        ] {
+            let id: OrcidId = s.parse().unwrap();
+            assert_eq!(id.as_str(), "0000-0002-1825-0097", "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for s in [
+            "",
+            "xyz",
+            "0000-0001-5916",
+            "0000-0001-5916-0947-1234",
+            "0000-0001-5916-0948",
+            "0000-0001-6916-0947",
+            "0000-0001-5916-094A",
+            "00-0000-0159-160947",
+            "0000-0001-5916-094?",
+            "https://example.org/0000-0001-5916-0947",
+            "0000-0002-1825-009x",
+            "00000000000000é",
+            "0000-0000-0000-00é",
+            "000000000000009é",
+            "https://orcid.org/0000-0000-0000-00é",
+        ] {
+            assert!(s.parse::<OrcidId>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        for s in ["00000000000000é", "0000-0000-0000-00é"] {
+            assert!(OrcidId::parse_lenient(s).is_err(), "{}", s);
+            assert!(OrcidId::try_from(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_lowercase_x() {
+        assert!("0000-0002-1694-233x".parse::<OrcidId>().is_err());
+        let id = OrcidId::parse_lenient("0000-0002-1694-233x").unwrap();
+        assert_eq!(id.as_str(), "0000-0002-1694-233X");
+        assert_eq!(
+            "0000-0002-1694-233X".parse::<OrcidId>().unwrap().as_str(),
+            "0000-0002-1694-233X"
+        );
+    }
+
+    #[test]
+    fn test_check_digit() {
+        assert_eq!(OrcidId::check_digit("000000015916094").unwrap(), '7');
+        assert_eq!(OrcidId::check_digit("000000021825009").unwrap(), '7');
+        assert_eq!(OrcidId::check_digit("000000021694233").unwrap(), 'X');
+        assert!(OrcidId::check_digit("00000002182500").is_err());
+        assert!(OrcidId::check_digit("00000002182500A").is_err());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let id = OrcidId::try_from("0000000159160947").unwrap();
+        assert_eq!(id.to_string(), "0000-0001-5916-0947");
+        assert_eq!(id.uri(), "https://orcid.org/0000-0001-5916-0947");
+        assert_eq!(
+            OrcidId::try_from("0000-0001-5916-0947".to_string()).unwrap(),
+            id
+        );
+        match OrcidId::try_from("invalid") {
+            Err(OrcidError::InvalidOrcidId(s)) => assert_eq!(s, "invalid"),
+            _ => panic!("Expected InvalidOrcidId"),
+        }
+    }
+
+    #[test]
+    fn test_ord_and_hash() {
+        let a: OrcidId = "0000-0001-5916-0947".parse().unwrap();
+        let b: OrcidId = "0000-0002-1825-0097".parse().unwrap();
+        assert!(a < b);
+        let set: std::collections::HashSet<OrcidId> =
+            [a.clone(), b, "0000000159160947".parse().unwrap()]
+                .into_iter()
+                .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&a));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let id: OrcidId = "0000-0002-1825-0097".parse().unwrap();
+        let serialized = serde_json::to_value(&id).unwrap();
+        assert_eq!(serialized, serde_json::json!("0000-0002-1825-0097"));
+        let back: OrcidId = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, id);
+        assert!(serde_json::from_value::<OrcidId>(serde_json::json!("invalid")).is_err());
+    }
+}
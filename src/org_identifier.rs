@@ -0,0 +1,318 @@
+use crate::error::{OrcidError, Result};
+use crate::utils::strip_prefixes;
+use std::fmt;
+
+/// Crockford base32, as used by ROR IDs
+const ROR_ALPHABET: &str = "0123456789abcdefghjkmnpqrstvwxyz";
+
+/// A disambiguated organization identifier, stored in its bare canonical form,
+/// e.g. `03yrm5c26` for `https://ror.org/03yrm5c26`
+///
+/// # Example
+///
+/// ```
+/// use orcid::OrgIdentifier;
+///
+/// let ror = OrgIdentifier::new("ROR", "https://ror.org/03yrm5c26").unwrap();
+/// assert_eq!(ror, OrgIdentifier::Ror("03yrm5c26".to_string()));
+/// assert_eq!(ror.url(), Some("https://ror.org/03yrm5c26".to_string()));
+/// assert!(OrgIdentifier::new("ROR", "03yrm5c27").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrgIdentifier {
+    /// Research Organization Registry, e.g. `03yrm5c26`
+    Ror(String),
+    /// Ringgold, e.g. `5292`
+    Ringgold(String),
+    /// Global Research Identifier Database, e.g. `grid.5335.0`
+    Grid(String),
+    /// Crossref Funder Registry, e.g. `100000001` for `10.13039/100000001`
+    Fundref(String),
+    /// Legal Entity Identifier, e.g. `5493001KJTIIGC8Y1R12`
+    Lei(String),
+    /// An identifier ORCID sent with an unknown source, or that failed validation,
+    /// kept verbatim
+    Other { source: String, id: String },
+}
+
+impl OrgIdentifier {
+    /// Parses an identifier for an ORCID `disambiguation-source`, e.g. `ROR` or `RINGGOLD`.
+    /// URL forms are accepted, and check digits are verified for ROR IDs and LEIs.
+    pub fn new(source: &str, id: &str) -> Result<Self> {
+        let invalid = || OrcidError::InvalidOrgIdentifier(format!("{} {}", source, id));
+        let id = id.trim();
+        let ret = match source.to_ascii_uppercase().as_str() {
+            "ROR" => {
+                let id = strip_prefixes(id, &["https://ror.org/", "http://ror.org/", "ror.org/"])
+                    .to_ascii_lowercase();
+                Self::is_valid_ror(&id).then_some(OrgIdentifier::Ror(id))
+            }
+            "RINGGOLD" => is_digits(id).then(|| OrgIdentifier::Ringgold(id.to_string())),
+            "GRID" => {
+                let id = strip_prefixes(
+                    id,
+                    &[
+                        "https://www.grid.ac/institutes/",
+                        "http://www.grid.ac/institutes/",
+                        "https://grid.ac/institutes/",
+                    ],
+                )
+                .to_ascii_lowercase();
+                Self::is_valid_grid(&id).then_some(OrgIdentifier::Grid(id))
+            }
+            "FUNDREF" => {
+                let id = strip_prefixes(
+                    id,
+                    &[
+                        "https://doi.org/",
+                        "http://doi.org/",
+                        "https://dx.doi.org/",
+                        "http://dx.doi.org/",
+                    ],
+                );
+                let id = strip_prefixes(id, &["10.13039/"]);
+                is_digits(id).then(|| OrgIdentifier::Fundref(id.to_string()))
+            }
+            "LEI" => {
+                let id = id.to_ascii_uppercase();
+                Self::is_valid_lei(&id).then_some(OrgIdentifier::Lei(id))
+            }
+            _ => None,
+        };
+        ret.ok_or_else(invalid)
+    }
+
+    /// Like `new`, but keeps an unknown or invalid identifier as `Other`
+    pub fn new_lenient(source: &str, id: &str) -> Self {
+        Self::new(source, id).unwrap_or_else(|_| OrgIdentifier::Other {
+            source: source.to_string(),
+            id: id.to_string(),
+        })
+    }
+
+    /// `0`, six base32 characters and a two-digit ISO 7064 mod 97-10 checksum
+    fn is_valid_ror(id: &str) -> bool {
+        if id.len() != 9 || !id.starts_with('0') || !id.is_ascii() {
+            return false;
+        }
+        let (base, checksum) = id.split_at(7);
+        let value = base.chars().try_fold(0u64, |value, c| {
+            ROR_ALPHABET.find(c).map(|digit| value * 32 + digit as u64)
+        });
+        match value {
+            Some(value) => format!("{:02}", 98 - (value * 100) % 97) == checksum,
+            None => false,
+        }
+    }
+
+    /// `grid.`, a number and a suffix, e.g. `grid.5335.0`
+    fn is_valid_grid(id: &str) -> bool {
+        let parts: Vec<&str> = id.split('.').collect();
+        matches!(parts.as_slice(), ["grid", number, suffix]
+            if is_digits(number) && !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_alphanumeric()))
+    }
+
+    /// 20 alphanumeric characters passing the ISO 7064 mod 97-10 check
+    fn is_valid_lei(id: &str) -> bool {
+        id.len() == 20
+            && id.chars().all(|c| c.is_ascii_alphanumeric())
+            && id
+                .chars()
+                .filter_map(|c| c.to_digit(36))
+                .fold(0, |rem, digit| {
+                    if digit < 10 {
+                        (rem * 10 + digit) % 97
+                    } else {
+                        (rem * 100 + digit) % 97
+                    }
+                })
+                == 1
+    }
+
+    /// The ORCID `disambiguation-source`, e.g. `RINGGOLD`
+    pub fn source(&self) -> &str {
+        match self {
+            OrgIdentifier::Ror(_) => "ROR",
+            OrgIdentifier::Ringgold(_) => "RINGGOLD",
+            OrgIdentifier::Grid(_) => "GRID",
+            OrgIdentifier::Fundref(_) => "FUNDREF",
+            OrgIdentifier::Lei(_) => "LEI",
+            OrgIdentifier::Other { source, .. } => source,
+        }
+    }
+
+    /// The bare identifier, e.g. `03yrm5c26`
+    pub fn id(&self) -> &str {
+        match self {
+            OrgIdentifier::Ror(id)
+            | OrgIdentifier::Ringgold(id)
+            | OrgIdentifier::Grid(id)
+            | OrgIdentifier::Fundref(id)
+            | OrgIdentifier::Lei(id)
+            | OrgIdentifier::Other { id, .. } => id,
+        }
+    }
+
+    /// The canonical URL; Ringgold and other IDs have none
+    pub fn url(&self) -> Option<String> {
+        match self {
+            OrgIdentifier::Ror(id) => Some(format!("https://ror.org/{}", id)),
+            OrgIdentifier::Ringgold(_) | OrgIdentifier::Other { .. } => None,
+            OrgIdentifier::Grid(id) => Some(format!("https://www.grid.ac/institutes/{}", id)),
+            OrgIdentifier::Fundref(id) => Some(format!("https://doi.org/10.13039/{}", id)),
+            OrgIdentifier::Lei(id) => Some(format!("https://search.gleif.org/#/record/{}", id)),
+        }
+    }
+
+    /// Serializes to an ORCID v3.0 `disambiguated-organization` object. ROR and FundRef
+    /// IDs are sent as URLs, the others bare, which is how ORCID stores them.
+    pub fn to_json(&self) -> serde_json::Value {
+        let id = match self {
+            OrgIdentifier::Ror(_) => self.url().unwrap_or_default(),
+            OrgIdentifier::Fundref(id) => format!("http://dx.doi.org/10.13039/{}", id),
+            _ => self.id().to_string(),
+        };
+        serde_json::json!({
+            "disambiguated-organization-identifier": id,
+            "disambiguation-source": self.source(),
+        })
+    }
+}
+
+/// `SOURCE:id`, e.g. `ROR:03yrm5c26`
+impl fmt::Display for OrgIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.source(), self.id())
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ror() {
+        for id in [
+            "03yrm5c26",
+            "https://ror.org/03yrm5c26",
+            "HTTP://ROR.ORG/03YRM5C26",
+            "ror.org/03yrm5c26",
+        ] {
+            assert_eq!(
+                OrgIdentifier::new("ROR", id).unwrap(),
+                OrgIdentifier::Ror("03yrm5c26".to_string()),
+                "{}",
+                id
+            );
+        }
+        assert!(OrgIdentifier::new("ror", "013meh722").is_ok());
+        for id in ["03yrm5c27", "13yrm5c26", "03yrm5c2", "03yrl5c26", ""] {
+            assert!(OrgIdentifier::new("ROR", id).is_err(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_ringgold() {
+        let id = OrgIdentifier::new("RINGGOLD", " 5292 ").unwrap();
+        assert_eq!(id, OrgIdentifier::Ringgold("5292".to_string()));
+        assert_eq!(id.url(), None);
+        assert!(OrgIdentifier::new("RINGGOLD", "52a").is_err());
+    }
+
+    #[test]
+    fn test_grid() {
+        let id = OrgIdentifier::new("GRID", "https://www.grid.ac/institutes/grid.5335.0").unwrap();
+        assert_eq!(id, OrgIdentifier::Grid("grid.5335.0".to_string()));
+        assert_eq!(id.to_string(), "GRID:grid.5335.0");
+        assert!(OrgIdentifier::new("GRID", "grid.5335").is_err());
+        assert!(OrgIdentifier::new("GRID", "5335.0").is_err());
+    }
+
+    #[test]
+    fn test_fundref() {
+        for id in [
+            "100000001",
+            "10.13039/100000001",
+            "http://dx.doi.org/10.13039/100000001",
+        ] {
+            assert_eq!(
+                OrgIdentifier::new("FUNDREF", id).unwrap(),
+                OrgIdentifier::Fundref("100000001".to_string())
+            );
+        }
+        let id = OrgIdentifier::Fundref("100000001".to_string());
+        assert_eq!(
+            id.url(),
+            Some("https://doi.org/10.13039/100000001".to_string())
+        );
+        assert!(OrgIdentifier::new("FUNDREF", "10.1234/100000001").is_err());
+    }
+
+    #[test]
+    fn test_lei() {
+        let id = OrgIdentifier::new("LEI", "5493001kjtiigc8y1r12").unwrap();
+        assert_eq!(id, OrgIdentifier::Lei("5493001KJTIIGC8Y1R12".to_string()));
+        assert!(OrgIdentifier::new("LEI", "5493001KJTIIGC8Y1R13").is_err());
+        assert!(OrgIdentifier::new("LEI", "5493001KJTIIGC8Y1R1").is_err());
+    }
+
+    #[test]
+    fn test_unknown_source() {
+        match OrgIdentifier::new("ISNI", "0000000121032683") {
+            Err(OrcidError::InvalidOrgIdentifier(s)) => assert_eq!(s, "ISNI 0000000121032683"),
+            _ => panic!("Expected InvalidOrgIdentifier"),
+        }
+    }
+
+    #[test]
+    fn test_new_lenient() {
+        assert_eq!(
+            OrgIdentifier::new_lenient("RINGGOLD", "5292"),
+            OrgIdentifier::Ringgold("5292".to_string())
+        );
+        let id = OrgIdentifier::new_lenient("RINGGOLD", "52a");
+        assert_eq!(
+            id,
+            OrgIdentifier::Other {
+                source: "RINGGOLD".to_string(),
+                id: "52a".to_string()
+            }
+        );
+        assert_eq!(id.to_string(), "RINGGOLD:52a");
+        assert_eq!(id.url(), None);
+        assert_eq!(
+            id.to_json(),
+            json!({
+                "disambiguated-organization-identifier": "52a",
+                "disambiguation-source": "RINGGOLD"
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            OrgIdentifier::Ror("03yrm5c26".to_string()).to_json(),
+            json!({
+                "disambiguated-organization-identifier": "https://ror.org/03yrm5c26",
+                "disambiguation-source": "ROR"
+            })
+        );
+        assert_eq!(
+            OrgIdentifier::Ringgold("5292".to_string()).to_json()
+                ["disambiguated-organization-identifier"],
+            "5292"
+        );
+        assert_eq!(
+            OrgIdentifier::Fundref("100000001".to_string()).to_json()
+                ["disambiguated-organization-identifier"],
+            "http://dx.doi.org/10.13039/100000001"
+        );
+    }
+}
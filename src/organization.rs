@@ -1,36 +1,29 @@
+use crate::org_identifier::OrgIdentifier;
+use crate::record;
 use serde_json;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Organization {
     name: Option<String>,
     city: Option<String>,
     region: Option<String>,
     country: Option<String>,
-    disambiguated_organization: Option<(String, String)>, // TODO external IDS
+    disambiguated_organization: Option<OrgIdentifier>,
 }
 
 impl Organization {
-    pub fn new_from_json(j: &serde_json::Value) -> Self {
-        let d_o = match j["disambiguated-organization"].as_object() {
-            Some(o) => match (
-                o.get("disambiguation-source"),
-                o.get("disambiguated-organization-identifier"),
-            ) {
-                (Some(source), Some(id)) => Some((source.to_string(), id.to_string())),
-                _ => None,
-            },
-            None => None,
-        };
-
+    pub fn new(name: &str) -> Self {
         Self {
-            name: j["name"].as_str().map(|s| s.to_string()),
-            city: j["address"]["city"].as_str().map(|s| s.to_string()),
-            region: j["address"]["region"].as_str().map(|s| s.to_string()),
-            country: j["address"]["country"].as_str().map(|s| s.to_string()),
-            disambiguated_organization: d_o,
+            name: Some(name.to_string()),
+            ..Default::default()
         }
     }
 
+    pub fn new_from_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::Organization>(j))
+    }
+
     pub fn name(&self) -> Option<&String> {
         self.name.as_ref()
     }
@@ -47,9 +40,82 @@ impl Organization {
         self.country.as_ref()
     }
 
-    pub fn disambiguated_organization(&self) -> Option<&(String, String)> {
+    /// The ROR, Ringgold, GRID, FundRef or LEI identifier of the organization
+    pub fn disambiguated_organization(&self) -> Option<&OrgIdentifier> {
         self.disambiguated_organization.as_ref()
     }
+
+    /// The bare ROR ID, e.g. `03yrm5c26`, if the organization is disambiguated by ROR
+    pub fn ror(&self) -> Option<&str> {
+        match self.disambiguated_organization {
+            Some(OrgIdentifier::Ror(ref id)) => Some(id),
+            _ => None,
+        }
+    }
+
+    // Setter methods
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
+
+    pub fn set_city(&mut self, city: Option<String>) {
+        self.city = city;
+    }
+
+    pub fn set_region(&mut self, region: Option<String>) {
+        self.region = region;
+    }
+
+    /// Set the two-letter ISO 3166 country code
+    pub fn set_country(&mut self, country: Option<String>) {
+        self.country = country;
+    }
+
+    pub fn set_disambiguated_organization(
+        &mut self,
+        disambiguated_organization: Option<OrgIdentifier>,
+    ) {
+        self.disambiguated_organization = disambiguated_organization;
+    }
+
+    /// Serializes to an ORCID v3.0 `organization` object
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = serde_json::json!({
+            "name": self.name,
+            "address": {
+                "city": self.city,
+                "region": self.region,
+                "country": self.country,
+            },
+        });
+        if let Some(ref disambiguated_organization) = self.disambiguated_organization {
+            j["disambiguated-organization"] = disambiguated_organization.to_json();
+        }
+        j
+    }
+}
+
+impl From<&record::Organization> for Organization {
+    fn from(org: &record::Organization) -> Self {
+        let address = org.address.as_ref();
+        // Unknown sources and malformed identifiers are kept as `OrgIdentifier::Other`
+        let d_o = org.disambiguated_organization.as_ref().and_then(|d_o| {
+            match (
+                &d_o.disambiguation_source,
+                &d_o.disambiguated_organization_identifier,
+            ) {
+                (Some(source), Some(id)) => Some(OrgIdentifier::new_lenient(source, id)),
+                _ => None,
+            }
+        });
+        Self {
+            name: org.name.clone(),
+            city: address.and_then(|a| a.city.clone()),
+            region: address.and_then(|a| a.region.clone()),
+            country: address.and_then(|a| a.country.clone()),
+            disambiguated_organization: d_o,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -80,7 +146,7 @@ mod tests {
         assert_eq!(org.country(), Some(&"Test Country".to_string()));
         assert_eq!(
             org.disambiguated_organization(),
-            Some(&("\"RINGGOLD\"".to_string(), "\"12345\"".to_string()))
+            Some(&OrgIdentifier::Ringgold("12345".to_string()))
         );
     }
 
@@ -130,7 +196,7 @@ mod tests {
         assert_eq!(org.country(), None);
         assert_eq!(
             org.disambiguated_organization(),
-            Some(&("\"GRID\"".to_string(), "\"grid.12345.6\"".to_string()))
+            Some(&OrgIdentifier::Grid("grid.12345.6".to_string()))
         );
     }
 
@@ -150,6 +216,28 @@ mod tests {
         assert_eq!(org.disambiguated_organization(), None);
     }
 
+    #[test]
+    fn test_new_from_json_keeps_unknown_disambiguated() {
+        let j = json!({
+            "name": "Test University",
+            "disambiguated-organization": {
+                "disambiguation-source": "RINGGOLD",
+                "disambiguated-organization-identifier": "52a"
+            }
+        });
+
+        let org = Organization::new_from_json(&j);
+
+        assert_eq!(
+            org.disambiguated_organization(),
+            Some(&OrgIdentifier::Other {
+                source: "RINGGOLD".to_string(),
+                id: "52a".to_string()
+            })
+        );
+        assert_eq!(org.ror(), None);
+    }
+
     #[test]
     fn test_new_from_json_partial_address() {
         let j = json!({
@@ -168,4 +256,93 @@ mod tests {
         assert_eq!(org.region(), None);
         assert_eq!(org.country(), Some(&"Test Country".to_string()));
     }
+
+    #[test]
+    fn test_to_json() {
+        let mut org = Organization::new("Test University");
+        org.set_city(Some("Test City".to_string()));
+        org.set_country(Some("GB".to_string()));
+        org.set_disambiguated_organization(Some(OrgIdentifier::Ror("03yrm5c26".to_string())));
+
+        assert_eq!(
+            org.to_json(),
+            json!({
+                "name": "Test University",
+                "address": {
+                    "city": "Test City",
+                    "region": null,
+                    "country": "GB"
+                },
+                "disambiguated-organization": {
+                    "disambiguated-organization-identifier": "https://ror.org/03yrm5c26",
+                    "disambiguation-source": "ROR"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_json_from_parsed() {
+        let j = json!({
+            "name": "Test University",
+            "disambiguated-organization": {
+                "disambiguation-source": "RINGGOLD",
+                "disambiguated-organization-identifier": "12345"
+            }
+        });
+        let org = Organization::new_from_json(&j);
+        let out = org.to_json();
+        assert_eq!(
+            out["disambiguated-organization"],
+            j["disambiguated-organization"]
+        );
+    }
+
+    #[test]
+    fn test_ror() {
+        let j = json!({
+            "name": "University of Cambridge",
+            "disambiguated-organization": {
+                "disambiguation-source": "ROR",
+                "disambiguated-organization-identifier": "https://ror.org/013meh722"
+            }
+        });
+        let org = Organization::new_from_json(&j);
+        assert_eq!(org.ror(), Some("013meh722"));
+        assert_eq!(
+            org.to_json()["disambiguated-organization"],
+            j["disambiguated-organization"]
+        );
+
+        let j = json!({
+            "name": "Test University",
+            "disambiguated-organization": {
+                "disambiguation-source": "ROR",
+                "disambiguated-organization-identifier": "not-a-ror-id"
+            }
+        });
+        let org = Organization::new_from_json(&j);
+        assert_eq!(
+            org.disambiguated_organization().map(OrgIdentifier::source),
+            Some("ROR")
+        );
+        assert_eq!(org.ror(), None);
+        assert_eq!(
+            org.to_json()["disambiguated-organization"],
+            j["disambiguated-organization"]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut org = Organization::new("Test University");
+        org.set_city(Some("Cambridge".to_string()));
+        org.set_disambiguated_organization(Some(OrgIdentifier::Ringgold("2167".to_string())));
+        let serialized = serde_json::to_value(&org).unwrap();
+        assert_eq!(serialized["name"], "Test University");
+        assert_eq!(serialized["city"], "Cambridge");
+        let back: Organization = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, org);
+    }
 }
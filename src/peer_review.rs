@@ -1,100 +1,46 @@
-use crate::date::Date;
+use crate::activity_group::GroupedActivity;
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::external_id::{self, ExternalId};
+use crate::fuzzy_date::FuzzyDate;
 use crate::organization::Organization;
+use crate::record;
 use serde_json;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PeerReview {
+    metadata: ActivityMetadata,
     organization: Option<Organization>,
     review_type: Option<String>,
     review_role: Option<String>,
     review_url: Option<String>,
-    review_completion_date: Option<Date>,
+    review_completion_date: Option<FuzzyDate>,
     review_group_id: Option<String>,
-    subject_external_identifier: Option<(String, String)>,
+    subject_external_identifier: Option<ExternalId>,
     subject_type: Option<String>,
     subject_name: Option<String>,
     subject_url: Option<String>,
-    external_ids: Vec<(String, String)>,
+    external_ids: Vec<ExternalId>,
 }
 
 impl PeerReview {
+    /// Parses the first summary of a `peer-reviews` group
     pub fn new_from_json(j: &serde_json::Value) -> Self {
-        let peer_review_summary = &j["peer-review-summary"][0];
-
-        let external_ids =
-            if let Some(ext_ids) = peer_review_summary["external-ids"]["external-id"].as_array() {
-                ext_ids
-                    .iter()
-                    .filter_map(|id| {
-                        match (
-                            id["external-id-type"].as_str(),
-                            id["external-id-value"].as_str(),
-                        ) {
-                            (Some(id_type), Some(id_value)) => {
-                                Some((id_type.to_string(), id_value.to_string()))
-                            }
-                            _ => None,
-                        }
-                    })
-                    .collect()
-            } else {
-                vec![]
-            };
-
-        let subject_external_identifier =
-            match peer_review_summary["subject-external-identifier"].as_object() {
-                Some(obj) => {
-                    match (
-                        obj.get("external-id-type").and_then(|v| v.as_str()),
-                        obj.get("external-id-value").and_then(|v| v.as_str()),
-                    ) {
-                        (Some(id_type), Some(id_value)) => {
-                            Some((id_type.to_string(), id_value.to_string()))
-                        }
-                        _ => None,
-                    }
-                }
-                None => None,
-            };
+        Self::new_from_summary_json(&j["peer-review-summary"][0])
+    }
 
-        Self {
-            organization: if peer_review_summary["organization"].is_object() {
-                Some(Organization::new_from_json(
-                    &peer_review_summary["organization"],
-                ))
-            } else {
-                None
-            },
-            review_type: peer_review_summary["review-type"]
-                .as_str()
-                .map(|s| s.to_string()),
-            review_role: peer_review_summary["role"].as_str().map(|s| s.to_string()),
-            review_url: peer_review_summary["review-url"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-            review_completion_date: if peer_review_summary["completion-date"]["year"].is_object() {
-                Some(Date::new_from_json(&peer_review_summary["completion-date"]))
-            } else {
-                None
-            },
-            review_group_id: peer_review_summary["review-group-id"]
-                .as_str()
-                .map(|s| s.to_string()),
-            subject_external_identifier,
-            subject_type: peer_review_summary["subject-type"]
-                .as_str()
-                .map(|s| s.to_string()),
-            subject_name: peer_review_summary["subject-name"]["title"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-            subject_url: peer_review_summary["subject-url"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-            external_ids,
-        }
+    /// Parses a single `peer-review-summary` object
+    pub fn new_from_summary_json(peer_review_summary: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::PeerReviewSummary>(
+            peer_review_summary,
+        ))
     }
 
     // Getter methods
+    pub fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+
     pub fn organization(&self) -> Option<&Organization> {
         self.organization.as_ref()
     }
@@ -111,7 +57,7 @@ impl PeerReview {
         self.review_url.as_ref()
     }
 
-    pub fn review_completion_date(&self) -> Option<&Date> {
+    pub fn review_completion_date(&self) -> Option<&FuzzyDate> {
         self.review_completion_date.as_ref()
     }
 
@@ -119,7 +65,7 @@ impl PeerReview {
         self.review_group_id.as_ref()
     }
 
-    pub fn subject_external_identifier(&self) -> Option<&(String, String)> {
+    pub fn subject_external_identifier(&self) -> Option<&ExternalId> {
         self.subject_external_identifier.as_ref()
     }
 
@@ -135,14 +81,59 @@ impl PeerReview {
         self.subject_url.as_ref()
     }
 
-    pub fn external_ids(&self) -> &Vec<(String, String)> {
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
         &self.external_ids
     }
 }
 
+impl Activity for PeerReview {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl GroupedActivity for PeerReview {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId> {
+        &mut self.external_ids
+    }
+}
+
+impl From<&record::PeerReviewSummary> for PeerReview {
+    fn from(summary: &record::PeerReviewSummary) -> Self {
+        Self {
+            metadata: ActivityMetadata::from(&summary.metadata),
+            organization: summary
+                .convening_organization
+                .as_ref()
+                .map(Organization::from),
+            review_type: summary.review_type.clone(),
+            review_role: summary.reviewer_role.clone(),
+            review_url: record::string_value(&summary.review_url),
+            review_completion_date: summary
+                .completion_date
+                .as_ref()
+                .filter(|date| date.year.is_some())
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            review_group_id: summary.review_group_id.clone(),
+            subject_external_identifier: summary
+                .subject_external_identifier
+                .as_ref()
+                .and_then(ExternalId::from_record),
+            subject_type: summary.subject_type.clone(),
+            subject_name: summary
+                .subject_name
+                .as_ref()
+                .and_then(|t| record::string_value(&t.title)),
+            subject_url: record::string_value(&summary.subject_url),
+            external_ids: external_id::from_record_ids(&summary.external_ids),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::external_id::ExternalIdType;
     use serde_json::json;
 
     #[test]
@@ -209,7 +200,7 @@ mod tests {
         );
         assert_eq!(
             review.subject_external_identifier(),
-            Some(&("doi".to_string(), "10.1038/nature12345".to_string()))
+            Some(&ExternalId::new(ExternalIdType::Doi, "10.1038/nature12345"))
         );
         assert_eq!(review.subject_type(), Some(&"journal-article".to_string()));
         assert_eq!(
@@ -222,8 +213,11 @@ mod tests {
         );
         assert_eq!(review.external_ids().len(), 1);
         assert_eq!(
-            review.external_ids()[0],
-            ("peer-review".to_string(), "PR-2023-12345".to_string())
+            (
+                review.external_ids()[0].id_type().as_str(),
+                review.external_ids()[0].value()
+            ),
+            ("peer-review", "PR-2023-12345")
         );
     }
 
@@ -297,18 +291,25 @@ mod tests {
 
         assert_eq!(review.external_ids().len(), 2);
         assert_eq!(
-            review.external_ids()[0],
-            ("peer-review".to_string(), "PR-123".to_string())
+            (
+                review.external_ids()[0].id_type().as_str(),
+                review.external_ids()[0].value()
+            ),
+            ("peer-review", "PR-123")
         );
         assert_eq!(
-            review.external_ids()[1],
-            ("publons".to_string(), "PUB-456".to_string())
+            (
+                review.external_ids()[1].id_type().as_str(),
+                review.external_ids()[1].value()
+            ),
+            ("publons", "PUB-456")
         );
     }
 
     #[test]
     fn test_debug_trait() {
         let review = PeerReview {
+            metadata: ActivityMetadata::default(),
             organization: None,
             review_type: Some("review".to_string()),
             review_role: Some("reviewer".to_string()),
@@ -331,17 +332,18 @@ mod tests {
     #[test]
     fn test_clone_trait() {
         let review = PeerReview {
+            metadata: ActivityMetadata::default(),
             organization: None,
             review_type: Some("review".to_string()),
             review_role: Some("reviewer".to_string()),
             review_url: Some("https://example.com".to_string()),
             review_completion_date: None,
             review_group_id: Some("issn:1234-5678".to_string()),
-            subject_external_identifier: Some(("doi".to_string(), "10.1234/test".to_string())),
+            subject_external_identifier: Some(ExternalId::new(ExternalIdType::Doi, "10.1234/test")),
             subject_type: Some("journal-article".to_string()),
             subject_name: Some("Test Article".to_string()),
             subject_url: Some("https://doi.org/10.1234/test".to_string()),
-            external_ids: vec![("id".to_string(), "123".to_string())],
+            external_ids: vec![ExternalId::new("id".into(), "123")],
         };
 
         let cloned = review.clone();
@@ -358,4 +360,48 @@ mod tests {
         assert_eq!(cloned.subject_url(), review.subject_url());
         assert_eq!(cloned.external_ids(), review.external_ids());
     }
+
+    #[test]
+    fn test_new_from_json_metadata() {
+        let j = json!({
+            "peer-review-summary": [{
+                "put-code": 4242,
+                "visibility": "limited",
+                "last-modified-date": { "value": 1700000000000u64 },
+                "source": { "source-name": { "value": "Jane Doe" } }
+            }]
+        });
+        let metadata = PeerReview::new_from_json(&j).metadata().clone();
+        assert_eq!(metadata.put_code(), Some(4242));
+        assert_eq!(metadata.visibility(), Some(&"limited".to_string()));
+        assert!(metadata.last_modified_date().is_some());
+        assert_eq!(
+            metadata.source().and_then(|s| s.name()),
+            Some(&"Jane Doe".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let j = json!({
+            "peer-review-summary": [{
+                "review-type": "review",
+                "review-group-id": "issn:0028-0836",
+                "subject-external-identifier": {
+                    "external-id-type": "doi",
+                    "external-id-value": "10.1234/subject"
+                }
+            }]
+        });
+        let review = PeerReview::new_from_json(&j);
+        let serialized = serde_json::to_value(&review).unwrap();
+        assert_eq!(serialized["review_group_id"], "issn:0028-0836");
+        assert_eq!(
+            serialized["subject_external_identifier"]["value"],
+            "10.1234/subject"
+        );
+        let back: PeerReview = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, review);
+    }
 }
@@ -1,75 +1,47 @@
-use crate::date::Date;
+use crate::activity_group::GroupedActivity;
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::external_id::{self, ExternalId};
+use crate::fuzzy_date::FuzzyDate;
 use crate::organization::Organization;
+use crate::record;
+use crate::utils::external_ids_to_json;
 use serde_json;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Qualification {
+    metadata: ActivityMetadata,
     organization: Option<Organization>,
     department_name: Option<String>,
     role_title: Option<String>,
-    start_date: Option<Date>,
-    end_date: Option<Date>,
-    external_ids: Vec<(String, String)>,
+    start_date: Option<FuzzyDate>,
+    end_date: Option<FuzzyDate>,
+    external_ids: Vec<ExternalId>,
     url: Option<String>,
 }
 
 impl Qualification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the first summary of a `qualifications` group
     pub fn new_from_json(j: &serde_json::Value) -> Self {
-        let qualification_summary = &j["qualification-summary"][0];
-
-        let external_ids = if let Some(ext_ids) =
-            qualification_summary["external-ids"]["external-id"].as_array()
-        {
-            ext_ids
-                .iter()
-                .filter_map(|id| {
-                    match (
-                        id["external-id-type"].as_str(),
-                        id["external-id-value"].as_str(),
-                    ) {
-                        (Some(id_type), Some(id_value)) => {
-                            Some((id_type.to_string(), id_value.to_string()))
-                        }
-                        _ => None,
-                    }
-                })
-                .collect()
-        } else {
-            vec![]
-        };
+        Self::new_from_summary_json(&j["qualification-summary"][0])
+    }
 
-        Self {
-            organization: if qualification_summary["organization"].is_object() {
-                Some(Organization::new_from_json(
-                    &qualification_summary["organization"],
-                ))
-            } else {
-                None
-            },
-            department_name: qualification_summary["department-name"]
-                .as_str()
-                .map(|s| s.to_string()),
-            role_title: qualification_summary["role-title"]
-                .as_str()
-                .map(|s| s.to_string()),
-            start_date: if qualification_summary["start-date"].is_object() {
-                Some(Date::new_from_json(&qualification_summary["start-date"]))
-            } else {
-                None
-            },
-            end_date: if qualification_summary["end-date"].is_object() {
-                Some(Date::new_from_json(&qualification_summary["end-date"]))
-            } else {
-                None
-            },
-            external_ids,
-            url: qualification_summary["url"]["value"]
-                .as_str()
-                .map(|s| s.to_string()),
-        }
+    /// Parses a single `qualification-summary` object
+    pub fn new_from_summary_json(qualification_summary: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::AffiliationSummary>(
+            qualification_summary,
+        ))
     }
 
     // Getter methods
+    pub fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+
     pub fn organization(&self) -> Option<&Organization> {
         self.organization.as_ref()
     }
@@ -82,21 +54,105 @@ impl Qualification {
         self.role_title.as_ref()
     }
 
-    pub fn start_date(&self) -> Option<&Date> {
+    pub fn start_date(&self) -> Option<&FuzzyDate> {
         self.start_date.as_ref()
     }
 
-    pub fn end_date(&self) -> Option<&Date> {
+    pub fn end_date(&self) -> Option<&FuzzyDate> {
         self.end_date.as_ref()
     }
 
-    pub fn external_ids(&self) -> &Vec<(String, String)> {
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
         &self.external_ids
     }
 
     pub fn url(&self) -> Option<&String> {
         self.url.as_ref()
     }
+
+    // Setter methods
+    pub fn set_organization(&mut self, organization: Option<Organization>) {
+        self.organization = organization;
+    }
+
+    pub fn set_department_name(&mut self, department_name: Option<String>) {
+        self.department_name = department_name;
+    }
+
+    pub fn set_role_title(&mut self, role_title: Option<String>) {
+        self.role_title = role_title;
+    }
+
+    pub fn set_start_date(&mut self, start_date: Option<FuzzyDate>) {
+        self.start_date = start_date;
+    }
+
+    pub fn set_end_date(&mut self, end_date: Option<FuzzyDate>) {
+        self.end_date = end_date;
+    }
+
+    pub fn set_external_ids(&mut self, external_ids: Vec<ExternalId>) {
+        self.external_ids = external_ids;
+    }
+
+    pub fn set_url(&mut self, url: Option<String>) {
+        self.url = url;
+    }
+
+    /// Serializes to an ORCID v3.0 `qualification` object
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = serde_json::json!({
+            "department-name": self.department_name,
+            "role-title": self.role_title,
+            "organization": self.organization.as_ref().map(|o| o.to_json()),
+        });
+        if let Some(ref start_date) = self.start_date {
+            j["start-date"] = start_date.to_json();
+        }
+        if let Some(ref end_date) = self.end_date {
+            j["end-date"] = end_date.to_json();
+        }
+        if !self.external_ids.is_empty() {
+            j["external-ids"] = external_ids_to_json(&self.external_ids);
+        }
+        if let Some(ref url) = self.url {
+            j["url"] = serde_json::json!({ "value": url });
+        }
+        j
+    }
+}
+
+impl Activity for Qualification {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl GroupedActivity for Qualification {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId> {
+        &mut self.external_ids
+    }
+}
+
+impl From<&record::AffiliationSummary> for Qualification {
+    fn from(summary: &record::AffiliationSummary) -> Self {
+        Self {
+            metadata: ActivityMetadata::from(&summary.metadata),
+            organization: summary.organization.as_ref().map(Organization::from),
+            department_name: summary.department_name.clone(),
+            role_title: summary.role_title.clone(),
+            start_date: summary
+                .start_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            end_date: summary
+                .end_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            external_ids: external_id::from_record_ids(&summary.external_ids),
+            url: record::string_value(&summary.url),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -159,11 +215,11 @@ mod tests {
         assert!(qualification.end_date().is_some());
         assert_eq!(qualification.external_ids().len(), 1);
         assert_eq!(
-            qualification.external_ids()[0],
             (
-                "qualification-id".to_string(),
-                "QUAL-2019-PHD-12345".to_string()
-            )
+                qualification.external_ids()[0].id_type().as_str(),
+                qualification.external_ids()[0].value()
+            ),
+            ("qualification-id", "QUAL-2019-PHD-12345")
         );
         assert_eq!(
             qualification.url(),
@@ -248,22 +304,32 @@ mod tests {
 
         assert_eq!(qualification.external_ids().len(), 3);
         assert_eq!(
-            qualification.external_ids()[0],
-            ("student-id".to_string(), "STU-123456".to_string())
+            (
+                qualification.external_ids()[0].id_type().as_str(),
+                qualification.external_ids()[0].value()
+            ),
+            ("student-id", "STU-123456")
         );
         assert_eq!(
-            qualification.external_ids()[1],
-            ("certificate-number".to_string(), "CERT-789012".to_string())
+            (
+                qualification.external_ids()[1].id_type().as_str(),
+                qualification.external_ids()[1].value()
+            ),
+            ("certificate-number", "CERT-789012")
         );
         assert_eq!(
-            qualification.external_ids()[2],
-            ("diploma-id".to_string(), "DIP-345678".to_string())
+            (
+                qualification.external_ids()[2].id_type().as_str(),
+                qualification.external_ids()[2].value()
+            ),
+            ("diploma-id", "DIP-345678")
         );
     }
 
     #[test]
     fn test_debug_trait() {
         let qualification = Qualification {
+            metadata: ActivityMetadata::default(),
             organization: None,
             department_name: Some("Mathematics".to_string()),
             role_title: Some("Master of Science".to_string()),
@@ -282,12 +348,13 @@ mod tests {
     #[test]
     fn test_clone_trait() {
         let qualification = Qualification {
+            metadata: ActivityMetadata::default(),
             organization: None,
             department_name: Some("Computer Science".to_string()),
             role_title: Some("PhD".to_string()),
             start_date: None,
             end_date: None,
-            external_ids: vec![("id".to_string(), "123".to_string())],
+            external_ids: vec![ExternalId::new("id".into(), "123")],
             url: Some("https://example.edu".to_string()),
         };
 
@@ -335,11 +402,71 @@ mod tests {
         assert!(qualification.start_date().is_some());
         assert!(qualification.end_date().is_some());
         assert_eq!(
-            qualification.external_ids()[0],
             (
-                "certification-number".to_string(),
-                "CISSP-2020-98765".to_string()
-            )
+                qualification.external_ids()[0].id_type().as_str(),
+                qualification.external_ids()[0].value()
+            ),
+            ("certification-number", "CISSP-2020-98765")
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut qualification = Qualification::new();
+        qualification.set_organization(Some(Organization::new("Test Org")));
+        qualification.set_department_name(Some("Physics".to_string()));
+        qualification.set_role_title(Some("Fellow".to_string()));
+        qualification.set_end_date(Some(FuzzyDate::new(2025, Some(5), Some(31)).unwrap()));
+        qualification.set_external_ids(vec![ExternalId::new("qualification-id".into(), "ID-1")]);
+        qualification.set_url(Some("https://example.org/1".to_string()));
+
+        let j = qualification.to_json();
+        assert_eq!(j["organization"]["name"], "Test Org");
+        assert_eq!(j["department-name"], "Physics");
+        assert_eq!(j["role-title"], "Fellow");
+        assert!(j.get("start-date").is_none());
+        assert_eq!(j["end-date"]["day"]["value"], "31");
+        assert_eq!(
+            j["external-ids"]["external-id"][0]["external-id-value"],
+            "ID-1"
+        );
+        assert_eq!(j["url"]["value"], "https://example.org/1");
+
+        // What we send can be read back by the summary parser
+        let parsed = Qualification::new_from_json(&json!({ "qualification-summary": [j] }));
+        assert_eq!(parsed.role_title(), Some(&"Fellow".to_string()));
+        assert_eq!(parsed.url(), Some(&"https://example.org/1".to_string()));
+    }
+
+    #[test]
+    fn test_new_from_json_metadata() {
+        let j = json!({
+            "qualification-summary": [{
+                "put-code": 4242,
+                "visibility": "limited",
+                "last-modified-date": { "value": 1700000000000u64 },
+                "source": { "source-name": { "value": "Jane Doe" } }
+            }]
+        });
+        let metadata = Qualification::new_from_json(&j).metadata().clone();
+        assert_eq!(metadata.put_code(), Some(4242));
+        assert_eq!(metadata.visibility(), Some(&"limited".to_string()));
+        assert!(metadata.last_modified_date().is_some());
+        assert_eq!(
+            metadata.source().and_then(|s| s.name()),
+            Some(&"Jane Doe".to_string())
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut qualification = Qualification::new();
+        qualification.set_role_title(Some("Certified Engineer".to_string()));
+        qualification.set_end_date(Some(FuzzyDate::new(2021, None, None).unwrap()));
+        let serialized = serde_json::to_value(&qualification).unwrap();
+        assert_eq!(serialized["role_title"], "Certified Engineer");
+        let back: Qualification = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, qualification);
+    }
 }
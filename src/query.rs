@@ -0,0 +1,425 @@
+use std::fmt;
+
+/// Characters with a meaning in Lucene query syntax
+const SPECIAL_CHARS: &str = "+-&|!(){}[]^\"~*?:\\/";
+
+/// A Solr query for ORCID's search endpoints, rendered with proper escaping by `to_string()`
+///
+/// # Example
+///
+/// ```
+/// use orcid::Q;
+///
+/// let q = Q::field("family-name", "Smith")
+///     .and(Q::phrase("affiliation-org-name", "MIT").or(Q::field("affiliation-org-name", "Harvard")))
+///     .and(Q::phrase("affiliation-org-name", "MIT Lincoln Lab").not());
+/// assert_eq!(
+///     q.to_string(),
+///     "family-name:Smith AND (affiliation-org-name:\"MIT\" OR affiliation-org-name:Harvard) \
+///      AND NOT affiliation-org-name:\"MIT Lincoln Lab\""
+/// );
+/// assert_eq!(
+///     Q::range("profile-last-modified-date", Some("2024-01-01T00:00:00Z"), None).to_string(),
+///     "profile-last-modified-date:[2024-01-01T00:00:00Z TO *]"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Q {
+    /// `field:value`, with special characters escaped
+    Term { field: String, value: String },
+    /// `field:"some words"`
+    Phrase { field: String, text: String },
+    /// `field:pat*ern?`, where `*` and `?` are wildcards and everything else is escaped
+    Wildcard { field: String, pattern: String },
+    /// `field:term~distance`
+    Fuzzy {
+        field: String,
+        term: String,
+        distance: u8,
+    },
+    /// `field:[from TO to]`, or `{from TO to}` if exclusive; a missing bound is `*`
+    Range {
+        field: String,
+        from: Option<String>,
+        to: Option<String>,
+        inclusive: bool,
+    },
+    /// All of the queries
+    And(Vec<Q>),
+    /// Any of the queries
+    Or(Vec<Q>),
+    /// Not the query
+    Not(Box<Q>),
+    /// The query in parentheses
+    Group(Box<Q>),
+    /// `query^boost`
+    Boost(Box<Q>, f32),
+    /// Sent as is, without escaping
+    Raw(String),
+}
+
+impl Q {
+    /// Matches `value` in `field`. A value containing whitespace becomes a phrase.
+    pub fn field(field: &str, value: &str) -> Self {
+        if value.chars().any(char::is_whitespace) {
+            return Self::phrase(field, value);
+        }
+        Q::Term {
+            field: field.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Matches the words of `text` in order
+    pub fn phrase(field: &str, text: &str) -> Self {
+        Q::Phrase {
+            field: field.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    /// Matches `pattern`, where `*` is any number of characters and `?` is one character
+    pub fn wildcard(field: &str, pattern: &str) -> Self {
+        Q::Wildcard {
+            field: field.to_string(),
+            pattern: pattern.to_string(),
+        }
+    }
+
+    /// Matches terms within `distance` edits of `term`; Lucene allows at most 2
+    pub fn fuzzy(field: &str, term: &str, distance: u8) -> Self {
+        Q::Fuzzy {
+            field: field.to_string(),
+            term: term.to_string(),
+            distance: distance.min(2),
+        }
+    }
+
+    /// Matches values between `from` and `to`, both included; `None` is open-ended
+    pub fn range(field: &str, from: Option<&str>, to: Option<&str>) -> Self {
+        Q::Range {
+            field: field.to_string(),
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+            inclusive: true,
+        }
+    }
+
+    /// Like `range`, but with both bounds excluded
+    pub fn range_exclusive(field: &str, from: Option<&str>, to: Option<&str>) -> Self {
+        Q::Range {
+            field: field.to_string(),
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+            inclusive: false,
+        }
+    }
+
+    /// A query in Solr syntax, sent without escaping
+    pub fn raw(query: &str) -> Self {
+        Q::Raw(query.to_string())
+    }
+
+    /// Both this and `other`
+    pub fn and(self, other: Q) -> Self {
+        match self {
+            Q::And(mut queries) => {
+                queries.push(other);
+                Q::And(queries)
+            }
+            q => Q::And(vec![q, other]),
+        }
+    }
+
+    /// This or `other`
+    pub fn or(self, other: Q) -> Self {
+        match self {
+            Q::Or(mut queries) => {
+                queries.push(other);
+                Q::Or(queries)
+            }
+            q => Q::Or(vec![q, other]),
+        }
+    }
+
+    /// Everything this does not match
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Q::Not(Box::new(self))
+    }
+
+    /// This in parentheses. Rendering adds the parentheses precedence needs anyway,
+    /// so this is only for readability.
+    pub fn group(self) -> Self {
+        Q::Group(Box::new(self))
+    }
+
+    /// Weighs matches of this query by `boost` in the ranking
+    pub fn boost(self, boost: f32) -> Self {
+        Q::Boost(Box::new(self), boost)
+    }
+
+    /// All `queries`, or `None` if there are none
+    pub fn all(queries: impl IntoIterator<Item = Q>) -> Option<Self> {
+        queries.into_iter().reduce(Q::and)
+    }
+
+    /// Any of `queries`, or `None` if there are none
+    pub fn any(queries: impl IntoIterator<Item = Q>) -> Option<Self> {
+        queries.into_iter().reduce(Q::or)
+    }
+
+    /// True for queries that need parentheses when nested
+    fn is_compound(&self) -> bool {
+        match self {
+            Q::And(queries) | Q::Or(queries) => queries.len() > 1,
+            Q::Not(_) | Q::Raw(_) => true,
+            _ => false,
+        }
+    }
+
+    fn fmt_nested(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // A purely negative clause in parentheses matches nothing, so it subtracts
+        // from all documents instead
+        if let Q::Not(_) = self {
+            write!(f, "(*:* {})", self)
+        } else if self.is_compound() {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+
+    fn fmt_joined(queries: &[Q], operator: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, query) in queries.iter().enumerate() {
+            if i > 0 {
+                write!(f, " {} ", operator)?;
+            }
+            match query {
+                // `a AND NOT b` needs no parentheses
+                Q::Not(inner) if operator == "AND" => {
+                    write!(f, "NOT ")?;
+                    inner.fmt_nested(f)?;
+                }
+                _ if queries.len() == 1 => write!(f, "{}", query)?,
+                _ => query.fmt_nested(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Q {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Q::Term { field, value } => write!(f, "{}:{}", field, escape(value)),
+            Q::Phrase { field, text } => write!(f, "{}:{}", field, quote(text)),
+            Q::Wildcard { field, pattern } => {
+                let escaped: String = pattern
+                    .split_inclusive(['*', '?'])
+                    .map(|part| match part.strip_suffix(['*', '?']) {
+                        Some(literal) => escape(literal) + &part[literal.len()..],
+                        None => escape(part),
+                    })
+                    .collect();
+                write!(f, "{}:{}", field, escaped)
+            }
+            Q::Fuzzy {
+                field,
+                term,
+                distance,
+            } => write!(f, "{}:{}~{}", field, escape(term), distance),
+            Q::Range {
+                field,
+                from,
+                to,
+                inclusive,
+            } => {
+                let (open, close) = if *inclusive { ('[', ']') } else { ('{', '}') };
+                write!(
+                    f,
+                    "{}:{}{} TO {}{}",
+                    field,
+                    open,
+                    range_bound(from.as_deref()),
+                    range_bound(to.as_deref()),
+                    close
+                )
+            }
+            Q::And(queries) => Q::fmt_joined(queries, "AND", f),
+            Q::Or(queries) => Q::fmt_joined(queries, "OR", f),
+            Q::Not(query) => {
+                write!(f, "NOT ")?;
+                query.fmt_nested(f)
+            }
+            Q::Group(query) if matches!(**query, Q::Not(_)) => query.fmt_nested(f),
+            Q::Group(query) => write!(f, "({})", query),
+            Q::Boost(query, boost) => {
+                query.fmt_nested(f)?;
+                write!(f, "^{}", boost)
+            }
+            Q::Raw(query) => write!(f, "{}", query),
+        }
+    }
+}
+
+/// Escapes Lucene special characters and whitespace with backslashes
+pub fn escape(value: &str) -> String {
+    let mut ret = String::with_capacity(value.len());
+    for c in value.chars() {
+        if SPECIAL_CHARS.contains(c) || c.is_whitespace() {
+            ret.push('\\');
+        }
+        ret.push(c);
+    }
+    ret
+}
+
+/// `"text"`, with quotes and backslashes escaped
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A range bound as is if Solr reads it as one token, otherwise quoted
+fn range_bound(bound: Option<&str>) -> String {
+    match bound {
+        None | Some("*") => "*".to_string(),
+        Some(bound)
+            if bound.is_empty()
+                || bound
+                    .chars()
+                    .any(|c| c.is_whitespace() || "[]{}\"\\".contains(c)) =>
+        {
+            quote(bound)
+        }
+        Some(bound) => bound.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("simple"), "simple");
+        assert_eq!(escape("10.1038/nature12373"), "10.1038\\/nature12373");
+        assert_eq!(
+            escape("a:b (c) [d] \"e\""),
+            "a\\:b\\ \\(c\\)\\ \\[d\\]\\ \\\"e\\\""
+        );
+        assert_eq!(
+            escape("a&&b||!c^2~*?\\"),
+            "a\\&\\&b\\|\\|\\!c\\^2\\~\\*\\?\\\\"
+        );
+    }
+
+    #[test]
+    fn test_terms() {
+        assert_eq!(
+            Q::field("family-name", "O'Brien").to_string(),
+            "family-name:O'Brien"
+        );
+        assert_eq!(
+            Q::field("doi-self", "10.1234/a:b").to_string(),
+            "doi-self:10.1234\\/a\\:b"
+        );
+        assert_eq!(
+            Q::field("text", "climate change").to_string(),
+            "text:\"climate change\""
+        );
+        assert_eq!(
+            Q::phrase("text", "say \"hi\"").to_string(),
+            "text:\"say \\\"hi\\\"\""
+        );
+        assert_eq!(
+            Q::wildcard("family-name", "Sm?th* (x)").to_string(),
+            "family-name:Sm?th*\\ \\(x\\)"
+        );
+        assert_eq!(
+            Q::fuzzy("family-name", "Smyth", 1).to_string(),
+            "family-name:Smyth~1"
+        );
+        assert_eq!(
+            Q::fuzzy("family-name", "Smyth", 5).to_string(),
+            "family-name:Smyth~2"
+        );
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(
+            Q::range(
+                "profile-last-modified-date",
+                Some("2024-01-01T00:00:00Z"),
+                None
+            )
+            .to_string(),
+            "profile-last-modified-date:[2024-01-01T00:00:00Z TO *]"
+        );
+        assert_eq!(
+            Q::range_exclusive("year", Some("2000"), Some("2010")).to_string(),
+            "year:{2000 TO 2010}"
+        );
+        assert_eq!(
+            Q::range("family-name", Some("a b"), Some("*")).to_string(),
+            "family-name:[\"a b\" TO *]"
+        );
+    }
+
+    #[test]
+    fn test_boolean() {
+        let a = Q::field("a", "1");
+        let b = Q::field("b", "2");
+        let c = Q::field("c", "3");
+        assert_eq!(
+            a.clone().and(b.clone()).and(c.clone()).to_string(),
+            "a:1 AND b:2 AND c:3"
+        );
+        assert_eq!(
+            a.clone().and(b.clone().or(c.clone())).to_string(),
+            "a:1 AND (b:2 OR c:3)"
+        );
+        assert_eq!(
+            a.clone().and(b.clone()).or(c.clone()).to_string(),
+            "(a:1 AND b:2) OR c:3"
+        );
+        assert_eq!(
+            a.clone().and(b.clone().not()).to_string(),
+            "a:1 AND NOT b:2"
+        );
+        assert_eq!(
+            a.clone().or(b.clone().not()).to_string(),
+            "a:1 OR (*:* NOT b:2)"
+        );
+        assert_eq!(b.clone().not().not().to_string(), "NOT (*:* NOT b:2)");
+        assert_eq!(b.clone().not().group().to_string(), "(*:* NOT b:2)");
+        assert_eq!(b.clone().not().boost(2.0).to_string(), "(*:* NOT b:2)^2");
+        assert_eq!(
+            a.clone().or(b.clone()).not().to_string(),
+            "NOT (a:1 OR b:2)"
+        );
+        assert_eq!(a.clone().group().to_string(), "(a:1)");
+        assert_eq!(
+            a.clone().or(b.clone()).boost(2.5).to_string(),
+            "(a:1 OR b:2)^2.5"
+        );
+        assert_eq!(Q::phrase("t", "x y").boost(2.0).to_string(), "t:\"x y\"^2");
+        assert_eq!(
+            a.clone().and(Q::raw("x:1 OR y:2")).to_string(),
+            "a:1 AND (x:1 OR y:2)"
+        );
+    }
+
+    #[test]
+    fn test_all_any() {
+        assert_eq!(Q::all(vec![]), None);
+        assert_eq!(Q::all(vec![Q::field("a", "1")]).unwrap().to_string(), "a:1");
+        assert_eq!(
+            Q::any(vec![Q::field("a", "1"), Q::field("a", "2")])
+                .unwrap()
+                .to_string(),
+            "a:1 OR a:2"
+        );
+    }
+}
@@ -0,0 +1,1493 @@
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{DeserializeOwned, Error, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::cell::Cell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Parses ORCID JSON into a record type. Malformed parts are skipped or left empty,
+/// the same way the string-keyed parsers have always treated them.
+pub fn from_json<T: DeserializeOwned + Default>(j: &serde_json::Value) -> T {
+    T::deserialize(j).unwrap_or_default()
+}
+
+thread_local! {
+    /// Set while collecting diagnostics, so malformed parts fail instead of being skipped
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restores the previous `STRICT` value when dropped, also if parsing panics
+struct StrictGuard(bool);
+
+impl Drop for StrictGuard {
+    fn drop(&mut self) {
+        STRICT.with(|strict| strict.set(self.0));
+    }
+}
+
+/// Runs `f` with lenient parsing turned off
+pub(crate) fn strict<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = StrictGuard(STRICT.with(|strict| strict.replace(true)));
+    f()
+}
+
+fn is_strict() -> bool {
+    STRICT.with(Cell::get)
+}
+
+/// Deserializes an optional field or section, treating a malformed one as missing,
+/// so a mistyped field does not take the rest of its object with it
+fn lenient<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Lenient,
+{
+    if is_strict() {
+        return Option::<T>::deserialize(d);
+    }
+    T::deserialize_lenient(d)
+}
+
+/// Deserializes a list, skipping malformed items and treating `null` as empty
+fn lenient_vec<'de, D, T>(d: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Lenient,
+{
+    if is_strict() {
+        return Ok(Option::<Vec<T>>::deserialize(d)?.unwrap_or_default());
+    }
+    d.deserialize_any(ListVisitor(PhantomData))
+}
+
+/// A type that is dropped, rather than failing its parent, if ORCID sends it malformed
+trait Lenient: DeserializeOwned {
+    fn deserialize_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Self>, D::Error>;
+}
+
+/// Scalars are buffered, which is cheap, and dropped if they do not parse
+macro_rules! lenient_scalars {
+    ($($t:ty),* $(,)?) => {
+        $(impl Lenient for $t {
+            fn deserialize_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Self>, D::Error> {
+                let value = serde_json::Value::deserialize(d)?;
+                Ok(Self::deserialize(&value).ok())
+            }
+        })*
+    };
+}
+
+/// Objects are parsed in place, without buffering, and dropped if they are not objects.
+/// Their fields are lenient themselves, so a malformed field does not fail the object.
+macro_rules! lenient_objects {
+    ($($t:ty),* $(,)?) => {
+        $(impl Lenient for $t {
+            fn deserialize_lenient<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Self>, D::Error> {
+                d.deserialize_any(ObjectVisitor(PhantomData))
+            }
+        })*
+    };
+}
+
+/// An object, or `None` for any other value
+struct ObjectVisitor<T>(PhantomData<T>);
+
+impl<'de, T: DeserializeOwned> Visitor<'de> for ObjectVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(MapAccessDeserializer::new(map)).map(Some)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_any(self)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_any(self)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_bool<E: Error>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_i64<E: Error>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_u64<E: Error>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_f64<E: Error>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_str<E: Error>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_bytes<E: Error>(self, _: &[u8]) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+}
+
+/// The well-formed items of a list, or nothing for any other value
+struct ListVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Lenient> Visitor<'de> for ListVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut ret = Vec::new();
+        while let Some(ListItem(item)) = seq.next_element::<ListItem<T>>()? {
+            ret.extend(item);
+        }
+        Ok(ret)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+        Ok(Vec::new())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_any(self)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_any(self)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_bool<E: Error>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_i64<E: Error>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_u64<E: Error>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_f64<E: Error>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_str<E: Error>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_bytes<E: Error>(self, _: &[u8]) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+}
+
+/// A list item, `None` if malformed
+struct ListItem<T>(Option<T>);
+
+impl<'de, T: Lenient> Deserialize<'de> for ListItem<T> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        T::deserialize_lenient(d).map(ListItem)
+    }
+}
+
+lenient_scalars!(String, Text, bool, u64);
+lenient_objects!(
+    StringValue,
+    Timestamp,
+    OrcidIdentifier,
+    Source,
+    ExternalIds,
+    ExternalId,
+    TransientValue,
+    FuzzyDate,
+    Organization,
+    OrganizationAddress,
+    DisambiguatedOrganization,
+    Title,
+    TranslatedTitle,
+    Contributors,
+    Contributor,
+    ContributorAttributes,
+    Preferences,
+    History,
+    Person,
+    Name,
+    OtherNames,
+    OtherName,
+    Biography,
+    ResearcherUrls,
+    ResearcherUrl,
+    Emails,
+    Email,
+    Addresses,
+    Address,
+    Keywords,
+    Keyword,
+    PersonExternalIdentifiers,
+    PersonExternalIdentifier,
+    ActivitiesSummary,
+    Affiliations,
+    AffiliationGroup,
+    AffiliationSummaries,
+    AffiliationSummary,
+    Works,
+    WorkGroup,
+    WorkSummary,
+    Citation,
+    Fundings,
+    FundingGroup,
+    FundingSummary,
+    Amount,
+    PeerReviews,
+    PeerReviewGroup,
+    PeerReviewSubGroup,
+    PeerReviewSummary,
+    ResearchResources,
+    ResearchResourceGroup,
+    ResearchResourceSummary,
+    Proposal,
+    Hosts,
+    ResourceItem,
+);
+
+/// A string that ORCID sometimes sends as a number, e.g. a year or a display index
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Text(pub String);
+
+impl Text {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        const EXPECTED: &str = "a string or number";
+        match serde_json::Value::deserialize(d)? {
+            serde_json::Value::String(s) => Ok(Text(s)),
+            serde_json::Value::Number(n) => Ok(Text(n.to_string())),
+            serde_json::Value::Null => Err(D::Error::invalid_type(Unexpected::Unit, &EXPECTED)),
+            serde_json::Value::Bool(b) => {
+                Err(D::Error::invalid_type(Unexpected::Bool(b), &EXPECTED))
+            }
+            serde_json::Value::Array(_) => Err(D::Error::invalid_type(Unexpected::Seq, &EXPECTED)),
+            serde_json::Value::Object(_) => Err(D::Error::invalid_type(Unexpected::Map, &EXPECTED)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Text {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// A `{"value": ...}` wrapper
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct StringValue {
+    #[serde(deserialize_with = "lenient")]
+    pub value: Option<Text>,
+}
+
+impl StringValue {
+    pub fn as_str(&self) -> Option<&str> {
+        self.value.as_ref().map(Text::as_str)
+    }
+}
+
+/// The string in an optional `{"value": ...}` wrapper
+pub(crate) fn string_value(v: &Option<StringValue>) -> Option<String> {
+    v.as_ref()
+        .and_then(StringValue::as_str)
+        .map(|s| s.to_string())
+}
+
+/// Parses the string in an optional `{"value": ...}` wrapper, e.g. a year
+pub(crate) fn parse_value<T: FromStr>(v: &Option<StringValue>) -> Option<T> {
+    v.as_ref()
+        .and_then(StringValue::as_str)
+        .and_then(|s| s.parse().ok())
+}
+
+/// Milliseconds since the epoch, as `{"value": 1487783400000}`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct Timestamp {
+    #[serde(deserialize_with = "lenient")]
+    pub value: Option<u64>,
+}
+
+/// An `orcid-identifier`, `source-orcid`, `source-client-id` or `contributor-orcid`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct OrcidIdentifier {
+    #[serde(deserialize_with = "lenient")]
+    pub uri: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub host: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Source {
+    #[serde(deserialize_with = "lenient")]
+    pub source_orcid: Option<OrcidIdentifier>,
+    #[serde(deserialize_with = "lenient")]
+    pub source_client_id: Option<OrcidIdentifier>,
+    #[serde(deserialize_with = "lenient")]
+    pub source_name: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub assertion_origin_orcid: Option<OrcidIdentifier>,
+    #[serde(deserialize_with = "lenient")]
+    pub assertion_origin_client_id: Option<OrcidIdentifier>,
+    #[serde(deserialize_with = "lenient")]
+    pub assertion_origin_name: Option<StringValue>,
+}
+
+/// The bookkeeping fields shared by activity summaries and person items
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Metadata {
+    #[serde(deserialize_with = "lenient")]
+    pub created_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub source: Option<Source>,
+    #[serde(deserialize_with = "lenient")]
+    pub put_code: Option<u64>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub visibility: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub display_index: Option<Text>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ExternalIds {
+    #[serde(deserialize_with = "lenient_vec")]
+    pub external_id: Vec<ExternalId>,
+}
+
+impl ExternalIds {
+    /// The `(type, value)` pairs of IDs that have both
+    pub fn pairs(&self) -> Vec<(String, String)> {
+        self.external_id
+            .iter()
+            .filter_map(ExternalId::pair)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ExternalId {
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_value: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_normalized: Option<TransientValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_url: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_relationship: Option<String>,
+}
+
+impl ExternalId {
+    pub fn pair(&self) -> Option<(String, String)> {
+        match (&self.external_id_type, &self.external_id_value) {
+            (Some(id_type), Some(id_value)) => Some((id_type.to_owned(), id_value.to_owned())),
+            _ => None,
+        }
+    }
+}
+
+/// A value computed by ORCID, e.g. a normalized external ID
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct TransientValue {
+    #[serde(deserialize_with = "lenient")]
+    pub value: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub transient: Option<bool>,
+}
+
+/// A date where month and day are optional; values are strings like `"09"`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct FuzzyDate {
+    #[serde(deserialize_with = "lenient")]
+    pub year: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub month: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub day: Option<StringValue>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Organization {
+    #[serde(deserialize_with = "lenient")]
+    pub name: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub address: Option<OrganizationAddress>,
+    #[serde(deserialize_with = "lenient")]
+    pub disambiguated_organization: Option<DisambiguatedOrganization>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct OrganizationAddress {
+    #[serde(deserialize_with = "lenient")]
+    pub city: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub region: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct DisambiguatedOrganization {
+    #[serde(deserialize_with = "lenient")]
+    pub disambiguated_organization_identifier: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub disambiguation_source: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Title {
+    #[serde(deserialize_with = "lenient")]
+    pub title: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub subtitle: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub translated_title: Option<TranslatedTitle>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct TranslatedTitle {
+    #[serde(deserialize_with = "lenient")]
+    pub value: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub language_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Contributors {
+    #[serde(deserialize_with = "lenient_vec")]
+    pub contributor: Vec<Contributor>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Contributor {
+    #[serde(deserialize_with = "lenient")]
+    pub contributor_orcid: Option<OrcidIdentifier>,
+    #[serde(deserialize_with = "lenient")]
+    pub credit_name: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub contributor_email: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub contributor_attributes: Option<ContributorAttributes>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ContributorAttributes {
+    #[serde(deserialize_with = "lenient")]
+    pub contributor_sequence: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub contributor_role: Option<String>,
+}
+
+/// A full ORCID v3.0 record, as returned by `/{orcid}`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Record {
+    #[serde(deserialize_with = "lenient")]
+    pub orcid_identifier: Option<OrcidIdentifier>,
+    #[serde(deserialize_with = "lenient")]
+    pub preferences: Option<Preferences>,
+    #[serde(deserialize_with = "lenient")]
+    pub history: Option<History>,
+    #[serde(deserialize_with = "lenient")]
+    pub person: Option<Person>,
+    #[serde(deserialize_with = "lenient")]
+    pub activities_summary: Option<ActivitiesSummary>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct Preferences {
+    #[serde(deserialize_with = "lenient")]
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct History {
+    #[serde(deserialize_with = "lenient")]
+    pub creation_method: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub completion_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub submission_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub claimed: Option<bool>,
+    #[serde(deserialize_with = "lenient")]
+    pub source: Option<Source>,
+    #[serde(deserialize_with = "lenient")]
+    pub deactivation_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub verified_email: Option<bool>,
+    #[serde(deserialize_with = "lenient")]
+    pub verified_primary_email: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Person {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub name: Option<Name>,
+    #[serde(deserialize_with = "lenient")]
+    pub other_names: Option<OtherNames>,
+    #[serde(deserialize_with = "lenient")]
+    pub biography: Option<Biography>,
+    #[serde(deserialize_with = "lenient")]
+    pub researcher_urls: Option<ResearcherUrls>,
+    #[serde(deserialize_with = "lenient")]
+    pub emails: Option<Emails>,
+    #[serde(deserialize_with = "lenient")]
+    pub addresses: Option<Addresses>,
+    #[serde(deserialize_with = "lenient")]
+    pub keywords: Option<Keywords>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_identifiers: Option<PersonExternalIdentifiers>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Name {
+    #[serde(deserialize_with = "lenient")]
+    pub created_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub given_names: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub family_name: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub credit_name: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub source: Option<Source>,
+    #[serde(deserialize_with = "lenient")]
+    pub visibility: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct OtherNames {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub other_name: Vec<OtherName>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct OtherName {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Biography {
+    #[serde(deserialize_with = "lenient")]
+    pub created_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub content: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub visibility: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ResearcherUrls {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub researcher_url: Vec<ResearcherUrl>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ResearcherUrl {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub url_name: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Option<StringValue>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Emails {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub email: Vec<Email>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct Email {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub email: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub primary: Option<bool>,
+    #[serde(deserialize_with = "lenient")]
+    pub verified: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Addresses {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub address: Vec<Address>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct Address {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub country: Option<StringValue>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Keywords {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub keyword: Vec<Keyword>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct Keyword {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PersonExternalIdentifiers {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub external_identifier: Vec<PersonExternalIdentifier>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PersonExternalIdentifier {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_value: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_url: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_id_relationship: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ActivitiesSummary {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub distinctions: Option<Affiliations>,
+    #[serde(deserialize_with = "lenient")]
+    pub educations: Option<Affiliations>,
+    #[serde(deserialize_with = "lenient")]
+    pub employments: Option<Affiliations>,
+    #[serde(deserialize_with = "lenient")]
+    pub fundings: Option<Fundings>,
+    #[serde(deserialize_with = "lenient")]
+    pub invited_positions: Option<Affiliations>,
+    #[serde(deserialize_with = "lenient")]
+    pub memberships: Option<Affiliations>,
+    #[serde(deserialize_with = "lenient")]
+    pub peer_reviews: Option<PeerReviews>,
+    #[serde(deserialize_with = "lenient")]
+    pub qualifications: Option<Affiliations>,
+    #[serde(deserialize_with = "lenient")]
+    pub research_resources: Option<ResearchResources>,
+    #[serde(deserialize_with = "lenient")]
+    pub services: Option<Affiliations>,
+    #[serde(deserialize_with = "lenient")]
+    pub works: Option<Works>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+/// One of the affiliation sections, e.g. `employments`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Affiliations {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(alias = "group", deserialize_with = "lenient_vec")]
+    pub affiliation_group: Vec<AffiliationGroup>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct AffiliationGroup {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub summaries: Vec<AffiliationSummaries>,
+    /// Summaries listed directly in the group, as read by `Membership::new_from_json`
+    #[serde(deserialize_with = "lenient_vec")]
+    pub membership_summary: Vec<AffiliationSummary>,
+    /// Summaries listed directly in the group, as read by `Qualification::new_from_json`
+    #[serde(deserialize_with = "lenient_vec")]
+    pub qualification_summary: Vec<AffiliationSummary>,
+}
+
+impl AffiliationGroup {
+    /// Every summary in the group, whichever layout it uses
+    pub fn all_summaries(&self) -> Vec<&AffiliationSummary> {
+        self.summaries
+            .iter()
+            .filter_map(AffiliationSummaries::summary)
+            .chain(self.membership_summary.iter())
+            .chain(self.qualification_summary.iter())
+            .collect()
+    }
+}
+
+/// An entry of `summaries`; only the field for the section is set
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct AffiliationSummaries {
+    #[serde(deserialize_with = "lenient")]
+    pub distinction_summary: Option<AffiliationSummary>,
+    #[serde(deserialize_with = "lenient")]
+    pub education_summary: Option<AffiliationSummary>,
+    #[serde(deserialize_with = "lenient")]
+    pub employment_summary: Option<AffiliationSummary>,
+    #[serde(deserialize_with = "lenient")]
+    pub invited_position_summary: Option<AffiliationSummary>,
+    #[serde(deserialize_with = "lenient")]
+    pub membership_summary: Option<AffiliationSummary>,
+    #[serde(deserialize_with = "lenient")]
+    pub qualification_summary: Option<AffiliationSummary>,
+    #[serde(deserialize_with = "lenient")]
+    pub service_summary: Option<AffiliationSummary>,
+}
+
+impl AffiliationSummaries {
+    pub fn summary(&self) -> Option<&AffiliationSummary> {
+        self.distinction_summary
+            .as_ref()
+            .or(self.education_summary.as_ref())
+            .or(self.employment_summary.as_ref())
+            .or(self.invited_position_summary.as_ref())
+            .or(self.membership_summary.as_ref())
+            .or(self.qualification_summary.as_ref())
+            .or(self.service_summary.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct AffiliationSummary {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub department_name: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub role_title: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub start_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub end_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub organization: Option<Organization>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Works {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub group: Vec<WorkGroup>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct WorkGroup {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub work_summary: Vec<WorkSummary>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct WorkSummary {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub title: Option<Title>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Option<StringValue>,
+    #[serde(rename = "type", deserialize_with = "lenient")]
+    pub work_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub publication_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub journal_title: Option<StringValue>,
+}
+
+/// A full work, as returned by `/{orcid}/work/{put-code}`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Work {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub title: Option<Title>,
+    #[serde(deserialize_with = "lenient")]
+    pub journal_title: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub short_description: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub citation: Option<Citation>,
+    #[serde(rename = "type", deserialize_with = "lenient")]
+    pub work_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub publication_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub contributors: Option<Contributors>,
+    #[serde(deserialize_with = "lenient")]
+    pub language_code: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub country: Option<StringValue>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Citation {
+    #[serde(deserialize_with = "lenient")]
+    pub citation_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub citation_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Fundings {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub group: Vec<FundingGroup>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct FundingGroup {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub funding_summary: Vec<FundingSummary>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct FundingSummary {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub title: Option<Title>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Option<StringValue>,
+    #[serde(rename = "type", deserialize_with = "lenient")]
+    pub funding_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub organization_defined_type: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub short_description: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub amount: Option<Amount>,
+    #[serde(deserialize_with = "lenient")]
+    pub start_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub end_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub organization: Option<Organization>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Amount {
+    #[serde(deserialize_with = "lenient")]
+    pub value: Option<Text>,
+    #[serde(deserialize_with = "lenient")]
+    pub currency_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PeerReviews {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub group: Vec<PeerReviewGroup>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PeerReviewGroup {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub peer_review_group: Vec<PeerReviewSubGroup>,
+    /// Summaries listed directly in the group, as read by `PeerReview::new_from_json`
+    #[serde(deserialize_with = "lenient_vec")]
+    pub peer_review_summary: Vec<PeerReviewSummary>,
+}
+
+impl PeerReviewGroup {
+    /// Every summary in the group and its sub-groups
+    pub fn all_summaries(&self) -> Vec<&PeerReviewSummary> {
+        self.peer_review_summary
+            .iter()
+            .chain(
+                self.peer_review_group
+                    .iter()
+                    .flat_map(|sub_group| sub_group.peer_review_summary.iter()),
+            )
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PeerReviewSubGroup {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub peer_review_summary: Vec<PeerReviewSummary>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PeerReviewSummary {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(alias = "role", deserialize_with = "lenient")]
+    pub reviewer_role: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient")]
+    pub review_url: Option<StringValue>,
+    #[serde(deserialize_with = "lenient")]
+    pub review_type: Option<String>,
+    #[serde(alias = "review-completion-date", deserialize_with = "lenient")]
+    pub completion_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub review_group_id: Option<String>,
+    #[serde(alias = "organization", deserialize_with = "lenient")]
+    pub convening_organization: Option<Organization>,
+    #[serde(deserialize_with = "lenient")]
+    pub subject_external_identifier: Option<ExternalId>,
+    #[serde(deserialize_with = "lenient")]
+    pub subject_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub subject_name: Option<Title>,
+    #[serde(deserialize_with = "lenient")]
+    pub subject_url: Option<StringValue>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ResearchResources {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub group: Vec<ResearchResourceGroup>,
+    #[serde(deserialize_with = "lenient")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ResearchResourceGroup {
+    #[serde(deserialize_with = "lenient")]
+    pub last_modified_date: Option<Timestamp>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub research_resource_summary: Vec<ResearchResourceSummary>,
+}
+
+/// A research resource summary, or a full research resource with its items
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ResearchResourceSummary {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(deserialize_with = "lenient")]
+    pub proposal: Option<Proposal>,
+    #[serde(deserialize_with = "lenient_vec")]
+    pub resource_item: Vec<ResourceItem>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Proposal {
+    #[serde(deserialize_with = "lenient")]
+    pub title: Option<Title>,
+    #[serde(deserialize_with = "lenient")]
+    pub hosts: Option<Hosts>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient")]
+    pub start_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub end_date: Option<FuzzyDate>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Option<StringValue>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
+pub struct Hosts {
+    #[serde(deserialize_with = "lenient_vec")]
+    pub organization: Vec<Organization>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ResourceItem {
+    #[serde(deserialize_with = "lenient")]
+    pub resource_name: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub resource_type: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub hosts: Option<Hosts>,
+    #[serde(deserialize_with = "lenient")]
+    pub external_ids: Option<ExternalIds>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Option<StringValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn record_json() -> serde_json::Value {
+        json!({
+            "orcid-identifier": {
+                "uri": "https://orcid.org/0000-0002-1825-0097",
+                "path": "0000-0002-1825-0097",
+                "host": "orcid.org"
+            },
+            "preferences": { "locale": "en" },
+            "history": {
+                "creation-method": "MEMBER_REFERRED",
+                "submission-date": { "value": 1356566400000u64 },
+                "last-modified-date": { "value": 1700000000000u64 },
+                "claimed": true,
+                "verified-email": true
+            },
+            "person": {
+                "name": {
+                    "given-names": { "value": "Josiah" },
+                    "family-name": { "value": "Carberry" },
+                    "credit-name": null,
+                    "visibility": "public"
+                },
+                "other-names": { "other-name": [{ "content": "J. Carberry", "put-code": 1 }] },
+                "biography": { "content": "Psychoceramics", "visibility": "public" },
+                "researcher-urls": { "researcher-url": [] },
+                "emails": { "email": [{ "email": "j@example.com", "primary": true, "verified": true }] },
+                "addresses": { "address": [{ "country": { "value": "US" } }] },
+                "keywords": { "keyword": [{ "content": "ceramics" }] },
+                "external-identifiers": { "external-identifier": [{
+                    "external-id-type": "Scopus Author ID",
+                    "external-id-value": "123",
+                    "external-id-url": { "value": "https://www.scopus.com/123" },
+                    "external-id-relationship": "self"
+                }] }
+            },
+            "activities-summary": {
+                "employments": {
+                    "affiliation-group": [{
+                        "external-ids": { "external-id": [] },
+                        "summaries": [{
+                            "employment-summary": {
+                                "put-code": 10,
+                                "display-index": "1",
+                                "role-title": "Professor",
+                                "start-date": { "year": { "value": "2001" }, "month": null, "day": null },
+                                "organization": {
+                                    "name": "Brown University",
+                                    "address": { "city": "Providence", "region": "RI", "country": "US" },
+                                    "disambiguated-organization": {
+                                        "disambiguated-organization-identifier": "6752",
+                                        "disambiguation-source": "RINGGOLD"
+                                    }
+                                }
+                            }
+                        }]
+                    }]
+                },
+                "works": {
+                    "group": [{
+                        "external-ids": { "external-id": [{
+                            "external-id-type": "doi",
+                            "external-id-value": "10.5555/12345678",
+                            "external-id-normalized": { "value": "10.5555/12345678", "transient": true },
+                            "external-id-relationship": "self"
+                        }] },
+                        "work-summary": [{
+                            "put-code": 20,
+                            "created-date": { "value": 1487783400000u64 },
+                            "source": { "source-client-id": { "path": "APP-1" }, "source-name": { "value": "Crossref" } },
+                            "title": { "title": { "value": "Toward a Unified Theory" }, "subtitle": null },
+                            "type": "journal-article",
+                            "publication-date": { "year": { "value": "2008" }, "month": { "value": "08" } },
+                            "visibility": "public",
+                            "display-index": "0"
+                        }]
+                    }]
+                },
+                "peer-reviews": {
+                    "group": [{
+                        "peer-review-group": [{
+                            "peer-review-summary": [{
+                                "put-code": 30,
+                                "reviewer-role": "reviewer",
+                                "review-type": "review",
+                                "review-group-id": "issn:0953-1513",
+                                "convening-organization": { "name": "Journal" }
+                            }]
+                        }]
+                    }]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_record() {
+        let record: Record = from_json(&record_json());
+        assert_eq!(
+            record.orcid_identifier.unwrap().path.as_deref(),
+            Some("0000-0002-1825-0097")
+        );
+        let history = record.history.unwrap();
+        assert_eq!(history.claimed, Some(true));
+        assert_eq!(history.submission_date.unwrap().value, Some(1356566400000));
+
+        let person = record.person.unwrap();
+        let name = person.name.unwrap();
+        assert_eq!(
+            name.given_names.as_ref().and_then(StringValue::as_str),
+            Some("Josiah")
+        );
+        assert!(name.credit_name.is_none());
+        assert_eq!(person.emails.unwrap().email[0].primary, Some(true));
+        assert_eq!(
+            person.external_identifiers.unwrap().external_identifier[0]
+                .external_id_url
+                .as_ref()
+                .and_then(StringValue::as_str),
+            Some("https://www.scopus.com/123")
+        );
+
+        let activities = record.activities_summary.unwrap();
+        let employments = activities.employments.unwrap();
+        let summaries = employments.affiliation_group[0].all_summaries();
+        assert_eq!(summaries[0].role_title.as_deref(), Some("Professor"));
+        assert_eq!(summaries[0].metadata.put_code, Some(10));
+        assert_eq!(
+            summaries[0].metadata.display_index,
+            Some(Text("1".to_string()))
+        );
+
+        let works = activities.works.unwrap();
+        let work = &works.group[0].work_summary[0];
+        assert_eq!(work.work_type.as_deref(), Some("journal-article"));
+        assert_eq!(
+            work.metadata.source.as_ref().unwrap().source_client_id,
+            Some(OrcidIdentifier {
+                path: Some("APP-1".to_string()),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            works.group[0].external_ids.as_ref().unwrap().pairs(),
+            vec![("doi".to_string(), "10.5555/12345678".to_string())]
+        );
+
+        let peer_reviews = activities.peer_reviews.unwrap();
+        let reviews = peer_reviews.group[0].all_summaries();
+        assert_eq!(
+            reviews[0].review_group_id.as_deref(),
+            Some("issn:0953-1513")
+        );
+    }
+
+    #[test]
+    fn test_lenient() {
+        let j = json!({
+            "orcid-identifier": "not an object",
+            "person": {
+                "name": { "given-names": { "value": "Josiah" } },
+                "keywords": { "keyword": [{ "content": "ok" }, { "content": 42 }, null] }
+            },
+            "activities-summary": {
+                "works": { "group": null },
+                "fundings": { "group": [{ "funding-summary": [{ "amount": { "value": 5000 } }] }] }
+            }
+        });
+        let record: Record = from_json(&j);
+        // A malformed section is dropped, the rest is kept
+        assert!(record.orcid_identifier.is_none());
+        let person = record.person.unwrap();
+        assert!(person.name.is_some());
+        // A mistyped field is dropped, the rest of its item is kept
+        let keywords = person.keywords.unwrap().keyword;
+        assert_eq!(keywords.len(), 2);
+        assert_eq!(keywords[1].content, None);
+        let activities = record.activities_summary.unwrap();
+        assert!(activities.works.unwrap().group.is_empty());
+        let amount = activities.fundings.unwrap().group[0].funding_summary[0]
+            .amount
+            .clone()
+            .unwrap();
+        assert_eq!(amount.value, Some(Text("5000".to_string())));
+    }
+
+    #[test]
+    fn test_lenient_from_str() {
+        // Objects and lists are parsed in place, also by the streaming deserializer
+        let record: Record = serde_json::from_str(
+            r#"{
+                "person": {
+                    "name": [{ "given-names": { "value": "Josiah" } }],
+                    "biography": { "content": "A biography" },
+                    "keywords": { "keyword": { "content": "not a list" } },
+                    "other-names": { "other-name": [5, { "content": "Joe" }, "x"] }
+                }
+            }"#,
+        )
+        .unwrap();
+        let person = record.person.unwrap();
+        assert!(person.name.is_none());
+        assert_eq!(
+            person.biography.unwrap().content.as_deref(),
+            Some("A biography")
+        );
+        assert!(person.keywords.unwrap().keyword.is_empty());
+        let other_names = person.other_names.unwrap().other_name;
+        assert_eq!(other_names.len(), 1);
+        assert_eq!(other_names[0].content.as_deref(), Some("Joe"));
+    }
+
+    #[test]
+    fn test_strict_restored_after_panic() {
+        let result = std::panic::catch_unwind(|| strict(|| panic!("parser bug")));
+        assert!(result.is_err());
+        assert!(!is_strict());
+        assert!(strict(is_strict));
+        assert!(!is_strict());
+    }
+
+    #[test]
+    fn test_from_json_not_an_object() {
+        assert_eq!(from_json::<Record>(&json!(null)), Record::default());
+        assert_eq!(from_json::<Record>(&json!([1, 2])), Record::default());
+    }
+}
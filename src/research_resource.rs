@@ -0,0 +1,257 @@
+use crate::activity_group::GroupedActivity;
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::external_id::{self, ExternalId};
+use crate::fuzzy_date::FuzzyDate;
+use crate::organization::Organization;
+use crate::record;
+use serde_json;
+
+/// A resource provided under a research resource proposal, e.g. an instrument
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceItem {
+    name: Option<String>,
+    resource_type: Option<String>,
+    hosts: Vec<Organization>,
+    external_ids: Vec<ExternalId>,
+    url: Option<String>,
+}
+
+impl ResourceItem {
+    pub fn new_from_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::ResourceItem>(j))
+    }
+
+    // Getter methods
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    /// The resource type, `infrastructures`, `collections`, `equipment` or `services`
+    pub fn resource_type(&self) -> Option<&String> {
+        self.resource_type.as_ref()
+    }
+
+    pub fn hosts(&self) -> &Vec<Organization> {
+        &self.hosts
+    }
+
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
+        &self.external_ids
+    }
+
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+}
+
+/// Access to a research resource, such as time on a facility, granted to the researcher
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResearchResource {
+    metadata: ActivityMetadata,
+    proposal_title: Option<String>,
+    hosts: Vec<Organization>,
+    external_ids: Vec<ExternalId>,
+    start_date: Option<FuzzyDate>,
+    end_date: Option<FuzzyDate>,
+    url: Option<String>,
+    resource_items: Vec<ResourceItem>,
+}
+
+impl ResearchResource {
+    /// Parses a `research-resource-summary` object, or a full `research-resource`.
+    /// Only the latter includes resource items.
+    pub fn new_from_summary_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::ResearchResourceSummary>(j))
+    }
+
+    // Getter methods
+    pub fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+
+    pub fn proposal_title(&self) -> Option<&String> {
+        self.proposal_title.as_ref()
+    }
+
+    /// The organizations hosting the proposal
+    pub fn hosts(&self) -> &Vec<Organization> {
+        &self.hosts
+    }
+
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
+        &self.external_ids
+    }
+
+    pub fn start_date(&self) -> Option<&FuzzyDate> {
+        self.start_date.as_ref()
+    }
+
+    pub fn end_date(&self) -> Option<&FuzzyDate> {
+        self.end_date.as_ref()
+    }
+
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+
+    /// The resource items; empty when parsed from a summary
+    pub fn resource_items(&self) -> &Vec<ResourceItem> {
+        &self.resource_items
+    }
+}
+
+impl Activity for ResearchResource {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl GroupedActivity for ResearchResource {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId> {
+        &mut self.external_ids
+    }
+}
+
+impl From<&record::ResourceItem> for ResourceItem {
+    fn from(item: &record::ResourceItem) -> Self {
+        Self {
+            name: item.resource_name.clone(),
+            resource_type: item.resource_type.clone(),
+            hosts: hosts(&item.hosts),
+            external_ids: external_id::from_record_ids(&item.external_ids),
+            url: record::string_value(&item.url),
+        }
+    }
+}
+
+impl From<&record::ResearchResourceSummary> for ResearchResource {
+    fn from(summary: &record::ResearchResourceSummary) -> Self {
+        let proposal = summary.proposal.clone().unwrap_or_default();
+        Self {
+            metadata: ActivityMetadata::from(&summary.metadata),
+            proposal_title: proposal
+                .title
+                .as_ref()
+                .and_then(|t| record::string_value(&t.title)),
+            hosts: hosts(&proposal.hosts),
+            external_ids: external_id::from_record_ids(&proposal.external_ids),
+            start_date: proposal
+                .start_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            end_date: proposal
+                .end_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            url: record::string_value(&proposal.url),
+            resource_items: summary
+                .resource_item
+                .iter()
+                .map(ResourceItem::from)
+                .collect(),
+        }
+    }
+}
+
+/// The organizations of a `hosts` object
+fn hosts(hosts: &Option<record::Hosts>) -> Vec<Organization> {
+    hosts
+        .as_ref()
+        .map(|hosts| hosts.organization.iter().map(Organization::from).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_id::ExternalIdType;
+    use serde_json::json;
+
+    #[test]
+    fn test_new_from_summary_json() {
+        let j = json!({
+            "put-code": 7,
+            "proposal": {
+                "title": { "title": { "value": "Beam time for crystallography" } },
+                "hosts": { "organization": [
+                    { "name": "Synchrotron Facility", "address": { "city": "Grenoble", "country": "FR" } },
+                    { "name": "Partner Lab" }
+                ] },
+                "external-ids": { "external-id": [{
+                    "external-id-type": "proposal-id",
+                    "external-id-value": "P-123"
+                }] },
+                "start-date": { "year": { "value": "2022" } },
+                "end-date": { "year": { "value": "2023" } },
+                "url": { "value": "https://example.com/proposal/123" }
+            }
+        });
+        let resource = ResearchResource::new_from_summary_json(&j);
+        assert_eq!(resource.metadata().put_code(), Some(7));
+        assert_eq!(
+            resource.proposal_title(),
+            Some(&"Beam time for crystallography".to_string())
+        );
+        assert_eq!(resource.hosts().len(), 2);
+        assert_eq!(
+            resource.hosts()[0].name(),
+            Some(&"Synchrotron Facility".to_string())
+        );
+        assert_eq!(
+            resource
+                .external_ids()
+                .iter()
+                .map(|id| (id.id_type().as_str(), id.value()))
+                .collect::<Vec<_>>(),
+            vec![("proposal-id", "P-123")]
+        );
+        assert!(resource.start_date().is_some());
+        assert!(resource.end_date().is_some());
+        assert_eq!(
+            resource.url(),
+            Some(&"https://example.com/proposal/123".to_string())
+        );
+        assert!(resource.resource_items().is_empty());
+    }
+
+    #[test]
+    fn test_resource_items() {
+        let j = json!({
+            "proposal": { "title": { "title": { "value": "Proposal" } } },
+            "resource-item": [{
+                "resource-name": "Beamline ID23",
+                "resource-type": "infrastructures",
+                "hosts": { "organization": [{ "name": "Synchrotron Facility" }] },
+                "external-ids": { "external-id": [{
+                    "external-id-type": "rrid",
+                    "external-id-value": "RRID:SCR_0001"
+                }] },
+                "url": { "value": "https://example.com/id23" }
+            }]
+        });
+        let resource = ResearchResource::new_from_summary_json(&j);
+        let items = resource.resource_items();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name(), Some(&"Beamline ID23".to_string()));
+        assert_eq!(
+            items[0].resource_type(),
+            Some(&"infrastructures".to_string())
+        );
+        assert_eq!(items[0].hosts().len(), 1);
+        assert_eq!(items[0].external_ids()[0].id_type(), &ExternalIdType::Rrid);
+        assert_eq!(
+            items[0].url(),
+            Some(&"https://example.com/id23".to_string())
+        );
+    }
+
+    #[test]
+    fn test_new_from_summary_json_empty() {
+        let resource = ResearchResource::new_from_summary_json(&json!({}));
+        assert!(resource.proposal_title().is_none());
+        assert!(resource.hosts().is_empty());
+        assert!(resource.start_date().is_none());
+    }
+}
@@ -1,19 +1,27 @@
-use crate::date::Date;
+use crate::activity_group::GroupedActivity;
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::external_id::{self, ExternalId, ExternalIdRelationship, ExternalIdType};
+use crate::fuzzy_date::FuzzyDate;
 use crate::organization::Organization;
+use crate::record;
+use crate::utils::external_ids_to_json;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Role {
+    metadata: ActivityMetadata,
     department: Option<String>,
     title: Option<String>,
-    start_date: Option<Date>,
-    end_date: Option<Date>,
+    start_date: Option<FuzzyDate>,
+    end_date: Option<FuzzyDate>,
     organization: Option<Organization>,
-    external_ids: Vec<(String, String)>,
+    external_ids: Vec<ExternalId>,
 }
 
 impl Role {
     pub fn new() -> Self {
         Self {
+            metadata: ActivityMetadata::default(),
             department: None,
             title: None,
             start_date: None,
@@ -23,7 +31,16 @@ impl Role {
         }
     }
 
+    /// Parses a single affiliation summary, e.g. an `employment-summary` object
+    pub fn new_from_summary_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::AffiliationSummary>(j))
+    }
+
     // Getter methods
+    pub fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+
     pub fn department(&self) -> Option<&String> {
         self.department.as_ref()
     }
@@ -32,11 +49,11 @@ impl Role {
         self.title.as_ref()
     }
 
-    pub fn start_date(&self) -> Option<&Date> {
+    pub fn start_date(&self) -> Option<&FuzzyDate> {
         self.start_date.as_ref()
     }
 
-    pub fn end_date(&self) -> Option<&Date> {
+    pub fn end_date(&self) -> Option<&FuzzyDate> {
         self.end_date.as_ref()
     }
 
@@ -45,6 +62,10 @@ impl Role {
     }
 
     // Setter methods
+    pub fn set_metadata(&mut self, metadata: ActivityMetadata) {
+        self.metadata = metadata;
+    }
+
     pub fn set_department(&mut self, department: Option<String>) {
         self.department = department;
     }
@@ -53,11 +74,11 @@ impl Role {
         self.title = title;
     }
 
-    pub fn set_start_date(&mut self, start_date: Option<Date>) {
+    pub fn set_start_date(&mut self, start_date: Option<FuzzyDate>) {
         self.start_date = start_date;
     }
 
-    pub fn set_end_date(&mut self, end_date: Option<Date>) {
+    pub fn set_end_date(&mut self, end_date: Option<FuzzyDate>) {
         self.end_date = end_date;
     }
 
@@ -65,18 +86,39 @@ impl Role {
         self.organization = organization;
     }
 
-    pub fn external_ids(&self) -> &Vec<(String, String)> {
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
         &self.external_ids
     }
 
-    pub fn add_external_id(&mut self, id_type: &str, id_value: &str) {
-        self.external_ids
-            .push((id_type.to_string(), id_value.to_string()));
+    pub fn add_external_id(&mut self, id_type: impl Into<ExternalIdType>, id_value: &str) {
+        self.external_ids.push(
+            ExternalId::new(id_type.into(), id_value)
+                .with_relationship(ExternalIdRelationship::Self_),
+        );
     }
 
-    pub fn set_external_ids(&mut self, external_ids: Vec<(String, String)>) {
+    pub fn set_external_ids(&mut self, external_ids: Vec<ExternalId>) {
         self.external_ids = external_ids;
     }
+
+    /// Serializes to an ORCID v3.0 affiliation object, e.g. an `employment`
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut j = serde_json::json!({
+            "department-name": self.department,
+            "role-title": self.title,
+            "organization": self.organization.as_ref().map(|o| o.to_json()),
+        });
+        if let Some(ref start_date) = self.start_date {
+            j["start-date"] = start_date.to_json();
+        }
+        if let Some(ref end_date) = self.end_date {
+            j["end-date"] = end_date.to_json();
+        }
+        if !self.external_ids.is_empty() {
+            j["external-ids"] = external_ids_to_json(&self.external_ids);
+        }
+        j
+    }
 }
 
 impl Default for Role {
@@ -85,6 +127,38 @@ impl Default for Role {
     }
 }
 
+impl Activity for Role {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl GroupedActivity for Role {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId> {
+        &mut self.external_ids
+    }
+}
+
+impl From<&record::AffiliationSummary> for Role {
+    fn from(summary: &record::AffiliationSummary) -> Self {
+        Self {
+            metadata: ActivityMetadata::from(&summary.metadata),
+            department: summary.department_name.clone(),
+            title: summary.role_title.clone(),
+            start_date: summary
+                .start_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            end_date: summary
+                .end_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            organization: summary.organization.as_ref().map(Organization::from),
+            external_ids: external_id::from_record_ids(&summary.external_ids),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,12 +244,55 @@ mod tests {
 
         assert_eq!(role.external_ids().len(), 2);
         assert_eq!(
-            role.external_ids()[0],
-            ("grant_number".to_string(), "GR-2023-12345".to_string())
+            (
+                role.external_ids()[0].id_type().as_str(),
+                role.external_ids()[0].value()
+            ),
+            ("grant_number", "GR-2023-12345")
         );
         assert_eq!(
-            role.external_ids()[1],
-            ("project_id".to_string(), "PROJ-456".to_string())
+            (
+                role.external_ids()[1].id_type().as_str(),
+                role.external_ids()[1].value()
+            ),
+            ("project_id", "PROJ-456")
         );
     }
+
+    #[test]
+    fn test_to_json() {
+        let mut role = Role::new();
+        role.set_department(Some("Engineering".to_string()));
+        role.set_title(Some("Professor".to_string()));
+        role.set_start_date(Some(FuzzyDate::new(2020, Some(1), None).unwrap()));
+        role.set_organization(Some(Organization::new("Test University")));
+        role.add_external_id("grant_number", "GR-1");
+
+        let j = role.to_json();
+        assert_eq!(j["department-name"], "Engineering");
+        assert_eq!(j["role-title"], "Professor");
+        assert_eq!(j["start-date"]["year"]["value"], "2020");
+        assert_eq!(j["start-date"]["month"]["value"], "01");
+        assert!(j.get("end-date").is_none());
+        assert_eq!(j["organization"]["name"], "Test University");
+        assert_eq!(
+            j["external-ids"]["external-id"][0]["external-id-value"],
+            "GR-1"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut role = Role::new();
+        role.set_title(Some("Professor".to_string()));
+        role.set_start_date(Some(FuzzyDate::new(2020, Some(9), None).unwrap()));
+        role.set_organization(Some(Organization::new("Test University")));
+        role.add_external_id("grant_number", "GR-1");
+        let serialized = serde_json::to_value(&role).unwrap();
+        assert_eq!(serialized["title"], "Professor");
+        assert_eq!(serialized["start_date"]["year"], 2020);
+        let back: Role = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, role);
+    }
 }
@@ -0,0 +1,840 @@
+//! Offline lookups in a [ROR](https://ror.org) data dump.
+//!
+//! Load the JSON or CSV file of a ROR release (schema v2, unzipped) into a `RorIndex`,
+//! then resolve GRID and FundRef IDs to ROR, follow parent relationships, and match
+//! free-text organization names.
+//!
+//! ```no_run
+//! use orcid::ror::RorIndex;
+//!
+//! # fn example(author: &orcid::Author) -> orcid::Result<()> {
+//! let index = RorIndex::from_path("v1.55-2024-10-31-ror-data.json")?;
+//! for role in author.employment() {
+//!     if let Some(organization) = role.organization() {
+//!         if let Some(candidate) = index.best_match(organization, 0.7) {
+//!             println!("{} ({:.2})", candidate.organization().name(), candidate.score());
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use crate::org_identifier::OrgIdentifier;
+use crate::organization::Organization;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::Path;
+
+/// Words ignored when matching names
+const STOPWORDS: [&str; 20] = [
+    "the", "of", "and", "for", "at", "in", "a", "an", "de", "des", "du", "la", "le", "der", "die",
+    "das", "und", "y", "di", "del",
+];
+
+/// The most candidates `candidates()` returns
+const MAX_CANDIDATES: usize = 10;
+
+/// An organization from a ROR dump
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RorOrganization {
+    id: String,
+    name: String,
+    other_names: Vec<String>,
+    city: Option<String>,
+    country_code: Option<String>,
+    grid_ids: Vec<String>,
+    fundref_ids: Vec<String>,
+    parent_ids: Vec<String>,
+    child_ids: Vec<String>,
+    status: String,
+}
+
+impl RorOrganization {
+    // Getter methods
+    /// The bare ROR ID, e.g. `03yrm5c26`
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://ror.org/{}", self.id)
+    }
+
+    /// The display name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Labels in other languages, aliases and acronyms
+    pub fn other_names(&self) -> &Vec<String> {
+        &self.other_names
+    }
+
+    pub fn city(&self) -> Option<&String> {
+        self.city.as_ref()
+    }
+
+    /// The two-letter ISO 3166 country code
+    pub fn country_code(&self) -> Option<&String> {
+        self.country_code.as_ref()
+    }
+
+    pub fn grid_ids(&self) -> &Vec<String> {
+        &self.grid_ids
+    }
+
+    /// FundRef IDs without the `10.13039/` prefix
+    pub fn fundref_ids(&self) -> &Vec<String> {
+        &self.fundref_ids
+    }
+
+    /// Bare ROR IDs of the parent organizations
+    pub fn parent_ids(&self) -> &Vec<String> {
+        &self.parent_ids
+    }
+
+    /// Bare ROR IDs of the child organizations
+    pub fn child_ids(&self) -> &Vec<String> {
+        &self.child_ids
+    }
+
+    /// `active`, `inactive` or `withdrawn`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+
+    pub fn to_org_identifier(&self) -> OrgIdentifier {
+        OrgIdentifier::Ror(self.id.clone())
+    }
+
+    fn names(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.name).chain(self.other_names.iter())
+    }
+}
+
+/// A ROR organization that may be the same as an ORCID `Organization`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RorCandidate<'a> {
+    organization: &'a RorOrganization,
+    score: f64,
+}
+
+impl<'a> RorCandidate<'a> {
+    // Getter methods
+    pub fn organization(&self) -> &'a RorOrganization {
+        self.organization
+    }
+
+    /// Confidence between 0 and 1. 1 means the organization has a matching identifier,
+    /// or the same name, city and country.
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+/// Organizations of a ROR dump, indexed by ROR, GRID and FundRef ID, and by name
+#[derive(Debug, Clone, Default)]
+pub struct RorIndex {
+    organizations: Vec<RorOrganization>,
+    by_id: HashMap<String, usize>,
+    by_grid: HashMap<String, usize>,
+    by_fundref: HashMap<String, usize>,
+    by_token: HashMap<String, Vec<usize>>,
+}
+
+impl RorIndex {
+    /// Loads a dump, as CSV if the file name ends in `.csv` and as JSON otherwise
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = std::io::BufReader::new(std::fs::File::open(path)?);
+        let is_csv = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        if is_csv {
+            Self::from_csv_reader(file)
+        } else {
+            Self::from_json_reader(file)
+        }
+    }
+
+    /// Loads the JSON file of a ROR release, an array of v2 records
+    pub fn from_json_reader(reader: impl Read) -> Result<Self> {
+        let records: Vec<JsonRecord> = serde_json::from_reader(reader)?;
+        Ok(Self::new(
+            records
+                .into_iter()
+                .map(JsonRecord::into_organization)
+                .collect(),
+        ))
+    }
+
+    /// Loads the CSV file of a ROR release
+    pub fn from_csv_reader(reader: impl Read) -> Result<Self> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
+        let column = |name: &str| headers.iter().position(|h| h == name);
+        let columns = CsvColumns {
+            id: column("id").ok_or_else(|| {
+                csv::Error::from(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "ROR CSV has no id column",
+                ))
+            })?,
+            display_name: column("names.types.ror_display"),
+            labels: column("names.types.label"),
+            aliases: column("names.types.alias"),
+            acronyms: column("names.types.acronym"),
+            city: column("locations.geonames_details.name"),
+            country_code: column("locations.geonames_details.country_code"),
+            grid_ids: column("external_ids.type.grid.all"),
+            fundref_ids: column("external_ids.type.fundref.all"),
+            relationships: column("relationships"),
+            status: column("status"),
+        };
+        let mut organizations = Vec::new();
+        for row in reader.records() {
+            organizations.push(columns.organization(&row?));
+        }
+        Ok(Self::new(organizations))
+    }
+
+    /// Indexes `organizations`; those without a valid ROR ID and duplicates are dropped
+    pub fn new(organizations: Vec<RorOrganization>) -> Self {
+        let mut ret = Self::default();
+        for organization in organizations {
+            if organization.id.is_empty() || ret.by_id.contains_key(&organization.id) {
+                continue;
+            }
+            let pos = ret.organizations.len();
+            ret.by_id.insert(organization.id.clone(), pos);
+            for grid_id in &organization.grid_ids {
+                ret.by_grid.insert(grid_id.clone(), pos);
+            }
+            for fundref_id in &organization.fundref_ids {
+                ret.by_fundref.insert(fundref_id.clone(), pos);
+            }
+            let tokens: HashSet<String> =
+                organization.names().flat_map(|name| tokens(name)).collect();
+            for token in tokens {
+                ret.by_token.entry(token).or_default().push(pos);
+            }
+            ret.organizations.push(organization);
+        }
+        ret
+    }
+
+    pub fn organizations(&self) -> &Vec<RorOrganization> {
+        &self.organizations
+    }
+
+    pub fn len(&self) -> usize {
+        self.organizations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.organizations.is_empty()
+    }
+
+    /// Looks up a ROR ID, bare or as a URL
+    pub fn get(&self, ror_id: &str) -> Option<&RorOrganization> {
+        match OrgIdentifier::new("ROR", ror_id).ok()? {
+            OrgIdentifier::Ror(id) => self.by_id.get(&id).map(|pos| &self.organizations[*pos]),
+            _ => None,
+        }
+    }
+
+    /// The ROR organization of a GRID ID, e.g. `grid.5335.0`
+    pub fn resolve_grid(&self, grid_id: &str) -> Option<&RorOrganization> {
+        self.resolve(&OrgIdentifier::new("GRID", grid_id).ok()?)
+    }
+
+    /// The ROR organization of a ROR, GRID or FundRef identifier. ROR dumps do not
+    /// list Ringgold IDs or LEIs, so those never resolve.
+    pub fn resolve(&self, identifier: &OrgIdentifier) -> Option<&RorOrganization> {
+        let pos = match identifier {
+            OrgIdentifier::Ror(id) => self.by_id.get(id),
+            OrgIdentifier::Grid(id) => self.by_grid.get(id),
+            OrgIdentifier::Fundref(id) => self.by_fundref.get(id),
+            OrgIdentifier::Ringgold(_) | OrgIdentifier::Lei(_) | OrgIdentifier::Other { .. } => {
+                None
+            }
+        }?;
+        Some(&self.organizations[*pos])
+    }
+
+    /// The direct parents of an organization
+    pub fn parents(&self, organization: &RorOrganization) -> Vec<&RorOrganization> {
+        organization
+            .parent_ids
+            .iter()
+            .filter_map(|id| self.by_id.get(id))
+            .map(|pos| &self.organizations[*pos])
+            .collect()
+    }
+
+    /// Parents, grandparents and so on, nearest first, each once
+    pub fn ancestors(&self, organization: &RorOrganization) -> Vec<&RorOrganization> {
+        let mut seen = HashSet::from([organization.id.as_str()]);
+        let mut ret = Vec::new();
+        let mut queue = self.parents(organization);
+        while !queue.is_empty() {
+            let mut next = Vec::new();
+            for parent in queue {
+                if seen.insert(parent.id.as_str()) {
+                    ret.push(parent);
+                    next.extend(self.parents(parent));
+                }
+            }
+            queue = next;
+        }
+        ret
+    }
+
+    /// The topmost ancestor following first parents, e.g. the university of a department,
+    /// or the organization itself if it has no parent
+    pub fn root<'a>(&'a self, organization: &'a RorOrganization) -> &'a RorOrganization {
+        let mut seen = HashSet::from([organization.id.as_str()]);
+        let mut ret = organization;
+        while let Some(parent) = self.parents(ret).into_iter().next() {
+            if !seen.insert(parent.id.as_str()) {
+                break;
+            }
+            ret = parent;
+        }
+        ret
+    }
+
+    /// ROR organizations that may be `organization`, best first.
+    ///
+    /// If the organization has a ROR, GRID or FundRef identifier in the dump, that is
+    /// the only candidate, with score 1. Otherwise active organizations sharing a word
+    /// with its name are scored by name similarity (80%), the same city (10%) and the
+    /// same country (10%). A different country lowers the score.
+    pub fn candidates(&self, organization: &Organization) -> Vec<RorCandidate<'_>> {
+        if let Some(found) = organization
+            .disambiguated_organization()
+            .and_then(|identifier| self.resolve(identifier))
+        {
+            return vec![RorCandidate {
+                organization: found,
+                score: 1.0,
+            }];
+        }
+        let Some(name) = organization.name() else {
+            return vec![];
+        };
+        let query = normalize(name);
+        let positions: HashSet<usize> = tokens(name)
+            .iter()
+            .filter_map(|token| self.by_token.get(token))
+            .flatten()
+            .copied()
+            .collect();
+        let mut ret: Vec<RorCandidate> = positions
+            .into_iter()
+            .map(|pos| &self.organizations[pos])
+            .filter(|candidate| candidate.is_active())
+            .map(|candidate| RorCandidate {
+                organization: candidate,
+                score: score(&query, organization, candidate),
+            })
+            .filter(|candidate| candidate.score > 0.0)
+            .collect();
+        ret.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.organization.id.cmp(&b.organization.id))
+        });
+        ret.truncate(MAX_CANDIDATES);
+        ret
+    }
+
+    /// The best candidate scoring at least `min_score`
+    pub fn best_match(
+        &self,
+        organization: &Organization,
+        min_score: f64,
+    ) -> Option<RorCandidate<'_>> {
+        self.candidates(organization)
+            .into_iter()
+            .next()
+            .filter(|candidate| candidate.score >= min_score)
+    }
+}
+
+fn score(query: &str, organization: &Organization, candidate: &RorOrganization) -> f64 {
+    let name_score = candidate
+        .names()
+        .map(|name| similarity(query, &normalize(name)))
+        .fold(0.0, f64::max);
+    let mut ret = name_score * 0.8;
+    if let (Some(city), Some(candidate_city)) = (organization.city(), candidate.city()) {
+        if normalize(city) == normalize(candidate_city) {
+            ret += 0.1;
+        }
+    }
+    if let (Some(country), Some(candidate_country)) =
+        (organization.country(), candidate.country_code())
+    {
+        if country.eq_ignore_ascii_case(candidate_country) {
+            ret += 0.1;
+        } else {
+            ret -= 0.3;
+        }
+    }
+    ret.clamp(0.0, 1.0)
+}
+
+/// Lowercase words separated by single spaces, without punctuation and stopwords
+fn normalize(name: &str) -> String {
+    tokens(name).join(" ")
+}
+
+fn tokens(name: &str) -> Vec<String> {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty() && !STOPWORDS.contains(token))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Dice coefficient of the character bigrams of two normalized names
+fn similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let bigrams = |s: &str| -> Vec<(char, char)> {
+        let chars: Vec<char> = s.chars().collect();
+        chars.windows(2).map(|w| (w[0], w[1])).collect()
+    };
+    let a = bigrams(a);
+    let mut b = bigrams(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let total = a.len() + b.len();
+    let mut shared = 0;
+    for bigram in a {
+        if let Some(pos) = b.iter().position(|other| *other == bigram) {
+            b.swap_remove(pos);
+            shared += 1;
+        }
+    }
+    2.0 * shared as f64 / total as f64
+}
+
+/// The bare ROR ID of `https://ror.org/...`
+fn ror_id(id: &str) -> Option<String> {
+    match OrgIdentifier::new("ROR", id).ok()? {
+        OrgIdentifier::Ror(id) => Some(id),
+        _ => None,
+    }
+}
+
+/// The bare FundRef ID of `10.13039/...` or a plain number
+fn fundref_id(id: &str) -> Option<String> {
+    OrgIdentifier::new("FUNDREF", id)
+        .ok()
+        .map(|id| id.id().to_string())
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct JsonRecord {
+    id: String,
+    names: Vec<JsonName>,
+    locations: Vec<JsonLocation>,
+    external_ids: Vec<JsonExternalId>,
+    relationships: Vec<JsonRelationship>,
+    status: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct JsonName {
+    value: String,
+    types: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct JsonLocation {
+    geonames_details: JsonGeonamesDetails,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct JsonGeonamesDetails {
+    name: Option<String>,
+    country_code: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct JsonExternalId {
+    #[serde(rename = "type")]
+    id_type: String,
+    all: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct JsonRelationship {
+    #[serde(rename = "type")]
+    relationship_type: String,
+    id: String,
+}
+
+impl JsonRecord {
+    fn into_organization(self) -> RorOrganization {
+        let display = self
+            .names
+            .iter()
+            .position(|name| name.types.iter().any(|t| t == "ror_display"))
+            .unwrap_or(0);
+        let mut names: Vec<String> = self.names.into_iter().map(|name| name.value).collect();
+        let name = if names.is_empty() {
+            String::new()
+        } else {
+            names.remove(display)
+        };
+        let external_ids = |id_type: &str| -> Vec<String> {
+            self.external_ids
+                .iter()
+                .filter(|id| id.id_type == id_type)
+                .flat_map(|id| id.all.iter().cloned())
+                .collect()
+        };
+        let related = |relationship_type: &str| -> Vec<String> {
+            self.relationships
+                .iter()
+                .filter(|r| r.relationship_type.eq_ignore_ascii_case(relationship_type))
+                .filter_map(|r| ror_id(&r.id))
+                .collect()
+        };
+        let location = self.locations.first().map(|l| &l.geonames_details);
+        RorOrganization {
+            id: ror_id(&self.id).unwrap_or_default(),
+            name,
+            other_names: names,
+            city: location.and_then(|l| l.name.clone()),
+            country_code: location.and_then(|l| l.country_code.clone()),
+            grid_ids: external_ids("grid"),
+            fundref_ids: external_ids("fundref")
+                .iter()
+                .filter_map(|id| fundref_id(id))
+                .collect(),
+            parent_ids: related("parent"),
+            child_ids: related("child"),
+            status: self.status,
+        }
+    }
+}
+
+/// Positions of the columns of a ROR CSV release
+struct CsvColumns {
+    id: usize,
+    display_name: Option<usize>,
+    labels: Option<usize>,
+    aliases: Option<usize>,
+    acronyms: Option<usize>,
+    city: Option<usize>,
+    country_code: Option<usize>,
+    grid_ids: Option<usize>,
+    fundref_ids: Option<usize>,
+    relationships: Option<usize>,
+    status: Option<usize>,
+}
+
+impl CsvColumns {
+    fn organization(&self, row: &csv::StringRecord) -> RorOrganization {
+        let get = |column: Option<usize>| column.and_then(|c| row.get(c)).unwrap_or_default();
+        // Multiple values are separated by "; ", names may be prefixed by a language code
+        let list = |column: Option<usize>| -> Vec<String> {
+            get(column)
+                .split("; ")
+                .map(strip_language)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        let related = |relationship_type: &str| -> Vec<String> {
+            get(self.relationships)
+                .split("; ")
+                .filter_map(|relationship| relationship.split_once(": "))
+                .filter(|(t, _)| t.trim().eq_ignore_ascii_case(relationship_type))
+                .filter_map(|(_, id)| ror_id(id))
+                .collect()
+        };
+        let name = strip_language(get(self.display_name)).to_string();
+        let mut other_names = list(self.labels);
+        other_names.retain(|label| *label != name);
+        other_names.extend(list(self.aliases));
+        other_names.extend(list(self.acronyms));
+        RorOrganization {
+            id: ror_id(get(Some(self.id))).unwrap_or_default(),
+            name,
+            other_names,
+            city: list(self.city).into_iter().next(),
+            country_code: list(self.country_code).into_iter().next(),
+            grid_ids: list(self.grid_ids),
+            fundref_ids: list(self.fundref_ids)
+                .iter()
+                .filter_map(|id| fundref_id(id))
+                .collect(),
+            parent_ids: related("parent"),
+            child_ids: related("child"),
+            status: get(self.status).to_string(),
+        }
+    }
+}
+
+/// `Université de Cambridge` from `fr: Université de Cambridge`
+fn strip_language(value: &str) -> &str {
+    let value = value.trim();
+    match value.split_once(": ") {
+        Some((lang, rest))
+            if (2..=3).contains(&lang.len()) && lang.chars().all(|c| c.is_ascii_lowercase()) =>
+        {
+            rest
+        }
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::OrcidError;
+    use serde_json::json;
+
+    fn json_dump() -> serde_json::Value {
+        json!([
+            {
+                "id": "https://ror.org/013meh722",
+                "names": [
+                    { "value": "Cambridge University", "types": ["alias"] },
+                    { "value": "University of Cambridge", "types": ["ror_display", "label"], "lang": "en" }
+                ],
+                "locations": [{ "geonames_details": { "name": "Cambridge", "country_code": "GB" } }],
+                "external_ids": [
+                    { "type": "grid", "all": ["grid.5335.0"], "preferred": "grid.5335.0" },
+                    { "type": "fundref", "all": ["501100000735"], "preferred": null }
+                ],
+                "relationships": [
+                    { "type": "child", "id": "https://ror.org/05dxps055", "label": "Department of Physics" }
+                ],
+                "status": "active"
+            },
+            {
+                "id": "https://ror.org/05dxps055",
+                "names": [{ "value": "Department of Physics", "types": ["ror_display"] }],
+                "locations": [{ "geonames_details": { "name": "Cambridge", "country_code": "GB" } }],
+                "external_ids": [],
+                "relationships": [
+                    { "type": "parent", "id": "https://ror.org/013meh722", "label": "University of Cambridge" }
+                ],
+                "status": "active"
+            },
+            {
+                "id": "https://ror.org/03yrm5c26",
+                "names": [
+                    { "value": "Cambridge Institute", "types": ["ror_display"] },
+                    { "value": "CI", "types": ["acronym"] }
+                ],
+                "locations": [{ "geonames_details": { "name": "Cambridge", "country_code": "US" } }],
+                "external_ids": [{ "type": "grid", "all": ["grid.1.2"] }],
+                "relationships": [{ "type": "parent", "id": "https://ror.org/02mhbdp94" }],
+                "status": "active"
+            },
+            {
+                "id": "https://ror.org/02mhbdp94",
+                "names": [{ "value": "Old Cambridge College", "types": ["ror_display"] }],
+                "relationships": [{ "type": "parent", "id": "https://ror.org/03yrm5c26" }],
+                "status": "withdrawn"
+            }
+        ])
+    }
+
+    fn index() -> RorIndex {
+        RorIndex::from_json_reader(json_dump().to_string().as_bytes()).unwrap()
+    }
+
+    fn organization(name: &str, city: Option<&str>, country: Option<&str>) -> Organization {
+        let mut ret = Organization::new(name);
+        ret.set_city(city.map(str::to_string));
+        ret.set_country(country.map(str::to_string));
+        ret
+    }
+
+    #[test]
+    fn test_from_json() {
+        let index = index();
+        assert_eq!(index.len(), 4);
+        let cambridge = index.get("https://ror.org/013meh722").unwrap();
+        assert_eq!(cambridge.name(), "University of Cambridge");
+        assert_eq!(
+            cambridge.other_names(),
+            &vec!["Cambridge University".to_string()]
+        );
+        assert_eq!(cambridge.city(), Some(&"Cambridge".to_string()));
+        assert_eq!(cambridge.country_code(), Some(&"GB".to_string()));
+        assert_eq!(cambridge.fundref_ids(), &vec!["501100000735".to_string()]);
+        assert_eq!(cambridge.child_ids(), &vec!["05dxps055".to_string()]);
+        assert_eq!(cambridge.url(), "https://ror.org/013meh722");
+        assert!(index.get("013meh722").is_some());
+        assert!(index.get("013meh723").is_none());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let index = index();
+        assert_eq!(index.resolve_grid("grid.5335.0").unwrap().id(), "013meh722");
+        assert!(index.resolve_grid("grid.9.9").is_none());
+        let fundref = OrgIdentifier::new("FUNDREF", "10.13039/501100000735").unwrap();
+        assert_eq!(index.resolve(&fundref).unwrap().id(), "013meh722");
+        let ringgold = OrgIdentifier::Ringgold("2152".to_string());
+        assert!(index.resolve(&ringgold).is_none());
+    }
+
+    #[test]
+    fn test_parents() {
+        let index = index();
+        let physics = index.get("05dxps055").unwrap();
+        let parents = index.parents(physics);
+        assert_eq!(parents.len(), 1);
+        assert_eq!(parents[0].id(), "013meh722");
+        assert_eq!(index.root(physics).id(), "013meh722");
+        assert!(index.ancestors(index.get("013meh722").unwrap()).is_empty());
+
+        // Parent cycles end
+        let institute = index.get("03yrm5c26").unwrap();
+        let ancestors: Vec<&str> = index.ancestors(institute).iter().map(|o| o.id()).collect();
+        assert_eq!(ancestors, vec!["02mhbdp94"]);
+        assert_eq!(index.root(institute).id(), "02mhbdp94");
+    }
+
+    #[test]
+    fn test_candidates() {
+        let index = index();
+
+        let exact = organization("University of Cambridge", Some("Cambridge"), Some("GB"));
+        let best = index.best_match(&exact, 0.9).unwrap();
+        assert_eq!(best.organization().id(), "013meh722");
+        assert_eq!(best.score(), 1.0);
+
+        // Alias, with a typo and without location
+        let fuzzy = organization("Cambrige University", None, None);
+        let best = index.best_match(&fuzzy, 0.5).unwrap();
+        assert_eq!(best.organization().id(), "013meh722");
+        assert!(best.score() < 0.8);
+
+        // The country rules out the UK university
+        let us = organization("Cambridge", Some("Cambridge"), Some("US"));
+        assert_eq!(index.candidates(&us)[0].organization().id(), "03yrm5c26");
+
+        // Withdrawn organizations are not candidates
+        let withdrawn = organization("Old Cambridge College", None, None);
+        assert!(index
+            .candidates(&withdrawn)
+            .iter()
+            .all(|c| c.organization().id() != "02mhbdp94"));
+
+        assert!(index
+            .candidates(&organization("Max Planck", None, None))
+            .is_empty());
+        assert!(index.best_match(&fuzzy, 0.95).is_none());
+    }
+
+    #[test]
+    fn test_candidates_by_identifier() {
+        let index = index();
+        let mut org = organization("Some name ORCID has", None, None);
+        org.set_disambiguated_organization(Some(OrgIdentifier::Grid("grid.5335.0".to_string())));
+        let candidates = index.candidates(&org);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].organization().id(), "013meh722");
+        assert_eq!(candidates[0].score(), 1.0);
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let csv = "id,names.types.acronym,names.types.alias,names.types.label,names.types.ror_display,locations.geonames_details.name,locations.geonames_details.country_code,external_ids.type.fundref.all,external_ids.type.grid.all,relationships,status\n\
+            https://ror.org/013meh722,,Cambridge University,en: University of Cambridge; fr: Université de Cambridge,en: University of Cambridge,Cambridge,GB,501100000735,grid.5335.0,child: https://ror.org/05dxps055,active\n\
+            https://ror.org/05dxps055,DP,,,Department of Physics,Cambridge,GB,,,parent: https://ror.org/013meh722,active\n";
+        let index = RorIndex::from_csv_reader(csv.as_bytes()).unwrap();
+        assert_eq!(index.len(), 2);
+        let cambridge = index.resolve_grid("grid.5335.0").unwrap();
+        assert_eq!(cambridge.name(), "University of Cambridge");
+        assert_eq!(
+            cambridge.other_names(),
+            &vec![
+                "Université de Cambridge".to_string(),
+                "Cambridge University".to_string()
+            ]
+        );
+        let physics = index.get("05dxps055").unwrap();
+        assert_eq!(physics.other_names(), &vec!["DP".to_string()]);
+        assert_eq!(index.root(physics).id(), "013meh722");
+        let fuzzy = organization("Universite de Cambridge", None, Some("GB"));
+        assert_eq!(
+            index.best_match(&fuzzy, 0.5).unwrap().organization().id(),
+            "013meh722"
+        );
+    }
+
+    #[test]
+    fn test_from_csv_malformed_row() {
+        match RorIndex::from_csv_reader(
+            "id,status\nhttps://ror.org/013meh722,active,extra\n".as_bytes(),
+        ) {
+            Err(OrcidError::CsvError(_)) => (),
+            _ => panic!("Expected CsvError"),
+        }
+    }
+
+    #[test]
+    fn test_from_csv_without_id_column() {
+        match RorIndex::from_csv_reader(
+            "name,status
+Cambridge,active
+"
+            .as_bytes(),
+        ) {
+            Err(OrcidError::CsvError(e)) => assert!(e.to_string().contains("no id column")),
+            _ => panic!("Expected CsvError"),
+        }
+    }
+
+    #[test]
+    fn test_from_path() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("orcid-ror-test-{}.json", std::process::id()));
+        std::fs::write(&path, json_dump().to_string()).unwrap();
+        let index = RorIndex::from_path(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(index.len(), 4);
+        match RorIndex::from_path(dir.join("does-not-exist.json")) {
+            Err(OrcidError::IoError(_)) => (),
+            _ => panic!("Expected IoError"),
+        }
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("cambridge", "cambridge"), 1.0);
+        assert_eq!(similarity("ab", "cd"), 0.0);
+        assert!(similarity("cambrige university", "cambridge university") > 0.8);
+        assert_eq!(
+            normalize("The University of  Cambridge!"),
+            "university cambridge"
+        );
+    }
+}
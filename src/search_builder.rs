@@ -1,5 +1,11 @@
 use crate::error::Result;
+use crate::expanded_search_result::ExpandedSearchResult;
+use crate::orcid_id::OrcidId;
+use crate::query::Q;
+use crate::search_query::SearchQuery;
+use crate::search_results::SearchResults;
 use crate::Client;
+use futures::stream::{self, BoxStream, StreamExt};
 
 #[derive(Debug, Clone)]
 pub struct SearchBuilder<'a> {
@@ -12,6 +18,7 @@ pub struct SearchBuilder<'a> {
     doi: Option<String>,
     eid: Option<String>,
     pmid: Option<String>,
+    queries: Vec<Q>,
     limit: Option<usize>,
     offset: Option<usize>,
 }
@@ -28,6 +35,7 @@ impl<'a> SearchBuilder<'a> {
             doi: None,
             eid: None,
             pmid: None,
+            queries: vec![],
             limit: None,
             offset: None,
         }
@@ -81,13 +89,19 @@ impl<'a> SearchBuilder<'a> {
         self
     }
 
-    /// Set the maximum number of results to return
+    /// Add a query built with `Q`, e.g. for OR, NOT and ranges
+    pub fn with_query(mut self, query: Q) -> Self {
+        self.queries.push(query);
+        self
+    }
+
+    /// Set the number of results per page, at most 1000
     pub fn limit(mut self, limit: usize) -> Self {
         self.limit = Some(limit);
         self
     }
 
-    /// Set the offset for pagination
+    /// Set the index of the first result; `offset` plus `limit` may not exceed 10,000
     pub fn offset(mut self, offset: usize) -> Self {
         self.offset = Some(offset);
         self
@@ -106,86 +120,86 @@ impl<'a> SearchBuilder<'a> {
         self.limit
     }
 
-    /// Build the search query string
+    /// Build the Solr query, without pagination. All criteria are ANDed.
     pub fn build_query(&self) -> String {
-        let mut parts = Vec::new();
-
-        if let Some(ref keyword) = self.keyword {
-            parts.push(format!("text:{}", quote_if_needed(keyword)));
-        }
-
-        if let Some(ref affiliation) = self.affiliation {
-            parts.push(format!(
-                "affiliation-org-name:{}",
-                quote_if_needed(affiliation)
-            ));
-        }
-
-        if let Some(ref given_names) = self.given_names {
-            parts.push(format!("given-names:{}", quote_if_needed(given_names)));
-        }
-
-        if let Some(ref family_name) = self.family_name {
-            parts.push(format!("family-name:{}", quote_if_needed(family_name)));
-        }
-
-        if let Some(ref orcid) = self.orcid {
-            parts.push(format!("orcid:{}", orcid));
-        }
+        let fields = [
+            ("text", &self.keyword),
+            ("affiliation-org-name", &self.affiliation),
+            ("given-names", &self.given_names),
+            ("family-name", &self.family_name),
+            ("orcid", &self.orcid),
+            ("doi-self", &self.doi),
+            ("eid", &self.eid),
+            ("pmid", &self.pmid),
+        ];
+        let parts = fields
+            .iter()
+            .filter_map(|(field, value)| value.as_deref().map(|value| Q::field(field, value)))
+            .chain(self.queries.iter().cloned());
+        Q::all(parts).map(|q| q.to_string()).unwrap_or_default()
+    }
 
-        if let Some(ref doi) = self.doi {
-            parts.push(format!("doi-self:{}", quote_if_needed(doi)));
-        }
+    /// Build the search request, checking ORCID's pagination limits
+    pub fn build(&self) -> Result<SearchQuery> {
+        let mut query = SearchQuery::new(&self.build_query());
+        query.set_rows(self.limit);
+        query.set_start(self.offset);
+        query.validate()?;
+        Ok(query)
+    }
 
-        if let Some(ref eid) = self.eid {
-            parts.push(format!("eid:{}", eid));
-        }
+    /// Execute the search and return a page of ORCID IDs with the total number of matches
+    pub async fn execute(&self) -> Result<SearchResults> {
+        self.client.search_query(&self.build()?).await
+    }
 
-        if let Some(ref pmid) = self.pmid {
-            parts.push(format!("pmid:{}", pmid));
+    /// A stream of the ORCID IDs of all pages, `limit` per page, starting at `offset`
+    pub fn stream(&self) -> BoxStream<'a, Result<OrcidId>> {
+        match self.build() {
+            Ok(query) => self.client.search_stream(query),
+            Err(e) => stream::once(async { Err(e) }).boxed(),
         }
+    }
 
-        let mut query = parts.join(" AND ");
-
-        // Add limit and offset as query parameters
-        let mut params = Vec::new();
-        if let Some(limit) = self.limit {
-            params.push(format!("rows={}", limit));
-        }
-        if let Some(offset) = self.offset {
-            params.push(format!("start={}", offset));
-        }
+    /// Switch to `expanded-search`, which returns names, emails and institutions
+    /// along with each ORCID ID
+    pub fn expanded(self) -> ExpandedSearchBuilder<'a> {
+        ExpandedSearchBuilder { builder: self }
+    }
+}
 
-        if !params.is_empty() {
-            if !query.is_empty() {
-                query = format!("{}&{}", query, params.join("&"));
-            } else {
-                query = params.join("&");
-            }
-        }
+/// A `SearchBuilder` in `expanded-search` mode
+#[derive(Debug, Clone)]
+pub struct ExpandedSearchBuilder<'a> {
+    builder: SearchBuilder<'a>,
+}
 
-        query
+impl<'a> ExpandedSearchBuilder<'a> {
+    /// Build the search request, checking ORCID's pagination limits
+    pub fn build(&self) -> Result<SearchQuery> {
+        self.builder.build()
     }
 
-    /// Execute the search and return ORCID IDs
-    pub async fn execute(&self) -> Result<Vec<String>> {
-        let query = self.build_query();
-        self.client.search(&query).await
+    /// Execute the search and return a page of results with the total number of matches
+    pub async fn execute(&self) -> Result<SearchResults<ExpandedSearchResult>> {
+        self.builder.client.expanded_search(&self.build()?).await
     }
-}
 
-/// Quote a string if it contains spaces or special characters
-fn quote_if_needed(s: &str) -> String {
-    if s.contains(' ') || s.contains('"') || s.contains(':') {
-        format!("\"{}\"", s.replace('"', "\\\""))
-    } else {
-        s.to_string()
+    /// A stream of the results of all pages, `limit` per page, starting at `offset`
+    pub fn stream(&self) -> BoxStream<'a, Result<ExpandedSearchResult>> {
+        match self.build() {
+            Ok(query) => self.builder.client.expanded_search_stream(query),
+            Err(e) => stream::once(async { Err(e) }).boxed(),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transport::FixtureTransport;
+    use serde_json::json;
+    use std::sync::Arc;
 
     #[test]
     fn test_search_builder_basic() {
@@ -238,10 +252,31 @@ mod tests {
             .limit(50)
             .offset(100);
 
-        let query = builder.build_query();
-        assert!(query.contains("text:physics"));
-        assert!(query.contains("rows=50"));
-        assert!(query.contains("start=100"));
+        assert_eq!(builder.build_query(), "text:physics");
+        let query = builder.build().unwrap();
+        assert_eq!(query.q(), "text:physics");
+        assert_eq!(query.rows(), Some(50));
+        assert_eq!(query.start(), Some(100));
+        assert_eq!(
+            query.to_query_string(),
+            "q=text%3Aphysics&rows=50&start=100"
+        );
+    }
+
+    #[test]
+    fn test_search_builder_limits() {
+        let client = Client::new();
+        assert!(SearchBuilder::new(&client).limit(1001).build().is_err());
+        assert!(SearchBuilder::new(&client)
+            .limit(1000)
+            .offset(9001)
+            .build()
+            .is_err());
+        assert!(SearchBuilder::new(&client)
+            .limit(1000)
+            .offset(9000)
+            .build()
+            .is_ok());
     }
 
     #[test]
@@ -250,15 +285,40 @@ mod tests {
         let builder = SearchBuilder::new(&client).with_doi("10.1038/nature12373");
 
         let query = builder.build_query();
-        assert_eq!(query, "doi-self:10.1038/nature12373");
+        assert_eq!(query, "doi-self:10.1038\\/nature12373");
+    }
+
+    #[test]
+    fn test_search_builder_escaping() {
+        let client = Client::new();
+        let query = |name: &str| {
+            SearchBuilder::new(&client)
+                .with_family_name(name)
+                .build_query()
+        };
+        assert_eq!(query("simple"), "family-name:simple");
+        assert_eq!(query("with space"), "family-name:\"with space\"");
+        assert_eq!(query("with:colon"), "family-name:with\\:colon");
+        assert_eq!(query("with\"quote"), "family-name:with\\\"quote");
+        assert_eq!(query("(a OR b)*"), "family-name:\"(a OR b)*\"");
     }
 
     #[test]
-    fn test_quote_if_needed() {
-        assert_eq!(quote_if_needed("simple"), "simple");
-        assert_eq!(quote_if_needed("with space"), "\"with space\"");
-        assert_eq!(quote_if_needed("with:colon"), "\"with:colon\"");
-        assert_eq!(quote_if_needed("with\"quote"), "\"with\\\"quote\"");
+    fn test_search_builder_with_query() {
+        let client = Client::new();
+        let query = SearchBuilder::new(&client)
+            .with_family_name("Smith")
+            .with_query(
+                Q::field("affiliation-org-name", "MIT")
+                    .or(Q::field("affiliation-org-name", "Harvard"))
+                    .and(Q::phrase("affiliation-org-name", "MIT Lincoln Lab").not()),
+            )
+            .build_query();
+        assert_eq!(
+            query,
+            "family-name:Smith AND ((affiliation-org-name:MIT OR affiliation-org-name:Harvard) \
+             AND NOT affiliation-org-name:\"MIT Lincoln Lab\")"
+        );
     }
 
     #[test]
@@ -281,12 +341,12 @@ mod tests {
         assert!(query.contains("affiliation-org-name:University"));
         assert!(query.contains("given-names:John"));
         assert!(query.contains("family-name:Doe"));
-        assert!(query.contains("orcid:0000-0001-2345-6789"));
-        assert!(query.contains("doi-self:10.1234/test"));
-        assert!(query.contains("eid:2-s2.0-12345"));
+        assert!(query.contains("orcid:0000\\-0001\\-2345\\-6789"));
+        assert!(query.contains("doi-self:10.1234\\/test"));
+        assert!(query.contains("eid:2\\-s2.0\\-12345"));
         assert!(query.contains("pmid:12345678"));
-        assert!(query.contains("rows=25"));
-        assert!(query.contains("start=50"));
+        assert!(!query.contains("rows="));
+        assert!(!query.contains("start="));
     }
 
     #[test]
@@ -296,22 +356,87 @@ mod tests {
             .with_keyword("climate change")
             .with_affiliation("Harvard")
             .limit(100)
-            .build_query();
+            .build()
+            .unwrap();
 
-        assert!(query.contains("text:\"climate change\""));
-        assert!(query.contains("affiliation-org-name:Harvard"));
-        assert!(query.contains("rows=100"));
+        assert!(query.q().contains("text:\"climate change\""));
+        assert!(query.q().contains("affiliation-org-name:Harvard"));
+        assert_eq!(query.rows(), Some(100));
     }
 
     #[tokio::test]
     async fn test_execute_invalid_orcid() {
-        let client = Client::new();
-        let result = SearchBuilder::new(&client)
+        let fixture = FixtureTransport::new().with_json(
+            "search?q=text%3Atest&rows=10&start=20",
+            json!({
+                "result": [{ "orcid-identifier": { "path": "0000-0001-5916-0947" } }],
+                "num-found": 21
+            }),
+        );
+        let client = Client::builder()
+            .transport(Arc::new(fixture))
+            .build()
+            .unwrap();
+        let results = SearchBuilder::new(&client)
             .with_keyword("test")
+            .limit(10)
+            .offset(20)
             .execute()
+            .await
+            .unwrap();
+        assert_eq!(results.num_found(), 21);
+        assert_eq!(results.orcid_ids()[0].as_str(), "0000-0001-5916-0947");
+    }
+
+    #[tokio::test]
+    async fn test_expanded() {
+        let fixture = FixtureTransport::new().with_json(
+            "expanded-search?q=family-name%3ACarberry&rows=5",
+            json!({
+                "expanded-result": [{
+                    "orcid-id": "0000-0002-1825-0097",
+                    "given-names": "Josiah",
+                    "family-names": "Carberry",
+                    "institution-name": ["Brown University"]
+                }],
+                "num-found": 1
+            }),
+        );
+        let client = Client::builder()
+            .transport(Arc::new(fixture))
+            .build()
+            .unwrap();
+        let builder = SearchBuilder::new(&client)
+            .with_family_name("Carberry")
+            .limit(5)
+            .expanded();
+        let results = builder.execute().await.unwrap();
+        assert_eq!(results.num_found(), 1);
+        assert_eq!(
+            results.items()[0].institution_names(),
+            &vec!["Brown University".to_string()]
+        );
+        let streamed: Vec<Result<ExpandedSearchResult>> = builder.stream().collect().await;
+        assert_eq!(streamed.len(), 1);
+        assert_eq!(
+            streamed[0].as_ref().unwrap().display_name(),
+            Some("Josiah Carberry".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_invalid_limit() {
+        let client = Client::new();
+        let results: Vec<Result<OrcidId>> = SearchBuilder::new(&client)
+            .with_keyword("test")
+            .limit(5000)
+            .stream()
+            .collect()
             .await;
-        // We don't assert success/failure as it would make a real API call,
-        // but we verify the method is callable and returns the right type
-        let _: Result<Vec<String>> = result;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(crate::OrcidError::InvalidSearch(_))
+        ));
     }
 }
@@ -0,0 +1,215 @@
+use crate::error::{OrcidError, Result};
+use crate::search_results::SearchResults;
+
+/// The most rows ORCID returns per page
+pub const MAX_ROWS: usize = 1000;
+
+/// The most results ORCID returns for a query, across all pages
+pub const MAX_RESULTS: usize = 10_000;
+
+/// Rows per page if none are requested
+pub const DEFAULT_ROWS: usize = 100;
+
+/// A search request: a Solr query and the page to return
+///
+/// # Example
+///
+/// ```
+/// use orcid::SearchQuery;
+///
+/// let query = SearchQuery::new("family-name:Smith").with_rows(50).with_start(100);
+/// assert_eq!(query.to_query_string(), "q=family-name%3ASmith&rows=50&start=100");
+/// assert!(SearchQuery::new("x").with_rows(2000).validate().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchQuery {
+    q: String,
+    rows: Option<usize>,
+    start: Option<usize>,
+}
+
+impl SearchQuery {
+    pub fn new(q: &str) -> Self {
+        Self {
+            q: q.to_string(),
+            rows: None,
+            start: None,
+        }
+    }
+
+    /// Results per page, at most `MAX_ROWS`
+    pub fn with_rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    /// Index of the first result, starting at 0
+    pub fn with_start(mut self, start: usize) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    // Getter methods
+    pub fn q(&self) -> &str {
+        &self.q
+    }
+
+    pub fn rows(&self) -> Option<usize> {
+        self.rows
+    }
+
+    pub fn start(&self) -> Option<usize> {
+        self.start
+    }
+
+    // Setter methods
+    pub fn set_rows(&mut self, rows: Option<usize>) {
+        self.rows = rows;
+    }
+
+    pub fn set_start(&mut self, start: Option<usize>) {
+        self.start = start;
+    }
+
+    /// Checks ORCID's limits: at most `MAX_ROWS` rows, and no page past the first
+    /// `MAX_RESULTS` results
+    pub fn validate(&self) -> Result<()> {
+        let rows = self.rows.unwrap_or(DEFAULT_ROWS);
+        let start = self.start.unwrap_or(0);
+        if rows > MAX_ROWS {
+            return Err(OrcidError::InvalidSearch(format!(
+                "rows {} exceeds {}",
+                rows, MAX_ROWS
+            )));
+        }
+        if start + rows > MAX_RESULTS {
+            return Err(OrcidError::InvalidSearch(format!(
+                "start {} plus rows {} exceeds {}",
+                start, rows, MAX_RESULTS
+            )));
+        }
+        Ok(())
+    }
+
+    /// The query for the page after `results`, or `None` if `results` was the last page.
+    /// The last page is shortened so it ends at `MAX_RESULTS`. Hits that could not be
+    /// parsed count too, so no page is fetched twice.
+    pub fn next_page<T>(&self, results: &SearchResults<T>) -> Option<SearchQuery> {
+        self.page_after(results.returned(), results.num_found())
+    }
+
+    /// The query for the page after one that returned `returned` of `num_found` results
+    pub(crate) fn page_after(&self, returned: usize, num_found: usize) -> Option<SearchQuery> {
+        let start = self.start.unwrap_or(0) + returned;
+        if returned == 0 || start >= num_found || start >= MAX_RESULTS {
+            return None;
+        }
+        let rows = self.rows.unwrap_or(DEFAULT_ROWS).min(MAX_RESULTS - start);
+        Some(self.clone().with_rows(rows).with_start(start))
+    }
+
+    /// The URL query, e.g. `q=text%3Aclimate&rows=50`
+    pub fn to_query_string(&self) -> String {
+        let mut ret = format!("q={}", urlencoding::encode(&self.q));
+        if let Some(rows) = self.rows {
+            ret += &format!("&rows={}", rows);
+        }
+        if let Some(start) = self.start {
+            ret += &format!("&start={}", start);
+        }
+        ret
+    }
+}
+
+impl From<&str> for SearchQuery {
+    fn from(q: &str) -> Self {
+        Self::new(q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_query_string() {
+        assert_eq!(SearchQuery::new("").to_query_string(), "q=");
+        assert_eq!(
+            SearchQuery::new("text:\"climate change\"").to_query_string(),
+            "q=text%3A%22climate%20change%22"
+        );
+        // Pagination stays out of the Solr query
+        let query = SearchQuery::new("a&rows=1").with_start(20);
+        assert_eq!(query.to_query_string(), "q=a%26rows%3D1&start=20");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(SearchQuery::new("x").validate().is_ok());
+        assert!(SearchQuery::new("x").with_rows(MAX_ROWS).validate().is_ok());
+        assert!(SearchQuery::new("x")
+            .with_rows(1000)
+            .with_start(9000)
+            .validate()
+            .is_ok());
+        match SearchQuery::new("x").with_rows(1001).validate() {
+            Err(OrcidError::InvalidSearch(message)) => {
+                assert_eq!(message, "rows 1001 exceeds 1000")
+            }
+            _ => panic!("Expected InvalidSearch"),
+        }
+        assert!(SearchQuery::new("x")
+            .with_rows(1000)
+            .with_start(9001)
+            .validate()
+            .is_err());
+        // The default page size counts too
+        assert!(SearchQuery::new("x").with_start(9950).validate().is_err());
+    }
+
+    fn results(num_found: usize, len: usize) -> SearchResults {
+        let result: Vec<serde_json::Value> = (0..len)
+            .map(|_| serde_json::json!({ "orcid-identifier": { "path": "0000-0002-1825-0097" } }))
+            .collect();
+        SearchResults::new_from_json(&serde_json::json!({
+            "result": result,
+            "num-found": num_found
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_next_page() {
+        let query = SearchQuery::new("x").with_rows(2);
+        let next = query.next_page(&results(5, 2)).unwrap();
+        assert_eq!(next, SearchQuery::new("x").with_rows(2).with_start(2));
+        let last = next.with_start(4);
+        assert!(last.next_page(&results(5, 1)).is_none());
+        assert!(query.next_page(&results(5, 0)).is_none());
+
+        // Without rows, pages are DEFAULT_ROWS long
+        let next = SearchQuery::new("x").next_page(&results(500, 100)).unwrap();
+        assert_eq!((next.rows(), next.start()), (Some(100), Some(100)));
+
+        // No page past MAX_RESULTS
+        let query = SearchQuery::new("x").with_rows(1000).with_start(8500);
+        let next = query.next_page(&results(50_000, 1000)).unwrap();
+        assert_eq!((next.rows(), next.start()), (Some(500), Some(9500)));
+        assert!(next.validate().is_ok());
+        assert!(next.next_page(&results(50_000, 500)).is_none());
+
+        // Unparseable hits are skipped, but still advance the next page
+        let results: SearchResults = SearchResults::new_from_json(&serde_json::json!({
+            "result": [{ "orcid-identifier": null }, { "orcid-identifier": null }],
+            "num-found": 5
+        }))
+        .unwrap();
+        assert!(results.is_empty());
+        let next = SearchQuery::new("x")
+            .with_rows(2)
+            .next_page(&results)
+            .unwrap();
+        assert_eq!(next.start(), Some(2));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::client_blocking::ClientBlocking;
+use crate::error::{OrcidError, Result};
+use crate::orcid_id::OrcidId;
+use crate::search_query::SearchQuery;
+
+/// An item of a search response: an `OrcidId` for `search`, an `ExpandedSearchResult` for
+/// `expanded-search`
+pub trait SearchHit: Sized {
+    /// The API path, e.g. `search`
+    const ENDPOINT: &'static str;
+    /// The key of the result array in the response, e.g. `result`
+    const RESULT_KEY: &'static str;
+
+    /// Parses one element of the result array; `None` skips it, e.g. without a valid ORCID iD
+    fn from_search_json(j: &serde_json::Value) -> Option<Self>;
+}
+
+impl SearchHit for OrcidId {
+    const ENDPOINT: &'static str = "search";
+    const RESULT_KEY: &'static str = "result";
+
+    fn from_search_json(j: &serde_json::Value) -> Option<Self> {
+        j["orcid-identifier"]["path"].as_str()?.parse().ok()
+    }
+}
+
+/// A page of search results
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchResults<T = OrcidId> {
+    num_found: usize,
+    returned: usize,
+    items: Vec<T>,
+}
+
+impl<T: SearchHit> SearchResults<T> {
+    /// Parses an ORCID v3.0 `search` or `expanded-search` response
+    pub fn new_from_json(j: &serde_json::Value) -> Result<Self> {
+        let num_found = j["num-found"].as_u64();
+        let result = &j[T::RESULT_KEY];
+        // ORCID sends a null result when nothing matches
+        let result = match (result.as_array(), num_found) {
+            (Some(result), _) => result.as_slice(),
+            (None, Some(_)) if result.is_null() => &[],
+            _ => return Err(OrcidError::BadApiResponse(j.to_owned())),
+        };
+        let items: Vec<T> = result.iter().filter_map(T::from_search_json).collect();
+        Ok(Self {
+            num_found: num_found.map_or(result.len(), |n| n as usize),
+            returned: result.len(),
+            items,
+        })
+    }
+}
+
+impl<T> SearchResults<T> {
+    // Getter methods
+    /// Total number of matches, of which this page is a part
+    pub fn num_found(&self) -> usize {
+        self.num_found
+    }
+
+    /// Number of hits in the response, including any that could not be parsed
+    /// and are missing from `items()`
+    pub fn returned(&self) -> usize {
+        self.returned
+    }
+
+    pub fn items(&self) -> &Vec<T> {
+        &self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl SearchResults<OrcidId> {
+    pub fn orcid_ids(&self) -> &Vec<OrcidId> {
+        &self.items
+    }
+
+    pub fn into_orcid_ids(self) -> Vec<OrcidId> {
+        self.items
+    }
+}
+
+impl<T> Default for SearchResults<T> {
+    fn default() -> Self {
+        Self {
+            num_found: 0,
+            returned: 0,
+            items: vec![],
+        }
+    }
+}
+
+/// The items of all pages of a search, fetched page by page as the iterator advances.
+/// Created by `ClientBlocking::search_iter` and `ClientBlocking::expanded_search_iter`.
+#[derive(Debug)]
+pub struct SearchIter<'a, T = OrcidId> {
+    client: &'a ClientBlocking,
+    next: Option<SearchQuery>,
+    page: std::vec::IntoIter<T>,
+    num_found: Option<usize>,
+}
+
+impl<'a, T: SearchHit> SearchIter<'a, T> {
+    pub(crate) fn new(client: &'a ClientBlocking, query: SearchQuery) -> Self {
+        Self {
+            client,
+            next: Some(query),
+            page: vec![].into_iter(),
+            num_found: None,
+        }
+    }
+
+    /// Total number of matches, once the first page has been fetched
+    pub fn num_found(&self) -> Option<usize> {
+        self.num_found
+    }
+}
+
+impl<T: SearchHit> Iterator for SearchIter<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.page.next() {
+                return Some(Ok(item));
+            }
+            let query = self.next.take()?;
+            match self.client.search_page::<T>(&query) {
+                Ok(results) => {
+                    self.num_found = Some(results.num_found());
+                    self.next = query.next_page(&results);
+                    self.page = results.into_items().into_iter();
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_new_from_json() {
+        let results: SearchResults = SearchResults::new_from_json(&json!({
+            "result": [
+                { "orcid-identifier": { "path": "0000-0001-5916-0947" } },
+                { "orcid-identifier": { "path": "0000-0002-1825-0097" } }
+            ],
+            "num-found": 1234
+        }))
+        .unwrap();
+        assert_eq!(results.num_found(), 1234);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.orcid_ids()[1].as_str(), "0000-0002-1825-0097");
+    }
+
+    #[test]
+    fn test_unparseable_hits() {
+        let results: SearchResults = SearchResults::new_from_json(&json!({
+            "result": [
+                { "orcid-identifier": { "path": "0000-0001-5916-0947" } },
+                { "orcid-identifier": null },
+                { "orcid-identifier": { "path": "0000-0001-5916-0948" } }
+            ],
+            "num-found": 10
+        }))
+        .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.returned(), 3);
+    }
+
+    #[test]
+    fn test_no_matches() {
+        let results: SearchResults =
+            SearchResults::new_from_json(&json!({ "result": null, "num-found": 0 })).unwrap();
+        assert!(results.is_empty());
+        assert_eq!(results.num_found(), 0);
+        assert!(SearchResults::<OrcidId>::new_from_json(&json!({ "unexpected": true })).is_err());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::funding::Funding;
+use crate::fuzzy_date::FuzzyDate;
+use crate::membership::Membership;
+use crate::organization::Organization;
+use crate::qualification::Qualification;
+use crate::role::Role;
+use std::ops::{Bound, RangeBounds};
+
+/// The activity behind a timeline entry
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimelineItem {
+    Employment(Role),
+    Education(Role),
+    InvitedPosition(Role),
+    Membership(Membership),
+    Qualification(Qualification),
+    Funding(Funding),
+}
+
+impl TimelineItem {
+    /// The employer, institution or funder
+    pub fn organization(&self) -> Option<&Organization> {
+        match self {
+            TimelineItem::Employment(role)
+            | TimelineItem::Education(role)
+            | TimelineItem::InvitedPosition(role) => role.organization(),
+            TimelineItem::Membership(membership) => membership.organization(),
+            TimelineItem::Qualification(qualification) => qualification.organization(),
+            TimelineItem::Funding(funding) => funding.organization(),
+        }
+    }
+}
+
+/// An activity with the interval it covers.
+///
+/// Partial dates cover their whole year or month, so an entry ending in `2021`
+/// is active until 2021-12-31. A missing start or end date leaves the interval
+/// open on that side.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimelineEntry {
+    start: Option<FuzzyDate>,
+    end: Option<FuzzyDate>,
+    item: TimelineItem,
+}
+
+impl TimelineEntry {
+    pub fn new(start: Option<FuzzyDate>, end: Option<FuzzyDate>, item: TimelineItem) -> Self {
+        Self { start, end, item }
+    }
+
+    // Getter methods
+    pub fn start(&self) -> Option<&FuzzyDate> {
+        self.start.as_ref()
+    }
+
+    pub fn end(&self) -> Option<&FuzzyDate> {
+        self.end.as_ref()
+    }
+
+    pub fn item(&self) -> &TimelineItem {
+        &self.item
+    }
+
+    /// True if the entry has no end date
+    pub fn is_ongoing(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// True if the entry shares at least one day with `range`
+    pub fn overlaps(&self, range: &impl RangeBounds<FuzzyDate>) -> bool {
+        let first_day = self.start.map(|d| d.first_day());
+        let last_day = self.end.map(|d| d.last_day());
+        let after_range_start = match (range.start_bound(), last_day) {
+            (Bound::Unbounded, _) | (_, None) => true,
+            (Bound::Included(start), Some(last_day)) => last_day >= start.first_day(),
+            (Bound::Excluded(start), Some(last_day)) => last_day > start.last_day(),
+        };
+        let before_range_end = match (range.end_bound(), first_day) {
+            (Bound::Unbounded, _) | (_, None) => true,
+            (Bound::Included(end), Some(first_day)) => first_day <= end.last_day(),
+            (Bound::Excluded(end), Some(first_day)) => first_day < end.first_day(),
+        };
+        after_range_start && before_range_end
+    }
+}
+
+/// Affiliations and fundings of a researcher, sorted by start date.
+/// Entries without a start date come first.
+///
+/// # Example
+///
+/// ```
+/// use orcid::{Author, FuzzyDate};
+/// use serde_json::json;
+///
+/// let author = Author::new_from_json(json!({
+///     "activities-summary": { "employments": { "affiliation-group": [{
+///         "summaries": [{ "employment-summary": {
+///             "organization": { "name": "Example University" },
+///             "start-date": { "year": { "value": "2015" } },
+///             "end-date": { "year": { "value": "2021" }, "month": { "value": "06" } }
+///         } }]
+///     }] } }
+/// }));
+/// let timeline = author.timeline();
+/// let date: FuzzyDate = "2021-06-30".parse().unwrap();
+/// assert_eq!(timeline.active_at(date).len(), 1);
+/// let date: FuzzyDate = "2021-07".parse().unwrap();
+/// assert!(timeline.active_at(date).is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timeline {
+    entries: Vec<TimelineEntry>,
+}
+
+impl Timeline {
+    /// Sorts `entries` chronologically
+    pub fn new(mut entries: Vec<TimelineEntry>) -> Self {
+        entries.sort_by_key(|entry| (entry.start.map(|d| d.first_day()), entry.start));
+        Self { entries }
+    }
+
+    // Getter methods
+    pub fn entries(&self) -> &Vec<TimelineEntry> {
+        &self.entries
+    }
+
+    pub fn into_entries(self) -> Vec<TimelineEntry> {
+        self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries active at any time during `date`; `2021` matches everything active in 2021
+    pub fn active_at(&self, date: FuzzyDate) -> Vec<&TimelineEntry> {
+        self.overlapping(date..=date)
+    }
+
+    /// Entries sharing at least one day with `range`, e.g. `start..=end` or `start..`
+    pub fn overlapping(&self, range: impl RangeBounds<FuzzyDate>) -> Vec<&TimelineEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.overlaps(&range))
+            .collect()
+    }
+
+    /// Entries active today
+    pub fn current(&self) -> Vec<&TimelineEntry> {
+        self.active_at(FuzzyDate::today())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> FuzzyDate {
+        s.parse().unwrap()
+    }
+
+    fn entry(start: Option<&str>, end: Option<&str>) -> TimelineEntry {
+        TimelineEntry::new(
+            start.map(date),
+            end.map(date),
+            TimelineItem::Employment(Role::new()),
+        )
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let e = entry(Some("2019-09"), Some("2021"));
+        assert!(e.overlaps(&(date("2019-09-01")..=date("2019-09-01"))));
+        assert!(!e.overlaps(&(date("2019-08-31")..=date("2019-08-31"))));
+        assert!(e.overlaps(&(date("2021-12-31")..)));
+        assert!(!e.overlaps(&(date("2022")..)));
+        assert!(e.overlaps(&(..date("2019-10"))));
+        assert!(!e.overlaps(&(..date("2019-09"))));
+        assert!(!e.overlaps(&(..=date("2019-08"))));
+        assert!(e.overlaps(&(date("2019")..=date("2019"))));
+        assert!(e.overlaps(&(date("2021")..date("2022"))));
+        assert!(!e.overlaps(&(date("2022")..date("2023"))));
+        assert!(e.overlaps(&(..)));
+    }
+
+    #[test]
+    fn test_open_ended() {
+        let ongoing = entry(Some("2020"), None);
+        assert!(ongoing.is_ongoing());
+        assert!(ongoing.overlaps(&(date("2999")..)));
+        assert!(!ongoing.overlaps(&(..=date("2019"))));
+
+        let no_start = entry(None, Some("2010"));
+        assert!(no_start.overlaps(&(..=date("1950"))));
+        assert!(!no_start.overlaps(&(date("2011")..)));
+
+        let undated = entry(None, None);
+        assert!(undated.overlaps(&(date("2000")..=date("2000"))));
+    }
+
+    #[test]
+    fn test_timeline() {
+        let timeline = Timeline::new(vec![
+            entry(Some("2015-03"), Some("2018")),
+            entry(Some("2019-09-01"), None),
+            entry(None, Some("2012")),
+            entry(Some("2015"), Some("2015-06")),
+        ]);
+        let starts: Vec<Option<String>> = timeline
+            .entries()
+            .iter()
+            .map(|e| e.start().map(|d| d.to_string()))
+            .collect();
+        assert_eq!(
+            starts,
+            vec![
+                None,
+                Some("2015".to_string()),
+                Some("2015-03".to_string()),
+                Some("2019-09-01".to_string())
+            ]
+        );
+        assert_eq!(timeline.active_at(date("2015")).len(), 2);
+        assert_eq!(timeline.active_at(date("2015-07")).len(), 1);
+        assert_eq!(timeline.active_at(date("2019-08")).len(), 0);
+        assert_eq!(timeline.overlapping(date("2016")..=date("2020")).len(), 2);
+        assert_eq!(timeline.current().len(), 1);
+    }
+}
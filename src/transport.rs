@@ -0,0 +1,462 @@
+use crate::client_builder::ClientBuilder;
+use crate::error::{OrcidError, Result};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// A boxed future, as returned by `Transport::execute`
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// HTTP method of a `Request`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// An HTTP request, independent of the HTTP library that sends it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn new(method: Method, url: &str) -> Self {
+        Self {
+            method,
+            url: url.to_string(),
+            headers: vec![],
+            body: None,
+        }
+    }
+
+    pub fn get(url: &str) -> Self {
+        Self::new(Method::Get, url)
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn with_body(mut self, body: String) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Returns the first value of a header, matching the name case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// An HTTP response, independent of the HTTP library that received it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    pub fn new(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Returns the first value of a header, matching the name case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Parses the body as JSON. A body that is not JSON becomes an
+    /// `HttpError` for unsuccessful responses, and a `JsonError` otherwise.
+    pub fn json(&self) -> Result<serde_json::Value> {
+        match serde_json::from_str(&self.body) {
+            Ok(json) => Ok(json),
+            Err(_) if !self.is_success() => Err(OrcidError::HttpError {
+                status: self.status,
+                body: self.body.to_owned(),
+            }),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Adds an `Authorization: Bearer` header to a request
+pub(crate) fn with_bearer(request: Request, token: &str) -> Request {
+    request.with_header("Authorization", &format!("Bearer {}", token))
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Sends requests for the async `Client`
+pub trait Transport: fmt::Debug + Send + Sync {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>>;
+}
+
+/// Sends requests for `ClientBlocking`
+pub trait BlockingTransport: fmt::Debug + Send + Sync {
+    fn execute(&self, request: Request) -> Result<Response>;
+}
+
+/// The default async transport, backed by `reqwest`
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(reqwest_method(request.method), &request.url);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let headers = collect_headers(response.headers());
+            let body = response.text().await?;
+            Ok(Response {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// The default blocking transport, backed by `reqwest::blocking`
+#[derive(Debug, Clone)]
+pub struct ReqwestBlockingTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl ReqwestBlockingTransport {
+    pub fn new(client: reqwest::blocking::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl BlockingTransport for ReqwestBlockingTransport {
+    fn execute(&self, request: Request) -> Result<Response> {
+        let mut builder = self
+            .client
+            .request(reqwest_method(request.method), &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let response = builder.send()?;
+        let status = response.status().as_u16();
+        let headers = collect_headers(response.headers());
+        let body = response.text()?;
+        Ok(Response {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+fn reqwest_method(method: Method) -> reqwest::Method {
+    match method {
+        Method::Get => reqwest::Method::GET,
+        Method::Post => reqwest::Method::POST,
+        Method::Put => reqwest::Method::PUT,
+        Method::Delete => reqwest::Method::DELETE,
+    }
+}
+
+fn collect_headers(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|v| (name.as_str().to_string(), v.to_string()))
+        })
+        .collect()
+}
+
+/// Queued responses by method and URL
+type Fixtures = HashMap<(Method, String), VecDeque<Response>>;
+
+/// An in-memory transport that answers requests with canned responses.
+///
+/// Paths are resolved against the base URL (the production API URL by default),
+/// unless they are absolute `http://` or `https://` URLs. Requests without a
+/// fixture get a `404`. Several responses for the same request are answered in
+/// order, the last one repeatedly. Clones share fixtures and the request log, so a
+/// test can keep a handle to inspect what a client sent.
+///
+/// # Example
+///
+/// ```
+/// use orcid::transport::FixtureTransport;
+/// use orcid::ClientBuilder;
+/// use serde_json::json;
+/// use std::sync::Arc;
+///
+/// let fixture = FixtureTransport::new().with_json(
+///     "search?q=test",
+///     json!({ "num-found": 0, "result": [] }),
+/// );
+/// let client = ClientBuilder::new()
+///     .blocking_transport(Arc::new(fixture.clone()))
+///     .build_blocking()
+///     .unwrap();
+/// assert!(client.search("test").unwrap().is_empty());
+/// assert_eq!(fixture.requests().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixtureTransport {
+    base_url: String,
+    fixtures: Arc<Mutex<Fixtures>>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl FixtureTransport {
+    pub fn new() -> Self {
+        Self {
+            base_url: ClientBuilder::new().api_url(),
+            fixtures: Arc::new(Mutex::new(HashMap::new())),
+            requests: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    /// Set the base URL relative paths are resolved against
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    /// Answer `GET path` with `200` and the given JSON
+    pub fn with_json(self, path: &str, json: serde_json::Value) -> Self {
+        self.with_response(Method::Get, path, Response::new(200, &json.to_string()))
+    }
+
+    /// Answer `method path` with the given response, after any registered before it
+    pub fn with_response(self, method: Method, path: &str, response: Response) -> Self {
+        let url = self.resolve(path);
+        if let Ok(mut fixtures) = self.fixtures.lock() {
+            fixtures
+                .entry((method, url))
+                .or_default()
+                .push_back(response);
+        }
+        self
+    }
+
+    /// All requests received so far, oldest first
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+
+    fn resolve(&self, path: &str) -> String {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            path.to_string()
+        } else {
+            self.base_url.clone() + path
+        }
+    }
+
+    fn respond(&self, request: Request) -> Response {
+        let response = self
+            .fixtures
+            .lock()
+            .ok()
+            .and_then(|mut fixtures| {
+                let responses = fixtures.get_mut(&(request.method, request.url.to_owned()))?;
+                if responses.len() > 1 {
+                    responses.pop_front()
+                } else {
+                    responses.front().cloned()
+                }
+            })
+            .unwrap_or_else(|| {
+                Response::new(
+                    404,
+                    &format!("No fixture for {} {}", request.method, request.url),
+                )
+            });
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(request);
+        }
+        response
+    }
+}
+
+impl Default for FixtureTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        let response = self.respond(request);
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+impl BlockingTransport for FixtureTransport {
+    fn execute(&self, request: Request) -> Result<Response> {
+        Ok(self.respond(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_method_display() {
+        assert_eq!(Method::Get.to_string(), "GET");
+        assert_eq!(Method::Delete.to_string(), "DELETE");
+    }
+
+    #[test]
+    fn test_header_lookup_is_case_insensitive() {
+        let response = Response::new(201, "").with_header("Location", "http://x/work/123");
+        assert_eq!(response.header("location"), Some("http://x/work/123"));
+        assert_eq!(response.header("content-type"), None);
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn test_response_json() {
+        assert_eq!(Response::new(200, r#"{"a":1}"#).json().unwrap()["a"], 1);
+        match Response::new(500, "oops").json() {
+            Err(OrcidError::HttpError { status, body }) => {
+                assert_eq!(status, 500);
+                assert_eq!(body, "oops");
+            }
+            _ => panic!("Expected HttpError"),
+        }
+        match Response::new(200, "oops").json() {
+            Err(OrcidError::JsonError(_)) => (),
+            _ => panic!("Expected JsonError"),
+        }
+    }
+
+    #[test]
+    fn test_fixture_relative_path() {
+        let fixture = FixtureTransport::new().with_json("0000-0001-5916-0947", json!({"a": 1}));
+        let response = BlockingTransport::execute(
+            &fixture,
+            Request::get("https://pub.orcid.org/v3.0/0000-0001-5916-0947"),
+        )
+        .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn test_fixture_absolute_url_and_base_url() {
+        let fixture = FixtureTransport::new()
+            .with_base_url("http://localhost/v3.0/")
+            .with_json("record", json!({}))
+            .with_response(
+                Method::Post,
+                "https://orcid.org/oauth/token",
+                Response::new(200, "{}"),
+            );
+        let get =
+            BlockingTransport::execute(&fixture, Request::get("http://localhost/v3.0/record"));
+        assert_eq!(get.unwrap().status, 200);
+        let post = BlockingTransport::execute(
+            &fixture,
+            Request::new(Method::Post, "https://orcid.org/oauth/token"),
+        );
+        assert_eq!(post.unwrap().status, 200);
+    }
+
+    #[test]
+    fn test_fixture_responses_in_order() {
+        let fixture = FixtureTransport::new()
+            .with_json("a", json!(1))
+            .with_json("a", json!(2));
+        let body = |fixture: &FixtureTransport| {
+            BlockingTransport::execute(fixture, Request::get("https://pub.orcid.org/v3.0/a"))
+                .unwrap()
+                .body
+        };
+        assert_eq!(body(&fixture), "1");
+        assert_eq!(body(&fixture), "2");
+        assert_eq!(body(&fixture), "2");
+    }
+
+    #[test]
+    fn test_fixture_missing() {
+        let fixture = FixtureTransport::new();
+        let response =
+            BlockingTransport::execute(&fixture, Request::get("https://example.org/x")).unwrap();
+        assert_eq!(response.status, 404);
+        assert!(response.body.contains("GET https://example.org/x"));
+    }
+
+    #[tokio::test]
+    async fn test_fixture_records_requests() {
+        let fixture = FixtureTransport::new().with_json("a", json!([]));
+        let handle = fixture.clone();
+        let request = Request::get("https://pub.orcid.org/v3.0/a").with_header("Accept", "x");
+        Transport::execute(&fixture, request.clone()).await.unwrap();
+        assert_eq!(handle.requests(), vec![request]);
+        assert_eq!(handle.requests()[0].header("accept"), Some("x"));
+    }
+}
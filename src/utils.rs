@@ -1,3 +1,5 @@
+use crate::external_id::{self, ExternalId};
+use crate::record;
 use serde_json;
 
 /// Extracts specified parts from a JSON array and returns them as a vector of string vectors.
@@ -42,9 +44,65 @@ pub fn collect_parts(j: &serde_json::Value, parts: Vec<&str>) -> Vec<Vec<String>
         .collect()
 }
 
+/// Parses an ORCID v3.0 `external-ids` object.
+///
+/// IDs without a type or value are skipped.
+///
+/// # Example
+///
+/// ```
+/// use orcid::utils::external_ids_from_json;
+/// use orcid::ExternalIdType;
+/// use serde_json::json;
+///
+/// let j = json!({ "external-id": [{ "external-id-type": "doi", "external-id-value": "10.1234/test" }] });
+/// let ids = external_ids_from_json(&j);
+/// assert_eq!(ids[0].id_type(), &ExternalIdType::Doi);
+/// assert_eq!(ids[0].value(), "10.1234/test");
+/// ```
+pub fn external_ids_from_json(j: &serde_json::Value) -> Vec<ExternalId> {
+    external_id::from_record_ids(&Some(record::from_json(j)))
+}
+
+/// Serializes external IDs to an ORCID v3.0 `external-ids` object.
+///
+/// IDs without a relationship get `self`, which is what ORCID expects for
+/// identifiers of the item itself.
+///
+/// # Example
+///
+/// ```
+/// use orcid::utils::external_ids_to_json;
+/// use orcid::{ExternalId, ExternalIdType};
+///
+/// let j = external_ids_to_json(&[ExternalId::new(ExternalIdType::Doi, "10.1234/test")]);
+/// assert_eq!(j["external-id"][0]["external-id-relationship"], "self");
+/// ```
+pub fn external_ids_to_json(external_ids: &[ExternalId]) -> serde_json::Value {
+    serde_json::json!({
+        "external-id": external_ids
+            .iter()
+            .map(ExternalId::to_json)
+            .collect::<Vec<serde_json::Value>>()
+    })
+}
+
+/// Strips the first matching prefix, ignoring ASCII case
+pub(crate) fn strip_prefixes<'a>(value: &'a str, prefixes: &[&str]) -> &'a str {
+    prefixes
+        .iter()
+        .find(|prefix| {
+            value
+                .get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+        })
+        .map_or(value, |prefix| &value[prefix.len()..])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::external_id::{ExternalIdRelationship, ExternalIdType};
     use serde_json::json;
 
     #[test]
@@ -135,4 +193,44 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0], vec!["numeric", ""]); // Numbers are not converted to strings
     }
+
+    #[test]
+    fn test_external_ids_to_json() {
+        let j = external_ids_to_json(&[
+            ExternalId::new(ExternalIdType::Doi, "10.1234/test"),
+            ExternalId::new(ExternalIdType::Pmid, "12345678"),
+        ]);
+        assert_eq!(j["external-id"].as_array().unwrap().len(), 2);
+        assert_eq!(j["external-id"][1]["external-id-type"], "pmid");
+        assert_eq!(j["external-id"][1]["external-id-value"], "12345678");
+
+        // The serialized form can be read back
+        let parts = collect_parts(
+            &j["external-id"],
+            vec!["external-id-type", "external-id-value"],
+        );
+        assert_eq!(parts[0], vec!["doi", "10.1234/test"]);
+    }
+
+    #[test]
+    fn test_external_ids_to_json_empty() {
+        assert_eq!(external_ids_to_json(&[]), json!({ "external-id": [] }));
+    }
+
+    #[test]
+    fn test_external_ids_round_trip() {
+        let ids = vec![
+            ExternalId::new(ExternalIdType::Doi, "10.1234/test")
+                .with_relationship(ExternalIdRelationship::Self_),
+            ExternalId::new(ExternalIdType::Pmid, "12345678")
+                .with_relationship(ExternalIdRelationship::PartOf)
+                .with_url("https://pubmed.ncbi.nlm.nih.gov/12345678"),
+        ];
+        assert_eq!(external_ids_from_json(&external_ids_to_json(&ids)), ids);
+        assert!(external_ids_from_json(&json!(null)).is_empty());
+        assert!(
+            external_ids_from_json(&json!({ "external-id": [{ "external-id-type": "doi" }] }))
+                .is_empty()
+        );
+    }
 }
@@ -1,32 +1,64 @@
-use crate::publication_date::PublicationDate;
-use crate::utils::collect_parts;
+use crate::activity_group::GroupedActivity;
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::external_id::{self, ExternalId};
+use crate::fuzzy_date::FuzzyDate;
+use crate::record;
+use crate::utils::external_ids_from_json;
 use serde_json;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Work {
+    pub metadata: ActivityMetadata,
     pub title: Option<String>,
-    pub external_ids: Vec<(String, String)>,
-    pub publication_date: PublicationDate,
+    pub external_ids: Vec<ExternalId>,
+    pub publication_date: Option<FuzzyDate>,
     pub pub_type: Option<String>,
 }
 
 impl Work {
+    /// Parses the first summary of a `works` group, with the external IDs of the group
     pub fn new_from_json(j: &serde_json::Value) -> Self {
+        let mut work = Self::new_from_summary_json(&j["work-summary"][0]);
+        let group_external_ids = external_ids_from_json(&j["external-ids"]);
+        if !group_external_ids.is_empty() {
+            work.external_ids = group_external_ids;
+        }
+        work
+    }
+
+    /// Parses a single `work-summary` object
+    pub fn new_from_summary_json(work_summary: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::WorkSummary>(work_summary))
+    }
+}
+
+impl Activity for Work {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl GroupedActivity for Work {
+    fn external_ids_mut(&mut self) -> &mut Vec<ExternalId> {
+        &mut self.external_ids
+    }
+}
+
+impl From<&record::WorkSummary> for Work {
+    fn from(summary: &record::WorkSummary) -> Self {
         Self {
-            title: j["work-summary"][0]["title"]["title"]["value"]
-                .as_str()
-                .map(|v| v.to_string()),
-            external_ids: collect_parts(
-                &j["external-ids"]["external-id"],
-                vec!["external-id-type", "external-id-value"],
-            )
-            .iter()
-            .map(|v| (v[0].to_owned(), v[1].to_owned()))
-            .collect(),
-            pub_type: j["work-summary"][0]["type"].as_str().map(|v| v.to_string()),
-            publication_date: PublicationDate::new_from_json(
-                &j["work-summary"][0]["publication-date"],
-            ),
+            metadata: ActivityMetadata::from(&summary.metadata),
+            title: summary
+                .title
+                .as_ref()
+                .and_then(|t| record::string_value(&t.title)),
+            external_ids: external_id::from_record_ids(&summary.external_ids),
+            pub_type: summary.work_type.clone(),
+            publication_date: summary
+                .publication_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
         }
     }
 }
@@ -69,16 +101,23 @@ mod tests {
         assert_eq!(work.pub_type, Some("journal-article".to_string()));
         assert_eq!(work.external_ids.len(), 2);
         assert_eq!(
-            work.external_ids[0],
-            ("doi".to_string(), "10.1234/test".to_string())
+            (
+                work.external_ids[0].id_type().as_str(),
+                work.external_ids[0].value()
+            ),
+            ("doi", "10.1234/test")
         );
         assert_eq!(
-            work.external_ids[1],
-            ("pmid".to_string(), "12345678".to_string())
+            (
+                work.external_ids[1].id_type().as_str(),
+                work.external_ids[1].value()
+            ),
+            ("pmid", "12345678")
+        );
+        assert_eq!(
+            work.publication_date.map(|d| d.to_string()),
+            Some("2023-06-15".to_string())
         );
-        assert_eq!(work.publication_date.year(), Some(2023));
-        assert_eq!(work.publication_date.month(), Some(6));
-        assert_eq!(work.publication_date.day(), Some(15));
     }
 
     #[test]
@@ -100,8 +139,51 @@ mod tests {
         assert_eq!(work.title, None);
         assert_eq!(work.pub_type, None);
         assert_eq!(work.external_ids.len(), 0);
-        assert_eq!(work.publication_date.year(), None);
-        assert_eq!(work.publication_date.month(), None);
-        assert_eq!(work.publication_date.day(), None);
+        assert_eq!(work.publication_date, None);
+    }
+
+    #[test]
+    fn test_new_from_json_metadata() {
+        let j = json!({
+            "work-summary": [{
+                "put-code": 12345,
+                "visibility": "public",
+                "source": { "source-client-id": { "path": "APP-1234" } }
+            }]
+        });
+        let work = Work::new_from_json(&j);
+        assert_eq!(work.metadata.put_code(), Some(12345));
+        assert_eq!(work.metadata.visibility(), Some(&"public".to_string()));
+        assert_eq!(
+            work.metadata.source().and_then(|s| s.client_id()),
+            Some(&"APP-1234".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let j = json!({
+            "work-summary": [{
+                "put-code": 1,
+                "title": { "title": { "value": "Test Publication" } },
+                "type": "journal-article",
+                "publication-date": { "year": { "value": "2023" } }
+            }],
+            "external-ids": { "external-id": [{
+                "external-id-type": "doi",
+                "external-id-value": "10.1234/test"
+            }] }
+        });
+        let work = Work::new_from_json(&j);
+        let serialized = serde_json::to_value(&work).unwrap();
+        assert_eq!(serialized["title"], "Test Publication");
+        assert_eq!(
+            serialized["external_ids"],
+            json!([{ "id_type": "doi", "value": "10.1234/test", "relationship": null, "url": null }])
+        );
+        assert_eq!(serialized["metadata"]["put_code"], 1);
+        let back: Work = serde_json::from_value(serialized).unwrap();
+        assert_eq!(back, work);
     }
 }
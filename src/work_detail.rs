@@ -0,0 +1,250 @@
+use crate::activity_metadata::{Activity, ActivityMetadata};
+use crate::contributor::Contributor;
+use crate::external_id::{self, ExternalId};
+use crate::fuzzy_date::FuzzyDate;
+use crate::record;
+use serde_json;
+
+/// A single work with all its details, as returned by `/work/{put-code}`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkDetail {
+    metadata: ActivityMetadata,
+    title: Option<String>,
+    subtitle: Option<String>,
+    translated_title: Option<(String, String)>, // (title, language_code)
+    journal_title: Option<String>,
+    short_description: Option<String>,
+    citation: Option<(String, String)>, // (citation_type, citation_value)
+    work_type: Option<String>,
+    publication_date: Option<FuzzyDate>,
+    external_ids: Vec<ExternalId>,
+    url: Option<String>,
+    contributors: Vec<Contributor>,
+    language_code: Option<String>,
+    country: Option<String>,
+}
+
+impl WorkDetail {
+    pub fn new_from_json(j: &serde_json::Value) -> Self {
+        Self::from(&record::from_json::<record::Work>(j))
+    }
+
+    // Getter methods
+    pub fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+
+    pub fn put_code(&self) -> Option<u64> {
+        self.metadata.put_code()
+    }
+
+    pub fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+
+    pub fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+
+    pub fn translated_title(&self) -> Option<&(String, String)> {
+        self.translated_title.as_ref()
+    }
+
+    pub fn journal_title(&self) -> Option<&String> {
+        self.journal_title.as_ref()
+    }
+
+    pub fn short_description(&self) -> Option<&String> {
+        self.short_description.as_ref()
+    }
+
+    /// The citation as `(citation_type, citation_value)`, e.g. `("bibtex", "@article{...}")`
+    pub fn citation(&self) -> Option<&(String, String)> {
+        self.citation.as_ref()
+    }
+
+    pub fn work_type(&self) -> Option<&String> {
+        self.work_type.as_ref()
+    }
+
+    pub fn publication_date(&self) -> Option<&FuzzyDate> {
+        self.publication_date.as_ref()
+    }
+
+    pub fn external_ids(&self) -> &Vec<ExternalId> {
+        &self.external_ids
+    }
+
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+
+    pub fn contributors(&self) -> &Vec<Contributor> {
+        &self.contributors
+    }
+
+    pub fn language_code(&self) -> Option<&String> {
+        self.language_code.as_ref()
+    }
+
+    pub fn country(&self) -> Option<&String> {
+        self.country.as_ref()
+    }
+}
+
+impl Activity for WorkDetail {
+    fn metadata(&self) -> &ActivityMetadata {
+        &self.metadata
+    }
+}
+
+impl From<&record::Work> for WorkDetail {
+    fn from(work: &record::Work) -> Self {
+        let title = work.title.as_ref();
+        Self {
+            metadata: ActivityMetadata::from(&work.metadata),
+            title: title.and_then(|t| record::string_value(&t.title)),
+            subtitle: title.and_then(|t| record::string_value(&t.subtitle)),
+            translated_title: title.and_then(|t| t.translated_title.as_ref()).and_then(
+                |tt| match (&tt.value, &tt.language_code) {
+                    (Some(title), Some(lang)) => Some((title.clone(), lang.clone())),
+                    _ => None,
+                },
+            ),
+            journal_title: record::string_value(&work.journal_title),
+            short_description: work.short_description.clone(),
+            citation: work.citation.as_ref().and_then(|c| {
+                match (&c.citation_type, &c.citation_value) {
+                    (Some(citation_type), Some(citation_value)) => {
+                        Some((citation_type.clone(), citation_value.clone()))
+                    }
+                    _ => None,
+                }
+            }),
+            work_type: work.work_type.clone(),
+            publication_date: work
+                .publication_date
+                .as_ref()
+                .and_then(|d| FuzzyDate::try_from(d).ok()),
+            external_ids: external_id::from_record_ids(&work.external_ids),
+            url: record::string_value(&work.url),
+            contributors: work
+                .contributors
+                .as_ref()
+                .map(|c| c.contributor.iter().map(Contributor::from).collect())
+                .unwrap_or_default(),
+            language_code: work.language_code.clone(),
+            country: record::string_value(&work.country),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_new_from_json() {
+        let j = json!({
+            "put-code": 12345,
+            "title": {
+                "title": { "value": "A study of things" },
+                "subtitle": { "value": "and stuff" },
+                "translated-title": { "value": "Eine Studie", "language-code": "de" }
+            },
+            "journal-title": { "value": "Journal of Things" },
+            "short-description": "Things were studied",
+            "citation": {
+                "citation-type": "bibtex",
+                "citation-value": "@article{things2023}"
+            },
+            "type": "journal-article",
+            "publication-date": {
+                "year": { "value": "2023" },
+                "month": { "value": "06" },
+                "day": null
+            },
+            "external-ids": {
+                "external-id": [{
+                    "external-id-type": "doi",
+                    "external-id-value": "10.1234/things",
+                    "external-id-relationship": "self"
+                }]
+            },
+            "url": { "value": "https://example.com/things" },
+            "contributors": {
+                "contributor": [{
+                    "contributor-orcid": { "path": "0000-0002-1825-0097" },
+                    "credit-name": { "value": "Josiah Carberry" },
+                    "contributor-attributes": {
+                        "contributor-sequence": "first",
+                        "contributor-role": "author"
+                    }
+                }, {
+                    "credit-name": { "value": "Jane Doe" },
+                    "contributor-attributes": {
+                        "contributor-sequence": "additional",
+                        "contributor-role": "author"
+                    }
+                }]
+            },
+            "language-code": "en",
+            "country": { "value": "US" }
+        });
+
+        let work = WorkDetail::new_from_json(&j);
+        assert_eq!(work.put_code(), Some(12345));
+        assert_eq!(work.title(), Some(&"A study of things".to_string()));
+        assert_eq!(work.subtitle(), Some(&"and stuff".to_string()));
+        assert_eq!(
+            work.translated_title(),
+            Some(&("Eine Studie".to_string(), "de".to_string()))
+        );
+        assert_eq!(work.journal_title(), Some(&"Journal of Things".to_string()));
+        assert_eq!(
+            work.short_description(),
+            Some(&"Things were studied".to_string())
+        );
+        assert_eq!(
+            work.citation(),
+            Some(&("bibtex".to_string(), "@article{things2023}".to_string()))
+        );
+        assert_eq!(work.work_type(), Some(&"journal-article".to_string()));
+        let date = work.publication_date().unwrap();
+        assert_eq!(date.year(), 2023);
+        assert_eq!(date.month(), Some(6));
+        assert_eq!(date.day(), None);
+        assert_eq!(
+            work.external_ids()
+                .iter()
+                .map(|id| (id.id_type().as_str(), id.value()))
+                .collect::<Vec<_>>(),
+            vec![("doi", "10.1234/things")]
+        );
+        assert_eq!(work.url(), Some(&"https://example.com/things".to_string()));
+        assert_eq!(work.contributors().len(), 2);
+        assert_eq!(
+            work.contributors()[0].orcid(),
+            Some(&"0000-0002-1825-0097".to_string())
+        );
+        assert_eq!(
+            work.contributors()[1].sequence(),
+            Some(&"additional".to_string())
+        );
+        assert_eq!(work.language_code(), Some(&"en".to_string()));
+        assert_eq!(work.country(), Some(&"US".to_string()));
+    }
+
+    #[test]
+    fn test_new_from_json_minimal() {
+        let work = WorkDetail::new_from_json(&json!({ "put-code": 1 }));
+        assert_eq!(work.put_code(), Some(1));
+        assert_eq!(work.title(), None);
+        assert_eq!(work.citation(), None);
+        assert!(work.publication_date().is_none());
+        assert!(work.external_ids().is_empty());
+        assert!(work.contributors().is_empty());
+    }
+}